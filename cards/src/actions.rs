@@ -0,0 +1,19 @@
+use protocol::entities::CreatureInstanceId;
+use salt_engine::{game_state::game_state::GameState, v2::BuffBuilder};
+
+/// Permanently buffs a creature, with the creature itself as the buff's source.
+pub(crate) fn gain_stats(
+    game_state: &mut GameState,
+    instance_id: CreatureInstanceId,
+    attack: i32,
+    health: i32,
+) {
+    let buff = BuffBuilder::new(instance_id)
+        .attack(attack)
+        .health(health)
+        .build();
+
+    if let Some(mut creature) = game_state.board_mut().creature_by_id_mut(instance_id) {
+        creature.get_mut(|c| c.add_buff(buff));
+    }
+}
@@ -1,52 +1,49 @@
-use protocol::entities::{Id, Position};
-use salt_engine::cards::{CardDefinition, UnitCardDefinition};
-
-fn create() -> Box<dyn UnitCardDefinition> {
-    Box::new(AttackDog)
-}
+use protocol::entities::{Position, Tribe};
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder, CreatureDefinition, CreatureDefinitionId,
+};
 
 #[derive(Debug, Clone)]
 pub struct AttackDog;
 
 impl AttackDog {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
-    }
-}
-
-impl CardDefinition for AttackDog {
-    fn title(&self) -> &str {
-        "Attack Dog"
-    }
-
-    fn cost(&self) -> i32 {
-        3
-    }
-
-    fn flavor_text(&self) -> &str {
-        "todo"
-    }
-
-    fn text(&self) -> &str {
-        ""
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("d45d1c5f-7cb3-4653-aa8f-227176ff0048")
+    }
+
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Attack Dog".to_string())
+            .cost(3)
+            .text(String::new())
+            .flavor_text("todo".to_string())
+            .attack(5)
+            .health(3)
+            .width(1)
+            .placeable_at(Position::Front)
+            .tribe(Tribe::Dog)
+            .build()
     }
 }
 
-impl UnitCardDefinition for AttackDog {
-    fn attack(&self) -> i32 {
-        5
-    }
+#[cfg(test)]
+mod tests {
+    use super::AttackDog;
+    use crate::tests::{make_dispatcher, make_test_state, stats, summon};
+    use protocol::entities::{BoardPos, RowId};
 
-    fn health(&self) -> i32 {
-        3
-    }
+    #[test]
+    fn summon_expects_5_3_in_front_row() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let pos = BoardPos::new(state.player_a_id(), RowId::FrontRow, 2);
 
-    fn row_width(&self) -> usize {
-        1
-    }
+        let dog = summon(&mut state, &mut dispatcher, AttackDog::id(), pos);
 
-    fn placeable_at(&self) -> Position {
-        Position::Front
+        assert_eq!(Some((5, 3)), stats(&state, dog));
+        assert_eq!(Some(pos), state.board().creature_pos(dog));
     }
 }
@@ -0,0 +1,29 @@
+use protocol::entities::Tribe;
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder, CreatureDefinition, CreatureDefinitionId,
+};
+
+/// The token summoned by the `BugSwarm` hero power.
+#[derive(Debug, Clone)]
+pub struct Bug;
+
+impl Bug {
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("98dbc824-d0ba-4a32-93c1-3ad4890cf180")
+    }
+
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Bug".to_string())
+            .cost(1)
+            .flavor_text("There's never just one.".to_string())
+            .attack(1)
+            .health(1)
+            .width(1)
+            .tribe(Tribe::Bug)
+            .build()
+    }
+}
@@ -0,0 +1,79 @@
+use protocol::entities::Tribe;
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder, templates::SummonRequirement, CreatureDefinition,
+    CreatureDefinitionId,
+};
+
+#[derive(Debug, Clone)]
+pub struct BugCollector;
+
+impl BugCollector {
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("40d6e6c1-18c3-4872-93a1-6c7211a361c1")
+    }
+
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Bug Collector".to_string())
+            .cost(2)
+            .text("Can only be summoned\nwhile you control\na Bug.".to_string())
+            .flavor_text("Has a jar for every one.".to_string())
+            .attack(3)
+            .health(3)
+            .width(1)
+            .summon_condition(SummonRequirement::ControlsTribe(Tribe::Bug))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BugCollector;
+    use crate::{
+        tests::{make_dispatcher_with_prompter, make_test_state, MockTestPrompter},
+        BugSwarm,
+    };
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, RowId},
+        rule_violation::RuleViolation,
+    };
+    use salt_engine::{
+        game_logic::events::{CreatureSummonedFromHandEvent, Event, HeroPowerUsedEvent},
+        game_state::CardInHand,
+    };
+
+    #[test]
+    fn summon_expects_only_while_controlling_a_bug() {
+        let mut state = make_test_state();
+        let player_a = state.player_a_id();
+        let bug_pos = BoardPos::new(player_a, RowId::BackRow, 0);
+
+        let mut prompter = MockTestPrompter::new();
+        prompter.expect_prompt_player_slot().return_const(bug_pos);
+        let mut dispatcher = make_dispatcher_with_prompter(&state, prompter);
+        state.set_hero_power(player_a, BugSwarm::definition());
+
+        let card_id = CreatureInstanceId::new();
+        state
+            .hand_mut(player_a)
+            .add_card(CardInHand::new_with_id(card_id, BugCollector::id()));
+        let summon = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+            card_id,
+        );
+
+        assert_eq!(
+            Err(RuleViolation::SummonConditionNotMet { card_id }),
+            summon.validate(&state)
+        );
+
+        smol::block_on(dispatcher.dispatch(HeroPowerUsedEvent::new(player_a), &mut state)).unwrap();
+
+        assert!(state.board().creature_at_pos(bug_pos).is_some());
+        assert!(summon.validate(&state).is_ok());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::Bug;
+use async_trait::async_trait;
+use protocol::entities::{BoardPos, PlayerId, RowId};
+use salt_engine::{
+    game_logic::{event_dispatch::EventDispatcher, events::CreatureSetEvent},
+    game_state::{game_state::GameState, ROW_LENGTH},
+    v2::{
+        actions::{SummonCondition, UponHeroPowerAction},
+        HeroPowerDefinition, HeroPowerId,
+    },
+};
+
+/// A hero power that summons a single 1/1 Bug.
+#[derive(Debug, Clone)]
+pub struct BugSwarm;
+
+impl BugSwarm {
+    #[must_use]
+    pub fn id() -> HeroPowerId {
+        HeroPowerId::parse_str("bf0d3efb-0b36-4375-8143-e64821399893")
+    }
+
+    #[must_use]
+    pub fn definition() -> HeroPowerDefinition {
+        HeroPowerDefinition::new(
+            Self::id(),
+            "Bug Swarm",
+            "Summon a 1/1 Bug.",
+            2,
+            SummonBugAction,
+        )
+        .with_condition(HasFreeSlot)
+    }
+}
+
+/// Bug Swarm can only be used while the player has an empty slot for the bug.
+struct HasFreeSlot;
+
+impl SummonCondition for HasFreeSlot {
+    fn is_met(&self, player_id: PlayerId, game_state: &GameState) -> bool {
+        [RowId::FrontRow, RowId::BackRow].into_iter().any(|row_id| {
+            (0..ROW_LENGTH).any(|row_index| {
+                game_state
+                    .board()
+                    .creature_at_pos(BoardPos::new(player_id, row_id, row_index))
+                    .is_none()
+            })
+        })
+    }
+}
+
+/// Asks the player for a free slot on their side, and summons a bug there.
+/// The slot is checked like any other summon, so a bad answer summons nothing.
+struct SummonBugAction;
+
+#[async_trait]
+impl UponHeroPowerAction for SummonBugAction {
+    async fn action(
+        &self,
+        player_id: PlayerId,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        let pos = dispatcher.player_prompter(player_id).prompt_player_slot();
+        let bug_id = game_state.next_instance_id();
+
+        dispatcher
+            .dispatch_triggered(
+                CreatureSetEvent::new(player_id, Bug::id(), bug_id, pos),
+                game_state,
+            )
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BugSwarm;
+    use crate::{
+        tests::{make_dispatcher_with_prompter, make_test_state, place, MockTestPrompter},
+        Bug, Pawn,
+    };
+    use protocol::{
+        entities::{BoardPos, RowId},
+        rule_violation::RuleViolation,
+    };
+    use salt_engine::{
+        game_logic::events::HeroPowerUsedEvent,
+        game_state::{game_state::GameState, ROW_LENGTH},
+        v2::CreatureInstance,
+    };
+
+    /// Uses Bug Swarm for player A, answering its prompt with `choose_slot`,
+    /// while player A has a Pawn in their back row at index 0.
+    fn use_bug_swarm(choose_slot: impl Fn(&GameState) -> BoardPos) -> GameState {
+        let mut state = make_test_state();
+        let player_a = state.player_a_id();
+        let pos = choose_slot(&state);
+
+        let mut prompter = MockTestPrompter::new();
+        prompter.expect_prompt_player_slot().return_const(pos);
+        let mut dispatcher = make_dispatcher_with_prompter(&state, prompter);
+        state.set_hero_power(player_a, BugSwarm::definition());
+        place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_a, RowId::BackRow, 0),
+        );
+
+        smol::block_on(dispatcher.dispatch(HeroPowerUsedEvent::new(player_a), &mut state)).unwrap();
+
+        state
+    }
+
+    fn bugs(state: &GameState) -> usize {
+        [state.player_a_id(), state.player_b_id()]
+            .into_iter()
+            .flat_map(|p| state.board().player_creature_ids(p))
+            .filter(|id| {
+                state
+                    .board()
+                    .creature_by_id(*id)
+                    .is_some_and(|c| c.get(CreatureInstance::definition_id) == Bug::id())
+            })
+            .count()
+    }
+
+    #[test]
+    fn use_expects_one_bug_in_free_slot() {
+        let state = use_bug_swarm(|s| BoardPos::new(s.player_a_id(), RowId::FrontRow, 2));
+
+        assert_eq!(1, bugs(&state));
+    }
+
+    #[test]
+    fn use_when_slot_taken_or_enemys_expects_no_bug() {
+        let taken = use_bug_swarm(|s| BoardPos::new(s.player_a_id(), RowId::BackRow, 0));
+        let enemys = use_bug_swarm(|s| BoardPos::new(s.player_b_id(), RowId::BackRow, 1));
+
+        assert_eq!(0, bugs(&taken));
+        assert_eq!(0, bugs(&enemys));
+    }
+
+    #[test]
+    fn use_when_no_free_slot_expects_rejected_and_nothing_paid() {
+        let mut state = make_test_state();
+        let player_a = state.player_a_id();
+        let mut dispatcher = make_dispatcher_with_prompter(&state, MockTestPrompter::new());
+        state.set_hero_power(player_a, BugSwarm::definition());
+
+        for row_id in [RowId::FrontRow, RowId::BackRow] {
+            for row_index in 0..ROW_LENGTH {
+                place(
+                    &mut state,
+                    Pawn::id(),
+                    BoardPos::new(player_a, row_id, row_index),
+                );
+            }
+        }
+
+        let result =
+            smol::block_on(dispatcher.dispatch(HeroPowerUsedEvent::new(player_a), &mut state));
+
+        assert_eq!(
+            Err(RuleViolation::HeroPowerConditionNotMet {
+                player_id: player_a
+            }),
+            result
+        );
+        let (mana, used) = state
+            .board()
+            .player_hero(player_a)
+            .get(|h| (h.mana(), h.has_used_hero_power()));
+        assert_eq!(10, mana);
+        assert!(!used);
+    }
+}
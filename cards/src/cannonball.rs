@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use log::info;
+use protocol::entities::{CreatureInstanceId, PlayerId};
+use salt_engine::{
+    game_logic::{event_dispatch::EventDispatcher, events::PosTakesDamageEvent},
+    game_state::game_state::GameState,
+    v2::{
+        actions::UponCastAction, builder::CreatureDefinitionBuilder, CreatureDefinition,
+        CreatureDefinitionId, SpellTarget,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct Cannonball;
+
+impl Cannonball {
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("c677b32a-64b6-4d55-a6b2-45bf139fcdcc")
+    }
+
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Cannonball".to_string())
+            .cost(2)
+            .text("Spell\nDeal 3 damage\nto an enemy\ncreature.".to_string())
+            .flavor_text("Who needs the cannon?".to_string())
+            .upon_cast(CastAction)
+            .spell_target(SpellTarget::EnemyCreature)
+            .build()
+    }
+}
+
+/// Asks the caster which enemy creature to hit.
+/// The cast is only valid while there is one, but the answer may still name another pos.
+struct CastAction;
+
+#[async_trait]
+impl UponCastAction for CastAction {
+    async fn action(
+        &self,
+        _card_id: CreatureInstanceId,
+        player_id: PlayerId,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        let target = dispatcher
+            .player_prompter(player_id)
+            .prompt_opponent_creature_pos();
+
+        // The prompt is only a request, so the answer is checked like any client input.
+        let is_enemy_creature = target.player_id == game_state.other_player(player_id)
+            && !target.row_id.is_hero()
+            && game_state.board().creature_at_pos(target).is_some();
+
+        if !is_enemy_creature {
+            info!("Cannonball fizzles, {:?} is not an enemy creature", target);
+            return;
+        }
+
+        info!("Cannonball is fired at {:?}", target);
+
+        dispatcher
+            .dispatch_triggered(PosTakesDamageEvent::new(target, 3), game_state)
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cannonball;
+    use crate::{
+        tests::{make_dispatcher_with_prompter, make_test_state, place, stats, MockTestPrompter},
+        AttackDog,
+    };
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, RowId},
+        rule_violation::RuleViolation,
+    };
+    use salt_engine::{game_logic::events::SpellCastFromHandEvent, game_state::CardInHand};
+
+    #[test]
+    fn cast_expects_chosen_enemy_damaged() {
+        let mut state = make_test_state();
+        let player_a = state.player_a_id();
+        let target_pos = BoardPos::new(state.player_b_id(), RowId::FrontRow, 2);
+
+        let mut prompter = MockTestPrompter::new();
+        prompter
+            .expect_prompt_opponent_creature_pos()
+            .return_const(target_pos);
+        let mut dispatcher = make_dispatcher_with_prompter(&state, prompter);
+
+        let dog = place(&mut state, AttackDog::id(), target_pos);
+        let card_id = CreatureInstanceId::new();
+        state
+            .hand_mut(player_a)
+            .add_card(CardInHand::new_with_id(card_id, Cannonball::id()));
+
+        smol::block_on(
+            dispatcher.dispatch(SpellCastFromHandEvent::new(player_a, card_id), &mut state),
+        )
+        .unwrap();
+
+        // Attack Dog only has 3 health.
+        assert_eq!(None, stats(&state, dog));
+        assert!(state.hand(player_a).is_empty());
+        assert_eq!(8, state.board().player_hero(player_a).get(|h| h.mana()));
+    }
+
+    #[test]
+    fn cast_when_target_not_enemy_creature_expects_fizzles() {
+        let mut state = make_test_state();
+        let player_a = state.player_a_id();
+        let own_pos = BoardPos::new(player_a, RowId::FrontRow, 2);
+
+        let mut prompter = MockTestPrompter::new();
+        prompter
+            .expect_prompt_opponent_creature_pos()
+            .return_const(own_pos);
+        let mut dispatcher = make_dispatcher_with_prompter(&state, prompter);
+
+        let dog = place(&mut state, AttackDog::id(), own_pos);
+        let enemy_pos = BoardPos::new(state.player_b_id(), RowId::FrontRow, 2);
+        let enemy_dog = place(&mut state, AttackDog::id(), enemy_pos);
+        let card_id = CreatureInstanceId::new();
+        state
+            .hand_mut(player_a)
+            .add_card(CardInHand::new_with_id(card_id, Cannonball::id()));
+
+        smol::block_on(
+            dispatcher.dispatch(SpellCastFromHandEvent::new(player_a, card_id), &mut state),
+        )
+        .unwrap();
+
+        assert_eq!(Some((5, 3)), stats(&state, dog));
+        assert_eq!(Some((5, 3)), stats(&state, enemy_dog));
+        assert!(state.hand(player_a).is_empty());
+    }
+
+    #[test]
+    fn cast_when_no_enemy_creature_expects_rejected_and_nothing_paid() {
+        let mut state = make_test_state();
+        let player_a = state.player_a_id();
+        let mut dispatcher = make_dispatcher_with_prompter(&state, MockTestPrompter::new());
+
+        place(
+            &mut state,
+            AttackDog::id(),
+            BoardPos::new(player_a, RowId::FrontRow, 2),
+        );
+        let card_id = CreatureInstanceId::new();
+        state
+            .hand_mut(player_a)
+            .add_card(CardInHand::new_with_id(card_id, Cannonball::id()));
+
+        let result = smol::block_on(
+            dispatcher.dispatch(SpellCastFromHandEvent::new(player_a, card_id), &mut state),
+        );
+
+        assert_eq!(Err(RuleViolation::NoLegalTarget { card_id }), result);
+        assert_eq!(1, state.hand(player_a).len());
+        assert_eq!(10, state.board().player_hero(player_a).get(|h| h.mana()));
+    }
+}
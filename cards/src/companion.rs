@@ -0,0 +1,61 @@
+use protocol::entities::{CreatureInstanceId, RowId};
+use salt_engine::{
+    game_state::game_state::GameState,
+    v2::{actions::PassiveEffect, Buff, BuffBuilder},
+};
+
+/// A passive that buffs each of the creature's companions, optionally only while the creature is in a given row.
+pub(crate) struct PassiveCompanionBuff {
+    attack: i32,
+    health: i32,
+    row_id: Option<RowId>,
+}
+
+impl PassiveCompanionBuff {
+    pub(crate) fn new(attack: i32, health: i32) -> Self {
+        Self {
+            attack,
+            health,
+            row_id: None,
+        }
+    }
+
+    pub(crate) fn new_for_row(attack: i32, health: i32, row_id: RowId) -> Self {
+        Self {
+            attack,
+            health,
+            row_id: Some(row_id),
+        }
+    }
+}
+
+impl PassiveEffect for PassiveCompanionBuff {
+    fn buffs(
+        &self,
+        instance_id: CreatureInstanceId,
+        game_state: &GameState,
+    ) -> Vec<(CreatureInstanceId, Buff)> {
+        let Some(pos) = game_state.board().creature_pos(instance_id) else {
+            return Vec::new();
+        };
+
+        if self.row_id.is_some_and(|row_id| row_id != pos.row_id) {
+            return Vec::new();
+        }
+
+        game_state
+            .board()
+            .companions_of(instance_id)
+            .into_iter()
+            .map(|companion_id| {
+                let buff = BuffBuilder::new(instance_id)
+                    .attack(self.attack)
+                    .health(self.health)
+                    .from_passive()
+                    .build();
+
+                (companion_id, buff)
+            })
+            .collect()
+    }
+}
@@ -1,117 +1,101 @@
-use protocol::entities::{Id, PassiveEffectInstanceId, Position};
-use salt_engine::{
-    cards::{CardDefinition, UnitCardDefinition},
-    game_logic::{BuffBuilder, PassiveCompanionBuff, PassiveEffectDefinition},
+use crate::companion::PassiveCompanionBuff;
+use protocol::entities::{Position, Tribe};
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder, CreatureDefinition, CreatureDefinitionId,
 };
 
 #[derive(Debug, Clone)]
 pub struct EmotionalSupportDog;
 
 impl EmotionalSupportDog {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
-    }
-}
-
-impl CardDefinition for EmotionalSupportDog {
-    fn title(&self) -> &str {
-        "Emo Sup Dog"
-        //"Emotional Support Dog"
-    }
-
-    fn cost(&self) -> i32 {
-        2
-    }
-
-    fn flavor_text(&self) -> &str {
-        "But really, aren't all dogs Emotional Support Dogs?"
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("5403cfc0-d0a9-44e2-8d79-b4933a2eceec")
     }
 
-    fn text(&self) -> &str {
-        "Back
-Companion has +1/+1."
-    }
-}
-
-impl UnitCardDefinition for EmotionalSupportDog {
-    fn attack(&self) -> i32 {
-        1
-    }
-
-    fn health(&self) -> i32 {
-        1
-    }
-
-    fn row_width(&self) -> usize {
-        1
-    }
-
-    fn passive_effect(&self) -> Option<Box<dyn PassiveEffectDefinition>> {
-        let buff = BuffBuilder::new(PassiveEffectInstanceId::new(), Id::new())
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Emo Sup Dog".to_string())
+            .cost(2)
+            .text("Back\nCompanion has +1/+1.".to_string())
+            .flavor_text("But really, aren't all dogs Emotional Support Dogs?".to_string())
             .attack(1)
             .health(1)
-            .build();
+            .width(1)
+            .placeable_at(Position::Back)
+            .tribe(Tribe::Dog)
+            .passive_effect(PassiveCompanionBuff::new(1, 1))
+            .build()
+    }
+}
 
-        let passive = PassiveCompanionBuff::new(Id::new(), Box::new(buff));
-        Some(Box::new(passive))
+#[cfg(test)]
+mod tests {
+    use super::EmotionalSupportDog;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place, stats},
+        Pawn,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::game_logic::events::CreatureTakesDamageEvent;
+
+    #[test]
+    fn passive_expects_companion_buffed_while_dog_on_board() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+
+        let pawn = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 3),
+        );
+        let beside = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 4),
+        );
+        let dog = place(
+            &mut state,
+            EmotionalSupportDog::id(),
+            BoardPos::new(player_id, RowId::BackRow, 3),
+        );
+
+        state.evaluate_passives();
+        assert_eq!(Some((2, 2)), stats(&state, pawn));
+        assert_eq!(Some((1, 1)), stats(&state, beside));
+
+        state.board_mut().remove_creature(dog);
+        state.evaluate_passives();
+        assert_eq!(Some((1, 1)), stats(&state, pawn));
     }
 
-    fn placeable_at(&self) -> Position {
-        Position::Back
+    #[test]
+    fn dog_destroyed_expects_companion_without_health_destroyed() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_id = state.player_a_id();
+
+        let pawn = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 3),
+        );
+        let dog = place(
+            &mut state,
+            EmotionalSupportDog::id(),
+            BoardPos::new(player_id, RowId::BackRow, 3),
+        );
+
+        for creature_id in [pawn, dog] {
+            smol::block_on(
+                dispatcher.dispatch(CreatureTakesDamageEvent::new(creature_id, 1), &mut state),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(None, stats(&state, dog));
+        assert_eq!(None, stats(&state, pawn));
     }
 }
-
-// #[cfg(test)]
-// mod tests {
-//     use super::EmotionalSupportDog;
-//     use crate::{
-//         tests::{make_dispatcher, make_test_state},
-//         ReallyBigRock,
-//     };
-//     use protocol::entities::{BoardPos, RowId};
-//     use salt_engine::{
-//         cards::UnitCardDefinition, game_logic::events::CreatureSetEvent,
-//         game_state::board::BoardView,
-//     };
-
-//     #[test]
-//     fn when_summoned_expects_provides_buff() {
-//         let mut state = make_test_state();
-//         let mut dispatcher = make_dispatcher(state.player_a_id(), state.player_b_id());
-
-//         // Summon the thing that will get buffed.
-//         let rock = ReallyBigRock.make_instance();
-//         let attack_start = rock.attack();
-//         let health_start = rock.health();
-//         let buffed_id = rock.id();
-//         {
-//             state.board_mut().track_pending_card(rock);
-//             let summon_at = BoardPos::new(state.player_a_id(), RowId::FrontRow, 3);
-//             let summon_doggy_event =
-//                 CreatureSetEvent::new(state.player_a_id(), buffed_id, summon_at);
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_doggy_event, &mut state).await;
-//             });
-//         }
-
-//         {
-//             let doggy = EmotionalSupportDog.make_instance();
-//             let doggy_id = doggy.id();
-//             state.board_mut().track_pending_card(doggy);
-//             let summon_at = BoardPos::new(state.player_a_id(), RowId::BackRow, 3);
-//             let summon_doggy_event =
-//                 CreatureSetEvent::new(state.player_a_id(), doggy_id, summon_at);
-
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_doggy_event, &mut state).await;
-//             });
-//         }
-
-//         let rock_updated_attack = state.board().creature_instance(buffed_id).attack();
-//         let rock_updated_health = state.board().creature_instance(buffed_id).health();
-
-//         assert_eq!(attack_start + 1, rock_updated_attack);
-//         assert_eq!(health_start + 1, rock_updated_health);
-//     }
-// }
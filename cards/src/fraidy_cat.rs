@@ -1,53 +1,76 @@
-use protocol::entities::{Id, Position};
-use salt_engine::cards::{CardDefinition, UnitCardDefinition};
+use protocol::entities::Tribe;
+use protocol::entities::{Keyword, Position};
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder, CreatureDefinition, CreatureDefinitionId,
+};
 
 #[derive(Debug, Clone)]
 pub struct FraidyCat;
 
 impl FraidyCat {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
-    }
-}
-
-impl CardDefinition for FraidyCat {
-    fn title(&self) -> &str {
-        "Fraidy Cat"
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("fce0b77e-9722-406b-930f-169438de3924")
     }
 
-    fn cost(&self) -> i32 {
-        3
-    }
-
-    fn flavor_text(&self) -> &str {
-        "todo"
-    }
-
-    fn text(&self) -> &str {
-        "Front.
-Hidden."
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Fraidy Cat".to_string())
+            .cost(3)
+            .text("Front.\nHidden.".to_string())
+            .flavor_text("todo".to_string())
+            .attack(2)
+            .health(4)
+            .width(1)
+            .placeable_at(Position::Front)
+            .tribe(Tribe::Cat)
+            .keyword(Keyword::Hidden)
+            .build()
     }
 }
 
-impl UnitCardDefinition for FraidyCat {
-    fn attack(&self) -> i32 {
-        2
-    }
+#[cfg(test)]
+mod tests {
+    use super::FraidyCat;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place, summon},
+        Pawn,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::{game_logic::events::AttackEvent, v2::CreatureInstance};
 
-    fn health(&self) -> i32 {
-        4
-    }
+    #[test]
+    fn summon_expects_hidden_until_it_fights() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_a = state.player_a_id();
+        let player_b = state.player_b_id();
 
-    fn row_width(&self) -> usize {
-        1
-    }
+        let cat = summon(
+            &mut state,
+            &mut dispatcher,
+            FraidyCat::id(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let is_hidden = |state: &salt_engine::game_state::game_state::GameState| {
+            state
+                .board()
+                .creature_by_id(cat)
+                .unwrap()
+                .get(CreatureInstance::is_hidden)
+        };
+        assert!(is_hidden(&state));
 
-    fn placeable_at(&self) -> Position {
-        Position::Front
-    }
+        let pawn = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        state.set_next_player_turn();
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(pawn, cat), &mut state)).unwrap();
 
-    fn is_hidden(&self) -> bool {
-        true
+        assert!(!is_hidden(&state));
     }
 }
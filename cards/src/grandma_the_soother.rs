@@ -1,285 +1,234 @@
 use async_trait::async_trait;
 use log::info;
-use protocol::entities::{CreatureInstanceId, Id, PlayerId, Position};
+use protocol::entities::{CreatureInstanceId, Position};
 use salt_engine::{
-    cards::{actions::UponEventAction, CardDefinition, UnitCardDefinition},
-    game_logic::{
-        events::{DrawCardEvent, GameEvent},
-        EventDispatcher,
+    game_logic::{event_dispatch::EventDispatcher, events::DrawCardEvent},
+    game_state::game_state::GameState,
+    v2::{
+        actions::UponCompanionDamagedAction, builder::CreatureDefinitionBuilder,
+        CreatureDefinition, CreatureDefinitionId, CreatureInstance,
     },
-    game_state::{board::BoardView, GameState},
 };
 
 #[derive(Debug, Clone)]
 pub struct GrandmaTheSoother;
 
 impl GrandmaTheSoother {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("e53c9f4d-9501-4e67-a22f-b27286bd08f5")
+    }
+
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Grandma The Soother".to_string())
+            .cost(2)
+            .text(
+                "Back.\nAfter her companion\ntakes damage and survives,\ndraw a card.".to_string(),
+            )
+            .flavor_text("todo".to_string())
+            .attack(0)
+            .health(2)
+            .width(1)
+            .placeable_at(Position::Back)
+            .upon_companion_damaged(GrandmasKissesAction)
+            .build()
     }
 }
 
-impl CardDefinition for GrandmaTheSoother {
-    fn title(&self) -> &str {
-        "Grandma The Soother"
+/// Draws a card if the companion took some damage and survived it.
+struct GrandmasKissesAction;
+
+#[async_trait]
+impl UponCompanionDamagedAction for GrandmasKissesAction {
+    async fn action(
+        &self,
+        instance_id: CreatureInstanceId,
+        companion_id: CreatureInstanceId,
+        damage_amount: usize,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        // During simultaneous damage a dying companion is still on the board, so check its health.
+        let survived = game_state
+            .board()
+            .creature_by_id(companion_id)
+            .is_some_and(|c| c.get(CreatureInstance::health) > 0);
+
+        if damage_amount == 0 || !survived {
+            return;
+        }
+        let Some(player_id) = game_state
+            .board()
+            .creature_pos(instance_id)
+            .map(|pos| pos.player_id)
+        else {
+            return;
+        };
+
+        info!("Grandma The Soother triggers card draw");
+        dispatcher
+            .dispatch_triggered(DrawCardEvent::new(player_id), game_state)
+            .await;
     }
+}
 
-    fn cost(&self) -> i32 {
-        2
+#[cfg(test)]
+mod tests {
+    use super::GrandmaTheSoother;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place},
+        AttackDog, Pawn, ReallyBigRock,
+    };
+    use protocol::entities::{BoardPos, CreatureInstanceId, RowId};
+    use salt_engine::{
+        game_logic::events::{
+            AttackEvent, CreatureTakesDamageEvent, GameEvent, PosTakesDamageEvent,
+        },
+        game_state::{game_state::GameState, CardInDeck},
+        v2::CreatureDefinitionId,
+    };
+
+    /// Grandma in the back row at index 0, and a full deck, returning the hand size.
+    fn setup_grandma(state: &mut GameState) -> usize {
+        let player_id = state.player_a_id();
+        place(
+            state,
+            GrandmaTheSoother::id(),
+            BoardPos::new(player_id, RowId::BackRow, 0),
+        );
+        state
+            .deck_mut(player_id)
+            .get_mut(|d| d.add_card(CardInDeck::new(Pawn::id())));
+
+        state.hand(player_id).len()
     }
 
-    fn flavor_text(&self) -> &str {
-        "todo"
+    fn resolve(state: &mut GameState, event: impl Into<GameEvent>) {
+        let mut dispatcher = make_dispatcher(state);
+        smol::block_on(dispatcher.dispatch(event, state)).unwrap();
     }
 
-    fn text(&self) -> &str {
-        "Back.
-After her companion
-takes damage and survives,
-draw a card."
+    fn damage(state: &mut GameState, creature_id: CreatureInstanceId, amount: usize) {
+        resolve(state, CreatureTakesDamageEvent::new(creature_id, amount));
     }
-}
 
-impl UnitCardDefinition for GrandmaTheSoother {
-    fn attack(&self) -> i32 {
-        0
+    /// A companion for Grandma at front row index 0, and an enemy across from it.
+    fn setup_fight(
+        state: &mut GameState,
+        companion: CreatureDefinitionId,
+        enemy: CreatureDefinitionId,
+    ) -> (CreatureInstanceId, CreatureInstanceId) {
+        let player_a = state.player_a_id();
+        let player_b = state.player_b_id();
+
+        (
+            place(
+                state,
+                companion,
+                BoardPos::new(player_a, RowId::FrontRow, 0),
+            ),
+            place(state, enemy, BoardPos::new(player_b, RowId::FrontRow, 0)),
+        )
     }
 
-    fn health(&self) -> i32 {
-        2
+    #[test]
+    fn when_companion_takes_damage_and_survives_expects_draws_card() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+        let hand_size = setup_grandma(&mut state);
+        let dog = place(
+            &mut state,
+            AttackDog::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 0),
+        );
+
+        damage(&mut state, dog, 1);
+
+        assert_eq!(hand_size + 1, state.hand(player_id).len());
     }
 
-    fn row_width(&self) -> usize {
-        1
+    #[test]
+    fn when_companion_takes_damage_and_dies_expects_not_draws_card() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+        let hand_size = setup_grandma(&mut state);
+        let pawn = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 0),
+        );
+
+        damage(&mut state, pawn, 1);
+
+        assert_eq!(hand_size, state.hand(player_id).len());
     }
 
-    fn placeable_at(&self) -> Position {
-        Position::Back
+    #[test]
+    fn when_non_companion_takes_damage_expects_not_draws_card() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+        let hand_size = setup_grandma(&mut state);
+        let dog = place(
+            &mut state,
+            AttackDog::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 1),
+        );
+
+        damage(&mut state, dog, 1);
+
+        assert_eq!(hand_size, state.hand(player_id).len());
     }
 
-    fn post_event_action(
-        &self,
-        card_instance_id: CreatureInstanceId,
-        event: &GameEvent,
-        game_state: &GameState,
-        _dispatcher: &mut EventDispatcher,
-    ) -> Option<Box<dyn UponEventAction>> {
-        let damage_event = if let GameEvent::CreatureTakesDamageEvent(e) = event {
-            e
-        } else {
-            return None;
-        };
+    #[test]
+    fn when_companion_dies_in_combat_expects_not_draws_card() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+        let hand_size = setup_grandma(&mut state);
+        let (pawn, dog) = setup_fight(&mut state, Pawn::id(), AttackDog::id());
 
-        let grandma_pos = game_state.board().pos_with_creature(card_instance_id);
-        let companion = game_state.board().companion_creature(grandma_pos)?;
+        resolve(&mut state, AttackEvent::new(pawn, dog));
 
-        if companion.id() == damage_event.creature_id() {
-            info!("Grandma's companion was damaged");
-            Some(Box::new(GrandmasKissesAction::new(grandma_pos.player_id))
-                as Box<dyn UponEventAction>)
-        } else {
-            None
-        }
+        assert_eq!(hand_size, state.hand(player_id).len());
     }
-}
 
-pub struct GrandmasKissesAction {
-    player_id: PlayerId,
-}
+    #[test]
+    fn when_companion_takes_no_damage_in_combat_expects_not_draws_card() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+        let hand_size = setup_grandma(&mut state);
+        let (dog, rock) = setup_fight(&mut state, AttackDog::id(), ReallyBigRock::id());
+
+        resolve(&mut state, AttackEvent::new(dog, rock));
 
-impl GrandmasKissesAction {
-    fn new(player_id: PlayerId) -> Self {
-        Self { player_id }
+        assert_eq!(hand_size, state.hand(player_id).len());
     }
-}
 
-#[async_trait]
-impl UponEventAction for GrandmasKissesAction {
-    async fn action(
-        &self,
-        _event: &GameEvent,
-        state: &mut GameState,
-        dispatcher: &mut EventDispatcher,
-    ) {
-        info!("Grandma The Soother triggers card draw");
-        let draw_card = DrawCardEvent::new(self.player_id);
-        dispatcher.dispatch(draw_card, state).await;
+    #[test]
+    fn when_area_damage_hits_companion_expects_draws_only_if_it_survives() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+        let hand_size = setup_grandma(&mut state);
+        let front = BoardPos::new(player_id, RowId::FrontRow, 0);
+        let grandma = BoardPos::new(player_id, RowId::BackRow, 0);
+
+        let pawn = place(&mut state, Pawn::id(), front);
+        resolve(
+            &mut state,
+            PosTakesDamageEvent::area(vec![front, grandma], 1),
+        );
+
+        assert!(state.board().creature_by_id(pawn).is_none());
+        assert_eq!(hand_size, state.hand(player_id).len());
+
+        place(&mut state, AttackDog::id(), front);
+        resolve(
+            &mut state,
+            PosTakesDamageEvent::area(vec![front, grandma], 1),
+        );
+
+        assert_eq!(hand_size + 1, state.hand(player_id).len());
     }
 }
-
-// #[cfg(test)]
-// mod tests {
-
-//     use super::*;
-//     use crate::{
-//         tests::{make_dispatcher, make_test_state},
-//         IndoorCat, Pawn,
-//     };
-//     use protocol::entities::{BoardPos, RowId};
-//     use salt_engine::game_logic::events::{
-//         CreatureSummonedFromHandEvent, CreatureTakesDamageEvent,
-//     };
-
-//     #[test]
-//     fn when_companion_takes_damage_and_survives_expects_draws_card() {
-//         let _ = env_logger::builder().is_test(true).try_init();
-//         let mut state = make_test_state();
-//         let mut dispatcher = make_dispatcher(state.player_a_id(), state.player_b_id());
-//         let player_id = state.player_a_id();
-
-//         // Summon a pawn to receive the buff
-//         let cat = IndoorCat.make_instance();
-//         let cat_id = cat.id();
-//         {
-//             let hand = state.hand_mut(player_id);
-//             hand.add_card(cat);
-
-//             let pawn_pos = BoardPos::new(player_id, RowId::FrontRow, 0);
-//             let summon_event = CreatureSummonedFromHandEvent::new(player_id, pawn_pos, cat_id);
-
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_event, &mut state).await;
-//             });
-//         }
-
-//         // Summon grandma
-//         {
-//             let grandma = GrandmaTheSoother.make_instance();
-//             let grandma_id = grandma.id();
-//             let hand = state.hand_mut(player_id);
-//             let grandma_pos = BoardPos::new(player_id, RowId::BackRow, 0);
-//             hand.add_card(grandma);
-//             let summon_event =
-//                 CreatureSummonedFromHandEvent::new(player_id, grandma_pos, grandma_id);
-
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_event, &mut state).await;
-//             });
-//         }
-
-//         let original_hand_size = state.hand(player_id).len();
-
-//         // Damage the pawn (to trigger Grandma)
-//         let damage_event = CreatureTakesDamageEvent::new(cat_id, 1);
-//         smol::block_on(async {
-//             dispatcher.dispatch(damage_event, &mut state).await;
-//         });
-
-//         let hand_size_after = state.hand(player_id).len();
-
-//         assert_eq!(
-//             hand_size_after,
-//             original_hand_size + 1,
-//             "Expected the hand size to be higher since Grandma should have been triggered."
-//         );
-//     }
-
-//     #[test]
-//     fn when_companion_takes_damage_and_dies_expects_not_draws_card() {
-//         let _ = env_logger::builder().is_test(true).try_init();
-//         let mut state = make_test_state();
-//         let mut dispatcher = make_dispatcher(state.player_a_id(), state.player_b_id());
-//         let player_id = state.player_a_id();
-
-//         // Summon a pawn to receive the buff
-//         let pawn = Pawn.make_instance();
-//         let pawn_id = pawn.id();
-//         {
-//             let hand = state.hand_mut(player_id);
-//             hand.add_card(pawn);
-
-//             let pawn_pos = BoardPos::new(player_id, RowId::FrontRow, 0);
-//             let summon_event = CreatureSummonedFromHandEvent::new(player_id, pawn_pos, pawn_id);
-
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_event, &mut state).await;
-//             });
-//         }
-
-//         // Summon grandma
-//         info!("Summoning grandma");
-//         {
-//             let grandma = GrandmaTheSoother.make_instance();
-//             let grandma_id = grandma.id();
-//             let hand = state.hand_mut(player_id);
-//             let grandma_pos = BoardPos::new(player_id, RowId::BackRow, 0);
-//             hand.add_card(grandma);
-//             let summon_event =
-//                 CreatureSummonedFromHandEvent::new(player_id, grandma_pos, grandma_id);
-
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_event, &mut state).await;
-//             });
-//         }
-
-//         let original_hand_size = state.hand(player_id).len();
-
-//         // Damage the pawn (to trigger Grandma)
-//         let damage_event = CreatureTakesDamageEvent::new(pawn_id, 1);
-//         smol::block_on(async {
-//             dispatcher.dispatch(damage_event, &mut state).await;
-//         });
-
-//         let hand_size_after = state.hand(player_id).len();
-
-//         assert_eq!(
-//             hand_size_after, original_hand_size,
-//             "Expected the hand size to be the same since grandma should not have triggered"
-//         );
-//     }
-
-//     #[test]
-//     fn when_non_companion_takes_damage_expects_not_draws_card() {
-//         let _ = env_logger::builder().is_test(true).try_init();
-//         let mut state = make_test_state();
-//         let mut dispatcher = make_dispatcher(state.player_a_id(), state.player_b_id());
-//         let player_id = state.player_a_id();
-
-//         // Summon a card to receive the buff
-//         let cat = IndoorCat.make_instance();
-//         let cat_id = cat.id();
-//         {
-//             let hand = state.hand_mut(player_id);
-//             hand.add_card(cat);
-
-//             let cat_pos = BoardPos::new(player_id, RowId::FrontRow, 0);
-//             let summon_event = CreatureSummonedFromHandEvent::new(player_id, cat_pos, cat_id);
-
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_event, &mut state).await;
-//             });
-//         }
-
-//         // Summon grandma
-//         info!("Summoning grandma");
-//         {
-//             let grandma = GrandmaTheSoother.make_instance();
-//             let grandma_id = grandma.id();
-//             let hand = state.hand_mut(player_id);
-//             let grandma_pos = BoardPos::new(player_id, RowId::BackRow, 1);
-//             hand.add_card(grandma);
-//             let summon_event =
-//                 CreatureSummonedFromHandEvent::new(player_id, grandma_pos, grandma_id);
-
-//             smol::block_on(async {
-//                 dispatcher.dispatch(summon_event, &mut state).await;
-//             });
-//         }
-
-//         let original_hand_size = state.hand(player_id).len();
-
-//         // Damage the pawn (to trigger Grandma)
-//         let damage_event = CreatureTakesDamageEvent::new(cat_id, 1);
-//         smol::block_on(async {
-//             dispatcher.dispatch(damage_event, &mut state).await;
-//         });
-
-//         let hand_size_after = state.hand(player_id).len();
-
-//         assert_eq!(
-//             hand_size_after, original_hand_size,
-//             "Expected the hand size to be the same since grandma should not have triggered"
-//         );
-//     }
-// }
@@ -1,56 +1,108 @@
-use protocol::entities::{Id, Position};
-use salt_engine::cards::{CardDefinition, UnitCardDefinition};
+use protocol::entities::Tribe;
+use protocol::entities::{Keyword, Position};
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder,
+    templates::{Effect, TemplateBehaviour, TriggerTemplate, When},
+    CreatureDefinition, CreatureDefinitionId,
+};
 
 #[derive(Debug, Clone)]
 pub struct IndoorCat;
 
 impl IndoorCat {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("c6a23979-5287-444e-ba8a-8bea2eda5557")
     }
-}
 
-impl CardDefinition for IndoorCat {
-    fn title(&self) -> &str {
-        "Indoor Cat"
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Indoor Cat".to_string())
+            .cost(3)
+            .text("Front.\nHidden.\nIf revealed during\nyour turn, gains\n+1/+1.".to_string())
+            .flavor_text("todo".to_string())
+            .attack(2)
+            .health(3)
+            .width(1)
+            .placeable_at(Position::Front)
+            .tribe(Tribe::Cat)
+            .keyword(Keyword::Hidden)
+            .templates(TemplateBehaviour {
+                triggers: vec![TriggerTemplate {
+                    when: When::RevealedDuringOwnersTurn,
+                    effects: vec![Effect::GainStats {
+                        attack: 1,
+                        health: 1,
+                    }],
+                }],
+                ..TemplateBehaviour::default()
+            })
+            .build()
     }
+}
 
-    fn cost(&self) -> i32 {
-        3
-    }
+#[cfg(test)]
+mod tests {
+    use super::IndoorCat;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place, stats},
+        Pawn,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::game_logic::events::AttackEvent;
 
-    fn flavor_text(&self) -> &str {
-        "todo"
-    }
+    #[test]
+    fn attack_when_revealed_on_own_turn_expects_buffed_once() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_a = state.player_a_id();
+        let player_b = state.player_b_id();
 
-    fn text(&self) -> &str {
-        "Front.
-Hidden.
-If revealed during
-your turn, gains
-+1/+1."
-    }
-}
+        let cat = place(
+            &mut state,
+            IndoorCat::id(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let hero_b = state.board().player_hero(player_b).get(|h| h.instance_id());
 
-impl UnitCardDefinition for IndoorCat {
-    fn attack(&self) -> i32 {
-        2
-    }
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(cat, hero_b), &mut state)).unwrap();
+        assert_eq!(Some((3, 4)), stats(&state, cat));
 
-    fn health(&self) -> i32 {
-        3
-    }
+        let pawn = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        state.set_next_player_turn();
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(pawn, cat), &mut state)).unwrap();
 
-    fn row_width(&self) -> usize {
-        1
+        // Already revealed, so only the pawn's damage changes the cat.
+        assert_eq!(Some((3, 3)), stats(&state, cat));
     }
 
-    fn placeable_at(&self) -> Position {
-        Position::Front
-    }
+    #[test]
+    fn attack_when_revealed_on_enemy_turn_expects_no_buff() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_a = state.player_a_id();
+        let player_b = state.player_b_id();
+
+        let cat = place(
+            &mut state,
+            IndoorCat::id(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let pawn = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        state.set_next_player_turn();
+
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(pawn, cat), &mut state)).unwrap();
 
-    fn is_hidden(&self) -> bool {
-        true
+        assert_eq!(Some((2, 2)), stats(&state, cat));
     }
 }
@@ -7,108 +7,173 @@
     clippy::cast_lossless,
     clippy::similar_names,
     clippy::module_inception,
-    dead_code
+    clippy::struct_field_names,
+    dead_code,
+    clippy::redundant_closure_for_method_calls
 )]
 
-// mod attack_dog;
-// mod emotional_support_dog;
-// mod fraidy_cat;
-// mod grandma_the_soother;
-// mod indoor_cat;
-// mod outdoor_cat;
-// mod pawn;
-// mod popcorn_vendor;
-// mod priest_of_the_lowland;
-// mod really_big_rock;
-// mod rickety_cannon;
-// mod sleeping_dog;
-
-// pub use attack_dog::AttackDog;
-// pub use emotional_support_dog::EmotionalSupportDog;
-// pub use fraidy_cat::FraidyCat;
-// pub use grandma_the_soother::GrandmaTheSoother;
-// pub use indoor_cat::IndoorCat;
-// pub use outdoor_cat::OutdoorCat;
-// pub use pawn::Pawn;
-// pub use popcorn_vendor::PopcornVendor;
-// pub use priest_of_the_lowland::PriestOfTheLowland;
-// pub use really_big_rock::ReallyBigRock;
-// pub use rickety_cannon::RicketyCannon;
-// pub use sleeping_dog::SleepingDog;
-
-// #[cfg(test)]
-// mod tests {
-//     use std::sync::Arc;
-
-//     use async_trait::async_trait;
-//     use mockall::mock;
-//     use protocol::{
-//         entities::{BoardPos, PlayerId},
-//         from_server::{Notification, VisualEvent},
-//     };
-//     use salt_engine::{
-//         cards::UnitCardDefinition,
-//         game_agent::{ClientNotifier, Prompter},
-//         game_logic::EventDispatcher,
-//         game_state::{Deck, GameState, GameStatePlayerView},
-//     };
-
-//     use crate::Pawn;
-
-//     mock! {
-//         pub(crate) TestPrompter {}
-//         impl Prompter for TestPrompter {
-//             fn prompt_slot(&self) -> BoardPos;
-//             fn prompt_player_slot(&self) -> BoardPos;
-//             fn prompt_opponent_slot(&self) -> BoardPos;
-//             fn prompt_creature_pos(&self) -> BoardPos;
-//             fn prompt_player_creature_pos(&self) -> BoardPos;
-//             fn prompt_opponent_creature_pos(&self) -> BoardPos;
-//         }
-//     }
-
-//     struct TestClientNotifier;
-
-//     #[async_trait]
-//     impl ClientNotifier for TestClientNotifier {
-//         async fn notify(&self, _event: Notification) {
-//             // Doing nothing for tests
-//         }
-//     }
-
-//     pub fn make_test_state() -> GameState {
-//         let player_a_deck = Deck::new(vec![Pawn.make_instance()]);
-//         let player_b_deck = Deck::new(vec![Pawn.make_instance()]);
-
-//         let mut state = GameState::initial_state(
-//             PlayerId::new(),
-//             player_a_deck,
-//             PlayerId::new(),
-//             player_b_deck,
-//         );
-
-//         state.raise_mana_limit(state.player_a_id(), 10);
-//         state.raise_mana_limit(state.player_b_id(), 10);
-//         state.refresh_player_mana(state.player_a_id());
-//         state.refresh_player_mana(state.player_b_id());
-
-//         state
-//     }
-
-//     // pub fn make_dispatcher(player_a_id: PlayerId, player_b_id: PlayerId) -> EventDispatcher {
-//     //     let notifier_a = Arc::new(TestClientNotifier);
-//     //     let notifier_b = Arc::new(TestClientNotifier);
-
-//     //     let prompter_a = Arc::new(MockTestPrompter::new());
-//     //     let prompter_b = Arc::new(MockTestPrompter::new());
-
-//     //     EventDispatcher::new(
-//     //         notifier_a,
-//     //         prompter_a,
-//     //         player_a_id,
-//     //         notifier_b,
-//     //         prompter_b,
-//     //         player_b_id,
-//     //     )
-//     // }
-// }
+mod actions;
+mod attack_dog;
+mod bug;
+mod bug_collector;
+mod bug_swarm;
+mod cannonball;
+mod companion;
+mod emotional_support_dog;
+mod fraidy_cat;
+mod grandma_the_soother;
+mod indoor_cat;
+mod outdoor_cat;
+mod pawn;
+mod popcorn_vendor;
+mod priest_of_the_lowland;
+mod really_big_rock;
+mod registry;
+mod rickety_cannon;
+mod sleeping_dog;
+
+pub use attack_dog::AttackDog;
+pub use bug::Bug;
+pub use bug_collector::BugCollector;
+pub use bug_swarm::BugSwarm;
+pub use cannonball::Cannonball;
+pub use emotional_support_dog::EmotionalSupportDog;
+pub use fraidy_cat::FraidyCat;
+pub use grandma_the_soother::GrandmaTheSoother;
+pub use indoor_cat::IndoorCat;
+pub use outdoor_cat::OutdoorCat;
+pub use pawn::Pawn;
+pub use popcorn_vendor::PopcornVendor;
+pub use priest_of_the_lowland::PriestOfTheLowland;
+pub use really_big_rock::ReallyBigRock;
+pub use rickety_cannon::RicketyCannon;
+pub use sleeping_dog::SleepingDog;
+
+pub use registry::card_registry;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::card_registry;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, PlayerId},
+        from_server::Notification,
+    };
+    use salt_engine::{
+        game_agent::{ClientNotifier, Prompter},
+        game_logic::{event_dispatch::EventDispatcher, events::CreatureSummonedFromHandEvent},
+        game_state::{game_state::GameState, CardInHand},
+        v2::{CreatureDefinitionId, CreatureInstance},
+    };
+    use std::sync::Arc;
+
+    mock! {
+        pub(crate) TestPrompter {}
+        impl Prompter for TestPrompter {
+            fn prompt_slot(&self) -> BoardPos;
+            fn prompt_player_slot(&self) -> BoardPos;
+            fn prompt_opponent_slot(&self) -> BoardPos;
+            fn prompt_creature_pos(&self) -> BoardPos;
+            fn prompt_player_creature_pos(&self) -> BoardPos;
+            fn prompt_opponent_creature_pos(&self) -> BoardPos;
+        }
+    }
+
+    struct TestClientNotifier;
+
+    #[async_trait]
+    impl ClientNotifier for TestClientNotifier {
+        async fn notify(&self, _event: Notification) {
+            // Doing nothing for tests
+        }
+    }
+
+    /// A game with every card registered, where both players have plenty of mana.
+    pub(crate) fn make_test_state() -> GameState {
+        let mut state = GameState::new(PlayerId::new(), PlayerId::new());
+        state.set_card_registry(card_registry());
+
+        for player_id in [state.player_a_id(), state.player_b_id()] {
+            state
+                .board_mut()
+                .player_hero_mut(player_id)
+                .get_mut(|h| h.set_mana(10));
+        }
+
+        state
+    }
+
+    pub(crate) fn make_dispatcher(state: &GameState) -> EventDispatcher {
+        make_dispatcher_with_prompter(state, MockTestPrompter::new())
+    }
+
+    /// A dispatcher where player A answers prompts through `prompter_a`.
+    pub(crate) fn make_dispatcher_with_prompter(
+        state: &GameState,
+        prompter_a: MockTestPrompter,
+    ) -> EventDispatcher {
+        EventDispatcher::new(
+            Arc::new(TestClientNotifier),
+            Arc::new(prompter_a),
+            state.player_a_id(),
+            Arc::new(TestClientNotifier),
+            Arc::new(MockTestPrompter::new()),
+            state.player_b_id(),
+        )
+    }
+
+    /// Summons the card from its owner's hand at `pos`, on the owner's turn.
+    pub(crate) fn summon(
+        state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+        definition_id: CreatureDefinitionId,
+        pos: BoardPos,
+    ) -> CreatureInstanceId {
+        let player_id = pos.player_id;
+        let card_id = CreatureInstanceId::new();
+
+        state
+            .hand_mut(player_id)
+            .add_card(CardInHand::new_with_id(card_id, definition_id));
+
+        if state.cur_player_turn() != player_id {
+            state.set_next_player_turn();
+        }
+
+        smol::block_on(dispatcher.dispatch(
+            CreatureSummonedFromHandEvent::new(player_id, pos, card_id),
+            state,
+        ))
+        .expect("Expected the summon to be valid.");
+
+        card_id
+    }
+
+    /// Sets a ready instance of the card at `pos`, without summoning it.
+    pub(crate) fn place(
+        state: &mut GameState,
+        definition_id: CreatureDefinitionId,
+        pos: BoardPos,
+    ) -> CreatureInstanceId {
+        let definition = state
+            .creature_definition(definition_id)
+            .expect("Expected the card to be registered.");
+        let mut creature =
+            CreatureInstance::new_from_definition(CreatureInstanceId::new(), definition);
+        creature.ready_for_turn();
+        let instance_id = creature.instance_id();
+
+        state.board_mut().set_creature_at_pos(creature, pos);
+
+        instance_id
+    }
+
+    /// The attack and health of the creature, if it is on the board.
+    pub(crate) fn stats(state: &GameState, instance_id: CreatureInstanceId) -> Option<(i32, i32)> {
+        state
+            .board()
+            .creature_by_id(instance_id)
+            .map(|c| c.get(|c| (c.attack(), c.health())))
+    }
+}
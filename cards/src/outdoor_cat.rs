@@ -1,56 +1,101 @@
-use protocol::entities::{Id, Position};
-use salt_engine::cards::{CardDefinition, UnitCardDefinition};
+use protocol::entities::Tribe;
+use protocol::entities::{Keyword, Position};
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder,
+    templates::{Effect, TemplateBehaviour, TriggerTemplate, When},
+    CreatureDefinition, CreatureDefinitionId,
+};
 
 #[derive(Debug, Clone)]
 pub struct OutdoorCat;
 
 impl OutdoorCat {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("18a64680-9bd9-476b-a785-fc01f697c05d")
     }
-}
 
-impl CardDefinition for OutdoorCat {
-    fn title(&self) -> &str {
-        "Outdoor Cat"
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Outdoor Cat".to_string())
+            .cost(3)
+            .text(
+                "Front.\nHidden.\nIf revealed during\nthe enemy's turn, gains\n+1/+1.".to_string(),
+            )
+            .flavor_text("todo".to_string())
+            .attack(3)
+            .health(2)
+            .width(1)
+            .placeable_at(Position::Front)
+            .tribe(Tribe::Cat)
+            .keyword(Keyword::Hidden)
+            .templates(TemplateBehaviour {
+                triggers: vec![TriggerTemplate {
+                    when: When::RevealedDuringEnemysTurn,
+                    effects: vec![Effect::GainStats {
+                        attack: 1,
+                        health: 1,
+                    }],
+                }],
+                ..TemplateBehaviour::default()
+            })
+            .build()
     }
+}
 
-    fn cost(&self) -> i32 {
-        3
-    }
+#[cfg(test)]
+mod tests {
+    use super::OutdoorCat;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place, stats},
+        Pawn,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::game_logic::events::AttackEvent;
 
-    fn flavor_text(&self) -> &str {
-        "todo"
-    }
+    #[test]
+    fn attack_when_revealed_on_enemy_turn_expects_buffed() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_a = state.player_a_id();
+        let player_b = state.player_b_id();
 
-    fn text(&self) -> &str {
-        "Front.
-Hidden.
-If revealed during
-the enemy's turn, gains
-+1/+1."
-    }
-}
+        let cat = place(
+            &mut state,
+            OutdoorCat::id(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let pawn = place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        state.set_next_player_turn();
 
-impl UnitCardDefinition for OutdoorCat {
-    fn attack(&self) -> i32 {
-        3
-    }
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(pawn, cat), &mut state)).unwrap();
 
-    fn health(&self) -> i32 {
-        2
+        assert_eq!(Some((4, 2)), stats(&state, cat));
+        assert_eq!(None, stats(&state, pawn));
     }
 
-    fn row_width(&self) -> usize {
-        1
-    }
+    #[test]
+    fn attack_when_revealed_on_own_turn_expects_no_buff() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_a = state.player_a_id();
+        let player_b = state.player_b_id();
 
-    fn placeable_at(&self) -> Position {
-        Position::Front
-    }
+        let cat = place(
+            &mut state,
+            OutdoorCat::id(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let hero_b = state.board().player_hero(player_b).get(|h| h.instance_id());
+
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(cat, hero_b), &mut state)).unwrap();
 
-    fn is_hidden(&self) -> bool {
-        true
+        assert_eq!(Some((3, 2)), stats(&state, cat));
     }
 }
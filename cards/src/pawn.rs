@@ -1,48 +1,70 @@
-use protocol::entities::{Id, Position};
-use salt_engine::cards::{CardDefinition, UnitCardDefinition};
+use protocol::entities::{Position, Tribe};
+use salt_engine::v2::{
+    builder::CreatureDefinitionBuilder, CreatureDefinition, CreatureDefinitionId,
+};
 
 #[derive(Debug, Clone)]
 pub struct Pawn;
 
 impl Pawn {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
-    }
-}
-
-impl CardDefinition for Pawn {
-    fn title(&self) -> &str {
-        "Pawn"
-    }
-
-    fn cost(&self) -> i32 {
-        1
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("1a46170d-dbff-49ca-ab03-f7d7384787d2")
     }
 
-    fn flavor_text(&self) -> &str {
-        "Just a lowly Pawn."
-    }
-
-    fn text(&self) -> &str {
-        "Front"
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Pawn".to_string())
+            .cost(1)
+            .text("Front".to_string())
+            .flavor_text("Just a lowly Pawn.".to_string())
+            .attack(1)
+            .health(1)
+            .width(1)
+            .placeable_at(Position::Front)
+            .tribe(Tribe::ChessPiece)
+            .build()
     }
 }
 
-impl UnitCardDefinition for Pawn {
-    fn attack(&self) -> i32 {
-        1
-    }
+#[cfg(test)]
+mod tests {
+    use super::Pawn;
+    use crate::tests::make_test_state;
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, Position, RowId},
+        rule_violation::RuleViolation,
+    };
+    use salt_engine::{
+        game_logic::events::{CreatureSummonedFromHandEvent, Event},
+        game_state::CardInHand,
+    };
 
-    fn health(&self) -> i32 {
-        1
-    }
+    #[test]
+    fn summon_when_back_row_expects_not_placeable() {
+        let mut state = make_test_state();
+        let player_id = state.player_a_id();
+        let card_id = CreatureInstanceId::new();
+        state
+            .hand_mut(player_id)
+            .add_card(CardInHand::new_with_id(card_id, Pawn::id()));
 
-    fn row_width(&self) -> usize {
-        1
-    }
+        let back = BoardPos::new(player_id, RowId::BackRow, 0);
+        let front = BoardPos::new(player_id, RowId::FrontRow, 0);
 
-    fn placeable_at(&self) -> Position {
-        Position::Front
+        assert_eq!(
+            Err(RuleViolation::NotPlaceableAt {
+                pos: back,
+                placeable_at: Position::Front
+            }),
+            CreatureSummonedFromHandEvent::new(player_id, back, card_id).validate(&state)
+        );
+        assert!(
+            CreatureSummonedFromHandEvent::new(player_id, front, card_id)
+                .validate(&state)
+                .is_ok()
+        );
     }
 }
@@ -1,91 +1,126 @@
 use async_trait::async_trait;
 use log::info;
-use protocol::entities::{CreatureInstanceId, Id, Position};
+use protocol::entities::{CreatureInstanceId, Position};
 use salt_engine::{
-    cards::{actions::UponTurnEndAction, CardDefinition, UnitCardDefinition},
-    game_logic::{events::CreatureHealedEvent, EventDispatcher},
-    game_state::{board::BoardView, GameState},
+    game_logic::{
+        event_dispatch::EventDispatcher,
+        events::{CreatureHealedEvent, GameEvent},
+    },
+    game_state::game_state::GameState,
+    v2::{
+        actions::{EventTrigger, UponEventAction},
+        builder::CreatureDefinitionBuilder,
+        CreatureDefinition, CreatureDefinitionId,
+    },
 };
 
 #[derive(Debug, Clone)]
 pub struct PriestOfTheLowland;
 
 impl PriestOfTheLowland {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
-    }
-}
-
-impl CardDefinition for PriestOfTheLowland {
-    fn title(&self) -> &str {
-        "Priest of the Lowland"
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("56ecf1d6-0766-4b6c-b24f-8dad2bc12f07")
     }
 
-    fn cost(&self) -> i32 {
-        1
-    }
-
-    fn flavor_text(&self) -> &str {
-        "todo"
-    }
-
-    fn text(&self) -> &str {
-        "Back
-Heal the Companion
-for 2 health at
-the end of your turn."
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Priest of the Lowland".to_string())
+            .cost(1)
+            .text("Back\nHeal the Companion\nfor 2 health at\nthe end of your turn.".to_string())
+            .flavor_text("todo".to_string())
+            .attack(0)
+            .health(2)
+            .width(1)
+            .placeable_at(Position::Back)
+            .pre_event_action(TurnEndTrigger)
+            .build()
     }
 }
 
-impl UnitCardDefinition for PriestOfTheLowland {
-    fn attack(&self) -> i32 {
-        0
-    }
+struct TurnEndTrigger;
 
-    fn health(&self) -> i32 {
-        2
-    }
-
-    fn row_width(&self) -> usize {
-        1
-    }
+impl EventTrigger for TurnEndTrigger {
+    fn trigger(
+        &self,
+        instance_id: CreatureInstanceId,
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>> {
+        let GameEvent::EndTurnEvent(end_turn) = event else {
+            return None;
+        };
+
+        if game_state.board().creature_pos(instance_id)?.player_id != end_turn.0 {
+            return None;
+        }
 
-    fn placeable_at(&self) -> Position {
-        Position::Back
-    }
+        let companion_ids = game_state.board().companions_of(instance_id);
+        if companion_ids.is_empty() {
+            return None;
+        }
 
-    fn upon_turn_end(&self) -> Box<dyn UponTurnEndAction> {
-        Box::new(TurnEndAction)
+        Some(Box::new(HealCompanionAction { companion_ids }))
     }
 }
 
-struct TurnEndAction;
+/// Heals every companion, since a wide priest or a wide companion can give the priest several.
+struct HealCompanionAction {
+    companion_ids: Vec<CreatureInstanceId>,
+}
 
 #[async_trait]
-impl UponTurnEndAction for TurnEndAction {
+impl UponEventAction for HealCompanionAction {
     async fn action(
         &self,
-        instance_id: CreatureInstanceId,
-        state: &mut GameState,
+        _event: &GameEvent,
+        game_state: &mut GameState,
         dispatcher: &mut EventDispatcher,
     ) {
-        let instance_pos = state.board().pos_with_creature(instance_id);
-
-        if let Some(companion) = state.board().companion_creature(instance_pos) {
-            // Heal the target for 2
-            let heal_amount = 2;
-            let heal_event = CreatureHealedEvent::new(companion.id(), heal_amount);
-
-            {
-                let target_creature = state.board().creature_instance(companion.id());
-                info!(
-                    "Priest of the Lowland heals companion {} for 2",
-                    target_creature.definition().title()
-                );
-            }
-
-            dispatcher.dispatch(heal_event, state).await;
+        for companion_id in &self.companion_ids {
+            info!("Priest of the Lowland heals {:?}", companion_id);
+            dispatcher
+                .dispatch_triggered(CreatureHealedEvent::new(*companion_id, 2), game_state)
+                .await;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PriestOfTheLowland;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place, stats},
+        AttackDog,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::game_logic::events::{CreatureTakesDamageEvent, EndTurnEvent};
+
+    #[test]
+    fn end_turn_expects_companion_healed() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_id = state.player_a_id();
+
+        let dog = place(
+            &mut state,
+            AttackDog::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 2),
+        );
+        place(
+            &mut state,
+            PriestOfTheLowland::id(),
+            BoardPos::new(player_id, RowId::BackRow, 2),
+        );
+
+        smol::block_on(dispatcher.dispatch(CreatureTakesDamageEvent::new(dog, 2), &mut state))
+            .unwrap();
+        assert_eq!(Some((5, 1)), stats(&state, dog));
+
+        smol::block_on(dispatcher.dispatch(EndTurnEvent(player_id), &mut state)).unwrap();
+
+        assert_eq!(Some((5, 3)), stats(&state, dog));
+    }
+}
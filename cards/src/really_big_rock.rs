@@ -1,65 +1,44 @@
 use async_trait::async_trait;
-use protocol::entities::{BoardPos, CreatureInstanceId, Id, Position, RowId};
+use protocol::entities::{BoardPos, CreatureInstanceId, Keyword, Position, RowId};
 use salt_engine::{
-    cards::{actions::UponDeathAction, CardDefinition, UnitCardDefinition},
-    game_logic::{events::PosTakesDamageEvent, EventDispatcher},
-    game_state::{board::BoardView, GameState},
+    game_logic::{event_dispatch::EventDispatcher, events::PosTakesDamageEvent},
+    game_state::game_state::GameState,
+    v2::{
+        actions::UponDeathAction, builder::CreatureDefinitionBuilder, CreatureDefinition,
+        CreatureDefinitionId,
+    },
 };
 
 #[derive(Debug, Clone)]
 pub struct ReallyBigRock;
 
 impl ReallyBigRock {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
-    }
-}
-
-impl CardDefinition for ReallyBigRock {
-    fn title(&self) -> &str {
-        "Really Big Rock"
-    }
-
-    fn cost(&self) -> i32 {
-        3
-    }
-
-    fn flavor_text(&self) -> &str {
-        "Not just a boulder."
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("951424f2-3c90-474d-8c43-1b4fd25561de")
     }
 
-    fn text(&self) -> &str {
-        "Defender\nOn death, deal\n1 damage to slots\n behind this."
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Really Big Rock".to_string())
+            .cost(3)
+            .text("Defender\nOn death, deal\n1 damage to slots\n behind this.".to_string())
+            .flavor_text("Not just a boulder.".to_string())
+            .attack(0)
+            //4
+            .health(1)
+            .width(WIDTH)
+            .placeable_at(Position::Either)
+            .keyword(Keyword::Defender)
+            .upon_death(DeathAction)
+            .build()
     }
 }
 
-impl UnitCardDefinition for ReallyBigRock {
-    fn attack(&self) -> i32 {
-        0
-    }
-
-    fn health(&self) -> i32 {
-        //4
-        1
-    }
-
-    fn row_width(&self) -> usize {
-        2
-    }
-
-    fn is_defender(&self) -> bool {
-        true
-    }
-
-    fn placeable_at(&self) -> Position {
-        Position::Either
-    }
-
-    fn upon_death(&self) -> Box<dyn salt_engine::cards::actions::UponDeathAction> {
-        Box::new(DeathAction)
-    }
-}
+/// The rock covers this many slots, and so damages up to this many behind it.
+const WIDTH: usize = 2;
 
 struct DeathAction;
 
@@ -67,25 +46,62 @@ struct DeathAction;
 impl UponDeathAction for DeathAction {
     async fn action(
         &self,
-        instance_id: CreatureInstanceId,
-        died_at_pos: BoardPos,
-        state: &mut GameState,
+        _instance_id: CreatureInstanceId,
+        pos: BoardPos,
+        game_state: &mut GameState,
         dispatcher: &mut EventDispatcher,
     ) {
-        if died_at_pos.row_id != RowId::FrontRow {
+        if pos.row_id != RowId::FrontRow {
             return;
         }
 
-        let instance = state.board().creature_instance(instance_id);
-
-        let width = instance.width();
+        let behind = (0..WIDTH)
+            .map(|i| BoardPos::new(pos.player_id, RowId::BackRow, pos.row_index + i))
+            .collect();
 
-        for i in 0..width {
-            let index = died_at_pos.row_index + i;
-            let behind_pos = BoardPos::new(died_at_pos.player_id, RowId::BackRow, index);
-            let event = PosTakesDamageEvent::new(behind_pos, 1);
+        dispatcher
+            .dispatch_triggered(PosTakesDamageEvent::area(behind, 1), game_state)
+            .await;
+    }
+}
 
-            dispatcher.dispatch(event, state).await;
-        }
+#[cfg(test)]
+mod tests {
+    use super::ReallyBigRock;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place, stats},
+        AttackDog,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::game_logic::events::CreatureTakesDamageEvent;
+
+    #[test]
+    fn death_when_front_row_expects_slots_behind_damaged() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_id = state.player_a_id();
+
+        let rock = place(
+            &mut state,
+            ReallyBigRock::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 1),
+        );
+        let behind = place(
+            &mut state,
+            AttackDog::id(),
+            BoardPos::new(player_id, RowId::BackRow, 2),
+        );
+        let beside = place(
+            &mut state,
+            AttackDog::id(),
+            BoardPos::new(player_id, RowId::BackRow, 3),
+        );
+
+        smol::block_on(dispatcher.dispatch(CreatureTakesDamageEvent::new(rock, 1), &mut state))
+            .unwrap();
+
+        assert_eq!(None, stats(&state, rock));
+        assert_eq!(Some((5, 2)), stats(&state, behind));
+        assert_eq!(Some((5, 3)), stats(&state, beside));
     }
 }
@@ -0,0 +1,91 @@
+use crate::{
+    AttackDog, Bug, BugCollector, BugSwarm, Cannonball, EmotionalSupportDog, FraidyCat,
+    GrandmaTheSoother, IndoorCat, OutdoorCat, Pawn, PopcornVendor, PriestOfTheLowland,
+    ReallyBigRock, RicketyCannon, SleepingDog,
+};
+use salt_engine::v2::CardRegistry;
+
+/// A registry holding every card and hero power in the game, each under its fixed id.
+#[must_use]
+pub fn card_registry() -> CardRegistry {
+    let mut registry = CardRegistry::new();
+
+    for definition in [
+        AttackDog::definition(),
+        Bug::definition(),
+        BugCollector::definition(),
+        Cannonball::definition(),
+        EmotionalSupportDog::definition(),
+        FraidyCat::definition(),
+        GrandmaTheSoother::definition(),
+        IndoorCat::definition(),
+        OutdoorCat::definition(),
+        Pawn::definition(),
+        PopcornVendor::definition(),
+        PriestOfTheLowland::definition(),
+        ReallyBigRock::definition(),
+        RicketyCannon::definition(),
+        SleepingDog::definition(),
+    ] {
+        registry.register(definition);
+    }
+
+    registry.register_hero_power(BugSwarm::definition());
+
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::card_registry;
+    use crate::{
+        AttackDog, Bug, BugCollector, BugSwarm, Cannonball, EmotionalSupportDog, FraidyCat,
+        GrandmaTheSoother, IndoorCat, OutdoorCat, Pawn, PopcornVendor, PriestOfTheLowland,
+        ReallyBigRock, RicketyCannon, SleepingDog,
+    };
+
+    #[test]
+    fn card_registry_expects_cards_under_fixed_ids() {
+        let registry = card_registry();
+
+        let pawn = registry
+            .get(Pawn::id())
+            .expect("Expected Pawn to be registered.");
+
+        assert_eq!("Pawn", pawn.title());
+        assert_eq!(
+            Pawn::id(),
+            card_registry().get(Pawn::id()).unwrap().definition_id()
+        );
+    }
+
+    #[test]
+    fn card_registry_expects_every_card_registered() {
+        let registry = card_registry();
+
+        for id in [
+            AttackDog::id(),
+            Bug::id(),
+            BugCollector::id(),
+            Cannonball::id(),
+            EmotionalSupportDog::id(),
+            FraidyCat::id(),
+            GrandmaTheSoother::id(),
+            IndoorCat::id(),
+            OutdoorCat::id(),
+            Pawn::id(),
+            PopcornVendor::id(),
+            PriestOfTheLowland::id(),
+            ReallyBigRock::id(),
+            RicketyCannon::id(),
+            SleepingDog::id(),
+        ] {
+            assert!(
+                registry.get(id).is_some(),
+                "Expected {id:?} to be registered."
+            );
+        }
+        assert_eq!(15, registry.len());
+        assert!(registry.hero_power(BugSwarm::id()).is_some());
+    }
+}
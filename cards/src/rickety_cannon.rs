@@ -1,108 +1,150 @@
 use async_trait::async_trait;
 use log::info;
-use protocol::entities::{BoardPos, CreatureInstanceId, Id, Position};
+use protocol::entities::{BoardPos, CreatureInstanceId, InstanceState, Position};
 use salt_engine::{
-    cards::{
-        actions::{UponSummonAction, UponTurnStartAction},
-        CardDefinition, UnitCardDefinition,
+    game_logic::{
+        event_dispatch::EventDispatcher,
+        events::{GameEvent, PosTakesDamageEvent},
+    },
+    game_state::game_state::GameState,
+    v2::{
+        actions::{EventTrigger, UponEventAction, UponSummonAction},
+        builder::CreatureDefinitionBuilder,
+        CreatureDefinition, CreatureDefinitionId, CreatureInstance,
     },
-    game_logic::{events::PosTakesDamageEvent, EventDispatcher},
-    game_state::{board::BoardView, GameState, InstanceState, MakePlayerView},
 };
 
 #[derive(Debug, Clone)]
 pub struct RicketyCannon;
 
 impl RicketyCannon {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("5ffe5702-1811-4a62-8b41-724a466faad7")
     }
-}
 
-impl CardDefinition for RicketyCannon {
-    fn title(&self) -> &str {
-        "Rickety cannon"
-    }
-
-    fn cost(&self) -> i32 {
-        2
-    }
-
-    fn flavor_text(&self) -> &str {
-        "yep"
-    }
-
-    fn text(&self) -> &str {
-        "Back\nSummon: pick a slot.\nAt the start of\nyour turn, deal\n1 damage there."
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Rickety cannon".to_string())
+            .cost(2)
+            .text(
+                "Back\nSummon: pick a slot.\nAt the start of\nyour turn, deal\n1 damage there."
+                    .to_string(),
+            )
+            .flavor_text("yep".to_string())
+            .attack(0)
+            .health(2)
+            .width(3)
+            .placeable_at(Position::Back)
+            .upon_summon(SummonAction)
+            .post_event_action(TurnStartTrigger)
+            .build()
     }
 }
 
-impl UnitCardDefinition for RicketyCannon {
-    fn attack(&self) -> i32 {
-        0
-    }
-
-    fn health(&self) -> i32 {
-        2
-    }
-
-    fn row_width(&self) -> usize {
-        3
-    }
-
-    fn placeable_at(&self) -> Position {
-        Position::Back
-    }
+/// Asks the summoner which slot the cannon should aim at.
+struct SummonAction;
 
-    fn upon_summon(&self) -> Box<dyn UponSummonAction> {
-        Box::new(SummonAction)
-    }
+#[async_trait]
+impl UponSummonAction for SummonAction {
+    async fn action(
+        &self,
+        instance_id: CreatureInstanceId,
+        pos: BoardPos,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        let target = dispatcher.player_prompter(pos.player_id).prompt_slot();
+        info!("Rickety Cannon aims at {:?}", target);
 
-    fn upon_turn_start(&self) -> Box<dyn UponTurnStartAction> {
-        Box::new(TurnStartAction)
+        if let Some(mut cannon) = game_state.board_mut().creature_by_id_mut(instance_id) {
+            cannon.get_mut(|c| c.set_state(Some(InstanceState::Pos(target))));
+        }
     }
 }
 
-struct TurnStartAction;
+struct TurnStartTrigger;
 
-#[async_trait]
-impl UponTurnStartAction for TurnStartAction {
-    async fn action(
+impl EventTrigger for TurnStartTrigger {
+    fn trigger(
         &self,
         instance_id: CreatureInstanceId,
-        state: &mut GameState,
-        dispatcher: &mut EventDispatcher,
-    ) {
-        let instance = state.board().creature_instance(instance_id);
-        let cannon_target = instance.state();
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>> {
+        let GameEvent::TurnStartEvent(turn_start) = event else {
+            return None;
+        };
+
+        let board = game_state.board();
+        if board.creature_pos(instance_id)?.player_id != turn_start.0 {
+            return None;
+        }
 
-        if let Some(InstanceState::Pos(pos)) = cannon_target {
-            info!("Rickety Cannon fires a shot at {:?}", pos);
-            let damage_event = PosTakesDamageEvent::new(pos, 1);
-            dispatcher.dispatch(damage_event, state).await;
+        match board
+            .creature_by_id(instance_id)?
+            .get(CreatureInstance::state)
+        {
+            Some(InstanceState::Pos(target)) => Some(Box::new(FireAction { target })),
+            _ => None,
         }
     }
 }
 
-struct SummonAction;
+struct FireAction {
+    target: BoardPos,
+}
 
 #[async_trait]
-impl UponSummonAction for SummonAction {
+impl UponEventAction for FireAction {
     async fn action(
         &self,
-        _card_id: CreatureInstanceId,
-        summoned_to_pos: BoardPos,
-        state: &mut GameState,
+        _event: &GameEvent,
+        game_state: &mut GameState,
         dispatcher: &mut EventDispatcher,
     ) {
-        // let summoner = summoned_to_pos.player_id;
-
-        // let _pos = dispatcher
-        //     .player_prompter(summoned_to_pos.player_id)
-        //     .prompt_slot(&state.player_view(summoner));
+        info!("Rickety Cannon fires a shot at {:?}", self.target);
+        dispatcher
+            .dispatch_triggered(PosTakesDamageEvent::new(self.target, 1), game_state)
+            .await;
+    }
+}
 
-        // todo!();
-        // instance.set_state(Some(InstanceState::Pos(pos)));
+#[cfg(test)]
+mod tests {
+    use super::RicketyCannon;
+    use crate::{
+        tests::{
+            make_dispatcher_with_prompter, make_test_state, place, stats, summon, MockTestPrompter,
+        },
+        AttackDog,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::game_logic::events::TurnStartEvent;
+
+    #[test]
+    fn turn_start_expects_chosen_slot_damaged() {
+        let mut state = make_test_state();
+        let player_a = state.player_a_id();
+        let target_pos = BoardPos::new(state.player_b_id(), RowId::FrontRow, 4);
+
+        let mut prompter = MockTestPrompter::new();
+        prompter.expect_prompt_slot().return_const(target_pos);
+        let mut dispatcher = make_dispatcher_with_prompter(&state, prompter);
+
+        let dog = place(&mut state, AttackDog::id(), target_pos);
+        summon(
+            &mut state,
+            &mut dispatcher,
+            RicketyCannon::id(),
+            BoardPos::new(player_a, RowId::BackRow, 0),
+        );
+        assert_eq!(Some((5, 3)), stats(&state, dog));
+
+        smol::block_on(dispatcher.dispatch(TurnStartEvent(player_a), &mut state)).unwrap();
+
+        assert_eq!(Some((5, 2)), stats(&state, dog));
     }
 }
@@ -1,155 +1,167 @@
+use crate::actions::gain_stats;
 use async_trait::async_trait;
-use protocol::entities::{Id, Position};
+use protocol::entities::{BoardPos, CreatureInstanceId, Position, RowId, Tribe};
 use salt_engine::{
-    cards::{actions::UponReceiveDamageAction, CardDefinition, UnitCardDefinition},
-    game_logic::{Buff, EventDispatcher},
-    game_state::{board::BoardView, GameState},
+    game_logic::{
+        event_dispatch::EventDispatcher,
+        events::{CreatureMovedEvent, Event, GameEvent, MoveReason},
+    },
+    game_state::game_state::GameState,
+    v2::{
+        actions::{EventTrigger, UponEventAction},
+        builder::CreatureDefinitionBuilder,
+        CreatureDefinition, CreatureDefinitionId, CreatureInstance,
+    },
 };
 
-use self::actions::ReceiveDamageAction;
-
 #[derive(Debug, Clone)]
 pub struct SleepingDog;
 
 impl SleepingDog {
-    fn id(&self) -> Id {
-        // id::parse("...")
-        todo!()
-    }
-}
-
-impl CardDefinition for SleepingDog {
-    fn title(&self) -> &str {
-        "Sleeping Dog"
-    }
-
-    fn cost(&self) -> i32 {
-        3
-    }
-
-    fn flavor_text(&self) -> &str {
-        "todo"
+    #[must_use]
+    pub fn id() -> CreatureDefinitionId {
+        CreatureDefinitionId::parse_str("973b69a0-2b11-4f48-80ba-2f75640ba513")
     }
 
-    fn text(&self) -> &str {
-        "Back
-When damaged, if
-in back row,
-move to front row,
-and +7 attack."
+    #[must_use]
+    pub fn definition() -> CreatureDefinition {
+        CreatureDefinitionBuilder::new()
+            .definition_id(Self::id())
+            .title("Sleeping Dog".to_string())
+            .cost(3)
+            .text(
+                "Back\nWhen damaged, if\nin back row,\nmove to front row,\nand +7 attack."
+                    .to_string(),
+            )
+            .flavor_text("todo".to_string())
+            .attack(0)
+            .health(3)
+            .width(2)
+            .placeable_at(Position::Back)
+            .tribe(Tribe::Dog)
+            .post_event_action(WakeUpTrigger)
+            .build()
     }
 }
 
-impl UnitCardDefinition for SleepingDog {
-    fn attack(&self) -> i32 {
-        0
-    }
+/// Runs after the damage. A dog that died stays asleep, even while simultaneous damage
+/// keeps it on the board.
+struct WakeUpTrigger;
+
+impl EventTrigger for WakeUpTrigger {
+    fn trigger(
+        &self,
+        instance_id: CreatureInstanceId,
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>> {
+        let GameEvent::CreatureTakesDamageEvent(damage_event) = event else {
+            return None;
+        };
+
+        if damage_event.creature_id() != instance_id {
+            return None;
+        }
 
-    fn health(&self) -> i32 {
-        3
-    }
+        let health = game_state
+            .board()
+            .creature_by_id(instance_id)?
+            .get(CreatureInstance::health);
+        if health <= 0 {
+            return None;
+        }
 
-    fn row_width(&self) -> usize {
-        2
-    }
+        let pos = game_state.board().creature_pos(instance_id)?;
+        if pos.row_id != RowId::BackRow {
+            return None;
+        }
 
-    fn placeable_at(&self) -> Position {
-        Position::Back
-    }
+        // The dog wakes up only if it has room in front of it.
+        let in_front = BoardPos::new(pos.player_id, RowId::FrontRow, pos.row_index);
+        let move_event = CreatureMovedEvent::new(instance_id, in_front, MoveReason::OwnEffect);
+        move_event.validate(game_state).ok()?;
 
-    fn upon_receive_damage(&self) -> Box<dyn UponReceiveDamageAction> {
-        Box::new(ReceiveDamageAction)
+        Some(Box::new(WakeUpAction(move_event)))
     }
 }
 
-mod actions {
-    use super::{async_trait, buffs, BoardView, EventDispatcher, GameState};
-    use protocol::entities::{CreatureInstanceId, RowId};
-    use salt_engine::cards::actions::UponReceiveDamageAction;
-
-    pub(super) struct ReceiveDamageAction;
-
-    #[async_trait]
-    impl UponReceiveDamageAction for ReceiveDamageAction {
-        async fn action(
-            &self,
-            instance_id: CreatureInstanceId,
-            state: &mut GameState,
-            _dispatcher: &mut EventDispatcher,
-        ) {
-            // can move to front row?
-            let slots = state.board().slots_with_creature(instance_id);
-            let starting_pos = slots[0].pos();
-
-            for slot in slots {
-                if slot.pos().row() == RowId::FrontRow {
-                    // Can't move to front row if already in front row.
-                    return;
-                }
-
-                let mut in_front = slot.pos();
-                in_front.row_id = RowId::FrontRow;
-
-                if state.board().creature_at_pos(in_front).is_some() {
-                    // Can't moveto front row if something is already there.
-                    return;
-                }
-            }
-
-            // Take the card off the back row
-            let instance = state.board_mut().take_creature_by_id(instance_id);
-
-            // Put it on the front row
-            let mut front_slot = starting_pos;
-            front_slot.row_id = RowId::FrontRow;
-            state.board_mut().set_creature_at_pos(front_slot, instance);
-
-            let buff = Box::new(buffs::SleepingDogBuff::new(instance_id));
-            let instance_mut = state.board_mut().creature_instance_mut(instance_id);
-            instance_mut.add_buff(buff);
+struct WakeUpAction(CreatureMovedEvent);
+
+#[async_trait]
+impl UponEventAction for WakeUpAction {
+    async fn action(
+        &self,
+        _event: &GameEvent,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        // Another reaction may have taken the front row first.
+        if dispatcher
+            .dispatch(self.0.clone(), game_state)
+            .await
+            .is_ok()
+        {
+            gain_stats(game_state, self.0.creature_id(), 7, 0);
         }
     }
 }
 
-mod buffs {
-    use protocol::entities::{BuffInstanceId, BuffSourceId, CreatureInstanceId};
-
-    use super::{Buff, Id};
-
-    #[derive(Debug)]
-    pub(super) struct SleepingDogBuff {
-        instance_id: BuffInstanceId,
-        source_id: BuffSourceId,
+#[cfg(test)]
+mod tests {
+    use super::SleepingDog;
+    use crate::{
+        tests::{make_dispatcher, make_test_state, place, stats},
+        Pawn,
+    };
+    use protocol::entities::{BoardPos, RowId};
+    use salt_engine::game_logic::events::CreatureTakesDamageEvent;
+
+    #[test]
+    fn damaged_when_in_back_row_expects_moves_to_front_with_attack() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_id = state.player_a_id();
+
+        let dog = place(
+            &mut state,
+            SleepingDog::id(),
+            BoardPos::new(player_id, RowId::BackRow, 2),
+        );
+
+        smol::block_on(dispatcher.dispatch(CreatureTakesDamageEvent::new(dog, 1), &mut state))
+            .unwrap();
+
+        assert_eq!(Some((7, 2)), stats(&state, dog));
+        assert_eq!(
+            Some(BoardPos::new(player_id, RowId::FrontRow, 2)),
+            state.board().creature_pos(dog)
+        );
     }
 
-    impl SleepingDogBuff {
-        pub fn new(source_id: CreatureInstanceId) -> Self {
-            Self {
-                instance_id: BuffInstanceId::new(),
-                source_id: BuffSourceId::CreatureInstance(source_id),
-            }
-        }
-    }
-
-    impl Buff for SleepingDogBuff {
-        fn attack_amount(&self) -> i32 {
-            7
-        }
-
-        fn health_amount(&self) -> i32 {
-            0
-        }
-
-        fn instance_id(&self) -> BuffInstanceId {
-            self.instance_id
-        }
-
-        fn source_id(&self) -> BuffSourceId {
-            self.source_id
-        }
-
-        fn definition_id(&self) -> Id {
-            todo!()
-        }
+    #[test]
+    fn damaged_when_front_row_blocked_expects_stays_asleep() {
+        let mut state = make_test_state();
+        let mut dispatcher = make_dispatcher(&state);
+        let player_id = state.player_a_id();
+
+        let dog = place(
+            &mut state,
+            SleepingDog::id(),
+            BoardPos::new(player_id, RowId::BackRow, 2),
+        );
+        place(
+            &mut state,
+            Pawn::id(),
+            BoardPos::new(player_id, RowId::FrontRow, 3),
+        );
+
+        smol::block_on(dispatcher.dispatch(CreatureTakesDamageEvent::new(dog, 1), &mut state))
+            .unwrap();
+
+        assert_eq!(Some((0, 2)), stats(&state, dog));
+        assert_eq!(
+            Some(BoardPos::new(player_id, RowId::BackRow, 2)),
+            state.board().creature_pos(dog)
+        );
     }
 }
@@ -6,6 +6,7 @@ use protocol::{
     entities::{BoardPos, CreatureDefinition, PlayerHero, PlayerId, RowId},
     from_client::ClientAction,
     from_server::{EntityAdded, EntityUpdate, Notification},
+    rule_violation::RuleViolation,
 };
 use salt_engine::{
     game_agent::{ClientNotifier, GameClient, Prompter},
@@ -88,6 +89,10 @@ impl GameClient for ConsoleAgent {
     async fn on_turn_start(&mut self, _game_state: &salt_engine::game_state::GameState) {
         todo!()
     }
+
+    async fn on_rule_violation(&mut self, violation: RuleViolation) {
+        say(format!("Invalid action: {}", violation));
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -3,14 +3,17 @@ use std::sync::Arc;
 
 #[cfg(test)]
 use mockall::{automock, predicate::str};
-use protocol::{entities::BoardPos, from_client::ClientAction, from_server::Notification};
+use protocol::{
+    entities::BoardPos, from_client::ClientAction, from_server::Notification,
+    rule_violation::RuleViolation,
+};
 
 use crate::game_state::game_state::GameState;
 
-/// A trait that defines the interaction between the GameRunner
+/// A trait that defines the interaction between the `GameRunner`
 /// and the client.
-/// The GameRunner is the rules engine, and it will use the
-/// GameClient for each player client to alert that client
+/// The `GameRunner` is the rules engine, and it will use the
+/// `GameClient` for each player client to alert that client
 /// to events, and to receive input from the player client.
 #[async_trait]
 pub trait GameClient: Send + Sync {
@@ -19,6 +22,10 @@ pub trait GameClient: Send + Sync {
     // rename to "receive_input"
     async fn next_action(&mut self) -> ClientAction;
 
+    /// Called when the client's last action broke a rule and was rejected.
+    /// The runner then waits for another action from the same client.
+    async fn on_rule_violation(&mut self, violation: RuleViolation);
+
     // make "notify"
     async fn make_prompter(&self) -> Arc<dyn Prompter>;
     async fn make_notifier(&self) -> Arc<dyn ClientNotifier>;
@@ -70,6 +77,7 @@ pub mod tests {
     use async_trait::async_trait;
     use mockall::mock;
     use protocol::{entities::BoardPos, from_server::Notification};
+    use std::sync::{Arc, Mutex};
 
     mock! {
         pub(crate) TestPrompter {}
@@ -91,4 +99,15 @@ pub mod tests {
             // Do nothing for the stub
         }
     }
+
+    /// Keeps every notification sent to the client, for tests about what a player gets to see.
+    #[derive(Default, Clone)]
+    pub(crate) struct RecordingNotifier(pub(crate) Arc<Mutex<Vec<Notification>>>);
+
+    #[async_trait]
+    impl ClientNotifier for RecordingNotifier {
+        async fn notify(&self, event: Notification) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
 }
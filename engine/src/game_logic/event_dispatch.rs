@@ -1,19 +1,31 @@
-use super::events::GameEvent;
+use super::events::{self, GameEvent};
 use crate::game_logic::event_handlers::{
-    DrawCardEventHandler, EventHandler, StartGameEventHandler,
+    AddCardToHandEventHandler, AttackEventHandler, CastSpellFromHandEventHandler,
+    CreatureDealsDamageHandler, CreatureDestroyedEventHandler, CreatureHealedEventHandler,
+    CreatureMovedEventHandler, CreatureRevealedEventHandler, CreatureSetEventHandler,
+    CreatureTakesDamageHandler, DrawCardEventHandler, EndTurnEventHandler, EventHandler,
+    HeroTakesDamageHandler, PlayerGainManaEventHandler, PlayerSpendManaEventHandler,
+    PosTakesDamageHandler, StartGameEventHandler, SummonCreatureFromHandEventHandler,
+    TurnStartHandler, UseHeroPowerEventHandler,
 };
+use crate::game_logic::events::CreatureDestroyedEvent;
 use crate::game_logic::events::Event;
+use crate::v2::{
+    actions::UponEventAction, CreatureDefinition, CreatureInstance, CreatureInstanceId,
+};
 use crate::{
     game_agent::{ClientNotifier, Prompter},
     game_state::game_state::GameState,
 };
-use log::{debug, info};
+use log::{debug, info, warn};
 use protocol::{entities::PlayerId, from_server::Notification};
 use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct EventDispatcher {
     stack: Vec<GameEvent>,
+    simultaneous_depth: usize,
+    pending_deaths: Vec<CreatureInstanceId>,
     player_a_notifier: Arc<dyn ClientNotifier>,
     player_a_prompter: Arc<dyn Prompter>,
     player_a_id: PlayerId,
@@ -34,6 +46,8 @@ impl EventDispatcher {
     ) -> Self {
         Self {
             stack: Vec::new(),
+            simultaneous_depth: 0,
+            pending_deaths: Vec::new(),
             player_a_notifier,
             player_a_prompter,
             player_a_id,
@@ -43,11 +57,15 @@ impl EventDispatcher {
         }
     }
 
-    pub async fn dispatch(&mut self, event: impl Into<GameEvent>, game_state: &mut GameState) {
+    /// Validates and handles the event, along with every event raised while handling it.
+    /// If the event breaks a rule, the game state is left untouched and the violation is returned.
+    pub async fn dispatch(
+        &mut self,
+        event: impl Into<GameEvent>,
+        game_state: &mut GameState,
+    ) -> events::Result {
         let event = event.into();
-        event
-            .validate(game_state)
-            .expect("Validation failed for dispatched event.");
+        event.validate(game_state)?;
 
         self.stack.push(event);
 
@@ -59,6 +77,106 @@ impl EventDispatcher {
             self.post_handle(&event, game_state).await;
 
             game_state.evaluate_passives();
+            self.destroy_dead_creatures(game_state).await;
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches an event raised by the game itself, rather than by a client's action.
+    /// There is no client to report a rule violation to, so a violating event is logged and dropped.
+    pub async fn dispatch_triggered(
+        &mut self,
+        event: impl Into<GameEvent>,
+        game_state: &mut GameState,
+    ) {
+        let event = event.into();
+        let event_debug = format!("{event:?}");
+
+        if let Err(violation) = self.dispatch(event, game_state).await {
+            warn!("Dropped triggered event {event_debug} that broke a rule: {violation}");
+        }
+    }
+
+    /// Destroys a creature that has run out of health.
+    /// While damage is being dealt simultaneously, the creature stays on the board
+    /// until all of that damage has been dealt, and is destroyed by `end_simultaneous`.
+    pub async fn destroy_creature(
+        &mut self,
+        creature_id: CreatureInstanceId,
+        game_state: &mut GameState,
+    ) {
+        if self.simultaneous_depth > 0 {
+            if !self.pending_deaths.contains(&creature_id) {
+                self.pending_deaths.push(creature_id);
+            }
+        } else {
+            self.dispatch_triggered(CreatureDestroyedEvent::new(creature_id), game_state)
+                .await;
+        }
+    }
+
+    /// Starts dealing damage simultaneously, such as both sides of an attack.
+    /// Creatures that die stay on the board until the matching `end_simultaneous`,
+    /// so they are all destroyed at once. Calls may be nested.
+    pub fn begin_simultaneous(&mut self) {
+        self.simultaneous_depth += 1;
+    }
+
+    /// Ends the matching `begin_simultaneous`. Once the outermost one ends,
+    /// every creature that died in the meantime is destroyed in board order,
+    /// so several deaths at once always resolve the same way.
+    /// A creature that was healed back above 0 health in the meantime survives.
+    pub async fn end_simultaneous(&mut self, game_state: &mut GameState) {
+        self.simultaneous_depth = self
+            .simultaneous_depth
+            .checked_sub(1)
+            .expect("end_simultaneous must follow a begin_simultaneous.");
+
+        if self.simultaneous_depth > 0 {
+            return;
+        }
+
+        let pending = std::mem::take(&mut self.pending_deaths);
+        let dying: Vec<CreatureInstanceId> = Self::board_order(game_state)
+            .into_iter()
+            .filter(|id| pending.contains(id))
+            .filter(|id| {
+                game_state
+                    .board()
+                    .creature_by_id(*id)
+                    .is_some_and(|c| c.get(CreatureInstance::health) <= 0)
+            })
+            .collect();
+
+        for creature_id in dying {
+            self.dispatch_triggered(CreatureDestroyedEvent::new(creature_id), game_state)
+                .await;
+        }
+    }
+
+    /// Destroys, in board order, every creature left with no health.
+    /// Damage destroys a creature as it is dealt, but losing a passive buff
+    /// can also leave a creature with no health once passives are re-evaluated.
+    async fn destroy_dead_creatures(&mut self, game_state: &mut GameState) {
+        let is_dead = |game_state: &GameState, id| {
+            game_state
+                .board()
+                .creature_by_id(id)
+                .is_some_and(|c| c.get(CreatureInstance::health) <= 0)
+        };
+
+        let dead: Vec<CreatureInstanceId> = Self::board_order(game_state)
+            .into_iter()
+            .filter(|id| is_dead(game_state, *id))
+            .collect();
+
+        for creature_id in dead {
+            // An earlier death may have already resolved this one.
+            // Destroying dispatches again, so the recursive future is boxed.
+            if is_dead(game_state, creature_id) {
+                Box::pin(self.destroy_creature(creature_id, game_state)).await;
+            }
         }
     }
 
@@ -74,7 +192,7 @@ impl EventDispatcher {
         } else if player_id == self.player_b_id {
             self.player_b_notifier.as_ref()
         } else {
-            panic!("Cannot get notifier for unknown player ID: {:?}", player_id)
+            panic!("Cannot get notifier for unknown player ID: {player_id:?}")
         }
     }
 
@@ -85,10 +203,7 @@ impl EventDispatcher {
         } else if player_id == self.player_b_id {
             self.player_a_notifier.as_ref()
         } else {
-            panic!(
-                "Cannot get notifier for opponent of unknown player ID: {:?}",
-                player_id
-            )
+            panic!("Cannot get notifier for opponent of unknown player ID: {player_id:?}")
         }
     }
 
@@ -99,47 +214,65 @@ impl EventDispatcher {
         } else if player_id == self.player_b_id {
             self.player_b_prompter.as_ref()
         } else {
-            panic!("Cannot get notifier for unknown player ID: {:?}", player_id)
+            panic!("Cannot get notifier for unknown player ID: {player_id:?}")
         }
     }
 
     async fn pre_handle(&mut self, event: &GameEvent, game_state: &mut GameState) {
-        // todo!()
-        // let pre_existing_actions = game_state
-        //     .board()
-        //     .all_characters_slots()
-        //     .creatures()
-        //     .filter_map(|c| {
-        //         c.definition()
-        //             .pre_event_action(c.id(), event, game_state, self)
-        //     })
-        //     .collect::<Vec<_>>();
-
-        // for action in pre_existing_actions {
-        //     action.action(event, game_state, self).await;
-        // }
+        let actions = Self::event_actions(game_state, |definition, instance_id, game_state| {
+            definition.pre_event_action(instance_id, event, game_state)
+        });
+
+        for action in actions {
+            action.action(event, game_state, self).await;
+        }
     }
 
     async fn post_handle(&mut self, event: &GameEvent, game_state: &mut GameState) {
-        // todo!()
-        // let pre_existing_actions = game_state
-        //     .board()
-        //     .all_characters_slots()
-        //     .creatures()
-        //     .filter_map(|c| {
-        //         c.definition()
-        //             .post_event_action(c.id(), event, game_state, self)
-        //     })
-        //     .collect::<Vec<_>>();
-
-        // for action in pre_existing_actions {
-        //     action.action(event, game_state, self).await;
-        // }
+        let actions = Self::event_actions(game_state, |definition, instance_id, game_state| {
+            definition.post_event_action(instance_id, event, game_state)
+        });
+
+        for action in actions {
+            action.action(event, game_state, self).await;
+        }
     }
 
-    async fn handle(&mut self, event: &GameEvent, game_state: &mut GameState) {
-        debug!("Dispatching event: {:?}", event);
+    /// Every creature on the board, in a fixed order: the active player's creatures first,
+    /// then the opponent's. Within a side, front row before back row, each from index 0 upwards.
+    fn board_order(game_state: &GameState) -> Vec<CreatureInstanceId> {
+        let active_player = game_state.cur_player_turn();
+        let board = game_state.board();
+
+        [active_player, game_state.other_player(active_player)]
+            .into_iter()
+            .flat_map(|player_id| board.player_creature_ids(player_id))
+            .collect()
+    }
 
+    /// Collects the actions the creatures on the board take in response to an event.
+    /// Creatures are asked in board order.
+    /// Every creature chooses its action before any action runs,
+    /// so an action cannot change which other creatures react to the same event.
+    fn event_actions(
+        game_state: &GameState,
+        choose_action: impl Fn(
+            &CreatureDefinition,
+            CreatureInstanceId,
+            &GameState,
+        ) -> Option<Box<dyn UponEventAction>>,
+    ) -> Vec<Box<dyn UponEventAction>> {
+        Self::board_order(game_state)
+            .into_iter()
+            .filter_map(|instance_id| {
+                let definition = game_state.creature_definition_of(instance_id)?;
+                choose_action(definition, instance_id, game_state)
+            })
+            .collect()
+    }
+
+    /// Sends each player the event as they are allowed to see it, if it can be seen at all.
+    async fn notify_visual_event(&self, event: &GameEvent, game_state: &GameState) {
         if let Some(client_event) = event.maybe_client_event(game_state.player_a_id(), game_state) {
             info!("Notifying player_a of event: {:?}", client_event);
             self.player_notifier(game_state.player_a_id())
@@ -153,77 +286,284 @@ impl EventDispatcher {
                 .notify(Notification::VisualEvent(client_event))
                 .await;
         }
+    }
+
+    async fn handle(&mut self, event: &GameEvent, game_state: &mut GameState) {
+        debug!("Dispatching event: {:?}", event);
+
+        self.notify_visual_event(event, game_state).await;
 
         match event {
-            GameEvent::AttackEvent(_) => todo!(),
-            GameEvent::EndTurnEvent(_) => todo!(),
-            GameEvent::CreatureSetEvent(_) => todo!(),
-            GameEvent::CreatureDealsDamageEvent(_) => todo!(),
-            GameEvent::CreatureTakesDamageEvent(_) => todo!(),
-            GameEvent::CreatureDestroyedEvent(_) => todo!(),
-            GameEvent::TurnStartEvent(_) => todo!(),
+            GameEvent::AttackEvent(event) => {
+                AttackEventHandler.handle(event, game_state, self).await;
+            }
+            GameEvent::EndTurnEvent(event) => {
+                EndTurnEventHandler.handle(event, game_state, self).await;
+            }
+            GameEvent::CreatureSetEvent(event) => {
+                CreatureSetEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::CreatureDealsDamageEvent(event) => {
+                CreatureDealsDamageHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::CreatureTakesDamageEvent(event) => {
+                CreatureTakesDamageHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::CreatureDestroyedEvent(event) => {
+                CreatureDestroyedEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::TurnStartEvent(event) => {
+                TurnStartHandler.handle(event, game_state, self).await;
+            }
             GameEvent::DrawCardEvent(event) => {
-                DrawCardEventHandler::default()
+                DrawCardEventHandler.handle(event, game_state, self).await;
+            }
+            GameEvent::AddCardToHandEvent(event) => {
+                AddCardToHandEventHandler
                     .handle(event, game_state, self)
-                    .await
+                    .await;
             }
-            GameEvent::AddCardToHandEvent(_) => todo!(),
             GameEvent::StartGameEvent(event) => {
-                StartGameEventHandler::default()
+                StartGameEventHandler.handle(event, game_state, self).await;
+            }
+            GameEvent::PlayerGainManaEvent(event) => {
+                PlayerGainManaEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::PlayerSpendManaEvent(event) => {
+                PlayerSpendManaEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::CreatureSummonedFromHandEvent(event) => {
+                SummonCreatureFromHandEventHandler
                     .handle(event, game_state, self)
-                    .await
+                    .await;
+            }
+            GameEvent::PosTakesDamageEvent(event) => {
+                PosTakesDamageHandler.handle(event, game_state, self).await;
+            }
+            GameEvent::CreatureHealedEvent(event) => {
+                CreatureHealedEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::HeroTakesDamageEvent(event) => {
+                HeroTakesDamageHandler.handle(event, game_state, self).await;
+            }
+            GameEvent::CreatureRevealedEvent(event) => {
+                CreatureRevealedEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::SpellCastFromHandEvent(event) => {
+                CastSpellFromHandEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::HeroPowerUsedEvent(event) => {
+                UseHeroPowerEventHandler
+                    .handle(event, game_state, self)
+                    .await;
+            }
+            GameEvent::CreatureMovedEvent(event) => {
+                CreatureMovedEventHandler
+                    .handle(event, game_state, self)
+                    .await;
             }
-            GameEvent::PlayerGainManaEvent(_) => todo!(),
-            GameEvent::PlayerSpendManaEvent(_) => todo!(),
-            GameEvent::CreatureSummonedFromHandEvent(_) => todo!(),
-            GameEvent::PosTakesDamageEvent(_) => todo!(),
-            GameEvent::CreatureHealedEvent(_) => todo!(),
         }
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use super::EventDispatcher;
-//     use crate::game_agent::tests::{MockTestPrompter, StubNotifier};
-//     use crate::game_state::{Deck, GameState};
-//     use protocol::entities::PlayerId;
-//     use std::sync::Arc;
-
-//     pub(crate) fn make_test_state() -> GameState {
-//         let player_a_deck = Deck::new(Vec::new());
-//         let player_b_deck = Deck::new(Vec::new());
-
-//         let mut state = GameState::initial_state(
-//             PlayerId::new(),
-//             player_a_deck,
-//             PlayerId::new(),
-//             player_b_deck,
-//         );
-
-//         state.raise_mana_limit(state.player_a_id(), 10);
-//         state.raise_mana_limit(state.player_b_id(), 10);
-//         state.refresh_player_mana(state.player_a_id());
-//         state.refresh_player_mana(state.player_b_id());
-
-//         state
-//     }
-
-//     #[test]
-//     fn dispatcher_uses_stack_ordering() {
-//         let prompter_a = Arc::new(MockTestPrompter::new());
-//         let prompter_b = Arc::new(MockTestPrompter::new());
-
-//         let notifier_a = Arc::new(StubNotifier);
-//         let notifier_b = Arc::new(StubNotifier);
-
-//         let _dispatcher = EventDispatcher::new(
-//             notifier_a,
-//             prompter_a,
-//             PlayerId::new(),
-//             notifier_b,
-//             prompter_b,
-//             PlayerId::new(),
-//         );
-//     }
-// }
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::EventDispatcher;
+    use crate::game_agent::tests::{MockTestPrompter, RecordingNotifier, StubNotifier};
+    use crate::game_logic::events::{CreatureTakesDamageEvent, GameEvent, HeroTakesDamageEvent};
+    use crate::game_state::game_state::{tests::place_creature, GameState};
+    use crate::v2::{
+        actions::{EventTrigger, UponEventAction},
+        builder::CreatureDefinitionBuilder,
+        CreatureInstanceId,
+    };
+    use async_trait::async_trait;
+    use protocol::entities::{BoardPos, PlayerId, RowId};
+    use std::sync::{Arc, Mutex};
+
+    /// A dispatcher for the players of the given `GameState`, with stubbed clients.
+    pub(crate) fn make_test_dispatcher(game_state: &GameState) -> EventDispatcher {
+        EventDispatcher::new(
+            Arc::new(StubNotifier),
+            Arc::new(MockTestPrompter::new()),
+            game_state.player_a_id(),
+            Arc::new(StubNotifier),
+            Arc::new(MockTestPrompter::new()),
+            game_state.player_b_id(),
+        )
+    }
+
+    /// A dispatcher for the players of the given `GameState`,
+    /// along with the notifiers recording what players A and B are sent.
+    pub(crate) fn make_recording_dispatcher(
+        game_state: &GameState,
+    ) -> (EventDispatcher, RecordingNotifier, RecordingNotifier) {
+        let (notifier_a, notifier_b) = (RecordingNotifier::default(), RecordingNotifier::default());
+        let dispatcher = EventDispatcher::new(
+            Arc::new(notifier_a.clone()),
+            Arc::new(MockTestPrompter::new()),
+            game_state.player_a_id(),
+            Arc::new(notifier_b.clone()),
+            Arc::new(MockTestPrompter::new()),
+            game_state.player_b_id(),
+        );
+
+        (dispatcher, notifier_a, notifier_b)
+    }
+
+    /// Records the id of every creature asked to react to an event.
+    struct RecordTrigger(Arc<Mutex<Vec<CreatureInstanceId>>>);
+
+    impl EventTrigger for RecordTrigger {
+        fn trigger(
+            &self,
+            instance_id: CreatureInstanceId,
+            _event: &GameEvent,
+            _game_state: &GameState,
+        ) -> Option<Box<dyn UponEventAction>> {
+            self.0.lock().unwrap().push(instance_id);
+            None
+        }
+    }
+
+    /// After the creature takes damage, deals the same damage to the opposing hero.
+    struct ReflectDamageTrigger;
+
+    impl EventTrigger for ReflectDamageTrigger {
+        fn trigger(
+            &self,
+            instance_id: CreatureInstanceId,
+            event: &GameEvent,
+            game_state: &GameState,
+        ) -> Option<Box<dyn UponEventAction>> {
+            let GameEvent::CreatureTakesDamageEvent(e) = event else {
+                return None;
+            };
+
+            if e.creature_id() != instance_id {
+                return None;
+            }
+
+            let owner = game_state.board().creature_pos(instance_id)?.player_id;
+
+            Some(Box::new(ReflectDamageAction {
+                opponent: game_state.other_player(owner),
+                damage_amount: e.damage_amount(),
+            }))
+        }
+    }
+
+    struct ReflectDamageAction {
+        opponent: PlayerId,
+        damage_amount: usize,
+    }
+
+    #[async_trait]
+    impl UponEventAction for ReflectDamageAction {
+        async fn action(
+            &self,
+            _event: &GameEvent,
+            game_state: &mut GameState,
+            dispatcher: &mut EventDispatcher,
+        ) {
+            dispatcher
+                .dispatch_triggered(
+                    HeroTakesDamageEvent::new(self.opponent, self.damage_amount),
+                    game_state,
+                )
+                .await;
+        }
+    }
+
+    #[test]
+    fn dispatch_when_creatures_on_board_expects_hooks_in_active_player_then_board_order() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        game_state.set_next_player_turn();
+
+        let pre_calls = Arc::new(Mutex::new(Vec::new()));
+        let post_calls = Arc::new(Mutex::new(Vec::new()));
+        let mut builder = CreatureDefinitionBuilder::new();
+        builder
+            .health(5)
+            .pre_event_action(RecordTrigger(Arc::clone(&pre_calls)))
+            .post_event_action(RecordTrigger(Arc::clone(&post_calls)));
+
+        let a_front = place_creature(
+            &mut game_state,
+            &builder,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let b_back = place_creature(
+            &mut game_state,
+            &builder,
+            BoardPos::new(player_b, RowId::BackRow, 0),
+        );
+        let b_front_right = place_creature(
+            &mut game_state,
+            &builder,
+            BoardPos::new(player_b, RowId::FrontRow, 4),
+        );
+        let b_front_left = place_creature(
+            &mut game_state,
+            &builder,
+            BoardPos::new(player_b, RowId::FrontRow, 1),
+        );
+
+        let mut dispatcher = make_test_dispatcher(&game_state);
+        smol::block_on(
+            dispatcher.dispatch(HeroTakesDamageEvent::new(player_a, 1), &mut game_state),
+        )
+        .unwrap();
+
+        let expected = vec![b_front_left, b_front_right, b_back, a_front];
+        assert_eq!(expected, *pre_calls.lock().unwrap());
+        assert_eq!(expected, *post_calls.lock().unwrap());
+    }
+
+    #[test]
+    fn dispatch_when_post_action_dispatches_event_expects_event_handled() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+
+        let creature = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new()
+                .health(5)
+                .post_event_action(ReflectDamageTrigger),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let hero_b_health = game_state.board().player_hero(player_b).get(|h| h.health());
+
+        let mut dispatcher = make_test_dispatcher(&game_state);
+        smol::block_on(
+            dispatcher.dispatch(CreatureTakesDamageEvent::new(creature, 2), &mut game_state),
+        )
+        .unwrap();
+
+        assert_eq!(
+            hero_b_health - 2,
+            game_state.board().player_hero(player_b).get(|h| h.health())
+        );
+    }
+}
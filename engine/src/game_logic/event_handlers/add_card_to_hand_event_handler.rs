@@ -1,13 +1,11 @@
 use crate::{
-    game_logic::{event_handlers::EventHandler, events::AddCardToHandEvent, EventDispatcher},
-    game_state::{GameState, MakePlayerViewNew},
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::AddCardToHandEvent,
+    },
+    game_state::{game_state::GameState, CardInHand},
 };
 use async_trait::async_trait;
 use log::info;
-use protocol::{
-    entities::{EntityPosition, IsEntity},
-    from_server::{EntityAdded, Notification},
-};
 
 #[derive(Default)]
 pub struct AddCardToHandEventHandler;
@@ -20,29 +18,17 @@ impl EventHandler for AddCardToHandEventHandler {
         &self,
         event: &AddCardToHandEvent,
         game_state: &mut GameState,
-        dispatcher: &mut EventDispatcher,
+        _dispatcher: &mut EventDispatcher,
     ) {
         let player_id = event.player_id();
-        let card = game_state
-            .board_mut()
-            .take_tracked_pending_card(event.card_id())
-            .expect("Expected to find the tracked pending card");
-
-        let card_id = card.id();
-        let entity = card.as_entity();
-        let hand_len = game_state.hand(player_id).len();
-        let position = EntityPosition::Hand(player_id, hand_len as u8);
-
-        game_state.hand_mut(event.player_id()).add_card(card);
+        let card = CardInHand::new_with_id(event.card_id(), event.definition_id());
 
-        let notifier = dispatcher.player_notifier(player_id);
-        let added_card_entity = EntityAdded::new(card_id, entity, position);
-        let notification = Notification::EntityAdded(added_card_entity);
-        notifier.notify(notification).await;
+        let position = game_state.hand_mut(player_id).add_card(card);
 
         info!(
-            "Player {:?} adds a card to hand. Next hand size: {}",
+            "Player {:?} adds a card to hand at {:?}. Next hand size: {}",
             player_id,
+            position,
             game_state.hand(player_id).len()
         );
     }
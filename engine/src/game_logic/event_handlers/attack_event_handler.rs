@@ -1,28 +1,31 @@
 use log::info;
-use protocol::{
-    from_server::{Notification, VisualEvent},
-    visual_events::CreatureAttacksTarget,
-};
 
 use crate::{
     game_logic::{
+        event_dispatch::EventDispatcher,
         event_handlers::EventHandler,
-        events::{AttackEvent, CreatureDealsDamageEvent, CreatureTakesDamageEvent},
-        EventDispatcher,
+        events::{
+            AttackEvent, CreatureDealsDamageEvent, CreatureRevealedEvent, CreatureTakesDamageEvent,
+            HeroTakesDamageEvent,
+        },
     },
-    game_state::board::BoardView,
-    game_state::GameState,
+    game_state::game_state::GameState,
+    v2::CreatureInstance,
 };
 use async_trait::async_trait;
+use protocol::entities::CreatureInstanceId;
 
 #[derive(Default, Debug)]
 pub struct AttackEventHandler;
 
-fn validate(event: &AttackEvent, game_state: &GameState) {
-    let pos = game_state.board().pos_with_creature(event.target());
-    if game_state.is_pos_defended(pos) {
-        panic!("Cannot attack defended pos {:?}", pos);
-    }
+/// The damage the creature deals in combat, or `None` if it is no longer on the board.
+fn attack_amount(game_state: &GameState, creature_id: CreatureInstanceId) -> Option<usize> {
+    let attack = game_state
+        .board()
+        .creature_by_id(creature_id)?
+        .get(CreatureInstance::attack);
+
+    Some(usize::try_from(attack).unwrap_or(0))
 }
 
 #[async_trait]
@@ -35,62 +38,389 @@ impl EventHandler for AttackEventHandler {
         game_state: &mut GameState,
         dispatcher: &mut EventDispatcher,
     ) {
-        validate(event, game_state);
+        game_state
+            .board_mut()
+            .creature_by_id_mut(event.attacker())
+            .expect("The attacker must be on the board.")
+            .get_mut(CreatureInstance::set_has_attacked);
+
+        // Hidden creatures are revealed when they first fight, before any damage is dealt.
+        for creature_id in [event.attacker(), event.target()] {
+            let is_hidden = game_state
+                .board()
+                .creature_by_id(creature_id)
+                .is_some_and(|c| c.get(CreatureInstance::is_hidden));
+
+            if is_hidden {
+                dispatcher
+                    .dispatch_triggered(CreatureRevealedEvent::new(creature_id), game_state)
+                    .await;
+            }
+        }
+
+        // A reveal may have removed the attacker, which ends the attack.
+        let Some(attacker_attack_amount) = attack_amount(game_state, event.attacker()) else {
+            info!("{:?} left the board before attacking", event.attacker());
+            return;
+        };
 
-        {
-            let attacker_instance = game_state.board().creature_instance(event.attacker());
-            let target_instance = game_state.board().creature_instance(event.target());
-            let attack_amount = attacker_instance.attack() as usize;
+        // Heroes do not deal damage back.
+        if let Some(hero_player_id) = game_state.board().hero_player_id(event.target()) {
             info!(
-                "{} attacks {} for {} damage",
-                attacker_instance.definition().title(),
-                target_instance.definition().title(),
-                attack_amount
+                "{:?} attacks the hero of player {:?} for {} damage",
+                event.attacker(),
+                hero_player_id,
+                attacker_attack_amount
             );
-        }
 
-        // 0. Send visual event to clients
-        {
-            let visual_event = CreatureAttacksTarget {
-                attacker: event.attacker().id(),
-                target: event.target().id(),
-            };
-            let visual_event = VisualEvent::CreatureAttacksTarget(visual_event);
+            let deal_damage_event = CreatureDealsDamageEvent::new(
+                event.attacker(),
+                event.target(),
+                attacker_attack_amount,
+            );
+            dispatcher
+                .dispatch_triggered(deal_damage_event, game_state)
+                .await;
+
+            let take_damage_event =
+                HeroTakesDamageEvent::new(hero_player_id, attacker_attack_amount);
             dispatcher
-                .notify_players(Notification::VisualEvent(visual_event))
+                .dispatch_triggered(take_damage_event, game_state)
                 .await;
+
+            return;
         }
 
+        // Both amounts are read before any damage is applied,
+        // so the exchange is simultaneous.
+        let Some(target_attack_amount) = attack_amount(game_state, event.target()) else {
+            info!("{:?} left the board before being attacked", event.target());
+            return;
+        };
+
+        info!(
+            "{:?} attacks {:?} for {} damage",
+            event.attacker(),
+            event.target(),
+            attacker_attack_amount
+        );
+
         // 1. Attacker deals damage
-        let attacker_attack_amount = game_state
-            .board()
-            .creature_instance(event.attacker())
-            .attack() as usize;
         let deal_damage_event =
             CreatureDealsDamageEvent::new(event.attacker(), event.target(), attacker_attack_amount);
 
-        dispatcher.dispatch(deal_damage_event, game_state).await;
+        dispatcher
+            .dispatch_triggered(deal_damage_event, game_state)
+            .await;
 
         // 2. Target deals damage
-        let target_attack_amount = game_state
-            .board()
-            .creature_instance(event.target())
-            .attack() as usize;
         let deal_damage_event =
             CreatureDealsDamageEvent::new(event.target(), event.attacker(), target_attack_amount);
 
-        dispatcher.dispatch(deal_damage_event, game_state).await;
+        dispatcher
+            .dispatch_triggered(deal_damage_event, game_state)
+            .await;
+
+        // Both take their damage before either is destroyed.
+        dispatcher.begin_simultaneous();
 
         // 3. Target receives damage
         let take_damage_event =
             CreatureTakesDamageEvent::new(event.target(), attacker_attack_amount);
 
-        dispatcher.dispatch(take_damage_event, game_state).await;
+        dispatcher
+            .dispatch_triggered(take_damage_event, game_state)
+            .await;
 
         // 4. Attacker receives damage
         let take_damage_event =
             CreatureTakesDamageEvent::new(event.attacker(), target_attack_amount);
 
-        dispatcher.dispatch(take_damage_event, game_state).await;
+        dispatcher
+            .dispatch_triggered(take_damage_event, game_state)
+            .await;
+
+        dispatcher.end_simultaneous(game_state).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_logic::{
+            event_dispatch::{tests::make_test_dispatcher, EventDispatcher},
+            events::{AttackEvent, Event, GameEvent},
+        },
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::{
+            actions::{EventTrigger, UponDeathAction, UponEventAction},
+            builder::CreatureDefinitionBuilder,
+            CreatureInstance,
+        },
+    };
+    use async_trait::async_trait;
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, Keyword, PlayerId, RowId},
+        rule_violation::RuleViolation,
+    };
+    use std::sync::{Arc, Mutex};
+
+    /// Records each death as (creature, how many creatures were left on the board).
+    struct RecordDeathAction(Arc<Mutex<Vec<(CreatureInstanceId, usize)>>>);
+
+    #[async_trait]
+    impl UponDeathAction for RecordDeathAction {
+        async fn action(
+            &self,
+            instance_id: CreatureInstanceId,
+            _pos: BoardPos,
+            game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            let left = [game_state.player_a_id(), game_state.player_b_id()]
+                .into_iter()
+                .map(|p| game_state.board().player_creature_ids(p).len())
+                .sum();
+            self.0.lock().unwrap().push((instance_id, left));
+        }
+    }
+
+    /// Once the creature is revealed, it leaves the board.
+    struct FleeWhenRevealedTrigger;
+
+    impl EventTrigger for FleeWhenRevealedTrigger {
+        fn trigger(
+            &self,
+            instance_id: CreatureInstanceId,
+            event: &GameEvent,
+            _game_state: &GameState,
+        ) -> Option<Box<dyn UponEventAction>> {
+            match event {
+                GameEvent::CreatureRevealedEvent(e) if e.creature_id() == instance_id => {
+                    Some(Box::new(FleeAction(instance_id)))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    struct FleeAction(CreatureInstanceId);
+
+    #[async_trait]
+    impl UponEventAction for FleeAction {
+        async fn action(
+            &self,
+            _event: &GameEvent,
+            game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            game_state.board_mut().remove_creature(self.0);
+        }
+    }
+
+    fn health_of(game_state: &GameState, creature_id: CreatureInstanceId) -> Option<i32> {
+        game_state
+            .board()
+            .creature_by_id(creature_id)
+            .map(|c| c.get(CreatureInstance::health))
+    }
+
+    fn place(
+        game_state: &mut GameState,
+        player_id: PlayerId,
+        attack: i32,
+        health: i32,
+    ) -> CreatureInstanceId {
+        let index = game_state
+            .board()
+            .creature_at_pos(BoardPos::new(player_id, RowId::FrontRow, 0))
+            .map_or(0, |_| 1);
+
+        place_creature(
+            game_state,
+            CreatureDefinitionBuilder::new()
+                .attack(attack)
+                .health(health)
+                .keyword(Keyword::Eager),
+            BoardPos::new(player_id, RowId::FrontRow, index),
+        )
+    }
+
+    #[test]
+    fn attack_expects_attacker_and_target_exchange_damage() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let attacker = place(&mut game_state, player_a, 3, 5);
+        let target = place(&mut game_state, player_b, 2, 4);
+
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(attacker, target), &mut game_state))
+            .unwrap();
+
+        assert_eq!(Some(3), health_of(&game_state, attacker));
+        assert_eq!(Some(1), health_of(&game_state, target));
+    }
+
+    #[test]
+    fn attack_when_both_lethal_expects_both_destroyed_and_removed() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let attacker = place(&mut game_state, player_a, 4, 2);
+        let target = place(&mut game_state, player_b, 2, 4);
+
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(attacker, target), &mut game_state))
+            .unwrap();
+
+        assert_eq!(None, health_of(&game_state, attacker));
+        assert_eq!(None, health_of(&game_state, target));
+        assert!(game_state.board().slots_with_creature(target).is_empty());
+    }
+
+    #[test]
+    fn attack_when_target_is_hero_expects_hero_damaged_and_attacker_unharmed() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let attacker = place(&mut game_state, player_a, 3, 5);
+        let (hero_id, hero_health) = game_state
+            .board()
+            .player_hero(player_b)
+            .get(|h| (h.instance_id(), h.health()));
+
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(attacker, hero_id), &mut game_state))
+            .unwrap();
+
+        assert_eq!(Some(5), health_of(&game_state, attacker));
+        assert_eq!(
+            hero_health - 3,
+            game_state.board().player_hero(player_b).get(|h| h.health())
+        );
+    }
+
+    #[test]
+    fn attack_expects_attacker_cannot_attack_again_this_turn() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let attacker = place(&mut game_state, player_a, 1, 5);
+        let target = place(&mut game_state, player_b, 1, 5);
+        let attack = AttackEvent::new(attacker, target);
+
+        assert!(attack.validate(&game_state).is_ok());
+
+        smol::block_on(dispatcher.dispatch(attack.clone(), &mut game_state)).unwrap();
+
+        assert_eq!(
+            Err(RuleViolation::AlreadyAttacked {
+                creature_id: attacker
+            }),
+            attack.validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn handle_when_hidden_creatures_fight_expects_both_revealed() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut hidden = CreatureDefinitionBuilder::new();
+        hidden
+            .attack(1)
+            .health(5)
+            .keyword(Keyword::Eager)
+            .keyword(Keyword::Hidden);
+
+        let attacker = place_creature(
+            &mut game_state,
+            &hidden,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let target = place_creature(
+            &mut game_state,
+            &hidden,
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        let is_hidden = |game_state: &GameState, id| {
+            game_state
+                .board()
+                .creature_by_id(id)
+                .unwrap()
+                .get(CreatureInstance::is_hidden)
+        };
+        assert!(is_hidden(&game_state, attacker));
+
+        let mut dispatcher = make_test_dispatcher(&game_state);
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(attacker, target), &mut game_state))
+            .unwrap();
+
+        assert!(!is_hidden(&game_state, attacker));
+        assert!(!is_hidden(&game_state, target));
+    }
+
+    #[test]
+    fn attack_when_both_die_expects_destroyed_together_in_board_order() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let deaths = Arc::new(Mutex::new(Vec::new()));
+        let mut builder = CreatureDefinitionBuilder::new();
+        builder
+            .attack(3)
+            .health(2)
+            .keyword(Keyword::Eager)
+            .upon_death(RecordDeathAction(Arc::clone(&deaths)));
+
+        let target = place_creature(
+            &mut game_state,
+            &builder,
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        let attacker = place_creature(
+            &mut game_state,
+            &builder,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(attacker, target), &mut game_state))
+            .unwrap();
+
+        // The active player's creature goes first, while the target is still on the board.
+        assert_eq!(vec![(attacker, 1), (target, 0)], *deaths.lock().unwrap());
+    }
+
+    #[test]
+    fn attack_when_reveal_removes_target_expects_no_damage_exchanged() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let attacker = place(&mut game_state, player_a, 3, 5);
+        let target = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new()
+                .attack(2)
+                .health(4)
+                .keyword(Keyword::Hidden)
+                .post_event_action(FleeWhenRevealedTrigger),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+
+        smol::block_on(dispatcher.dispatch(AttackEvent::new(attacker, target), &mut game_state))
+            .unwrap();
+
+        assert_eq!(None, health_of(&game_state, target));
+        assert_eq!(Some(5), health_of(&game_state, attacker));
     }
 }
@@ -0,0 +1,125 @@
+use log::{debug, info};
+
+use crate::{
+    game_logic::{
+        event_dispatch::EventDispatcher,
+        event_handlers::EventHandler,
+        events::{PlayerSpendManaEvent, SpellCastFromHandEvent},
+    },
+    game_state::game_state::GameState,
+};
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct CastSpellFromHandEventHandler;
+
+#[async_trait]
+impl EventHandler for CastSpellFromHandEventHandler {
+    type Event = SpellCastFromHandEvent;
+
+    async fn handle(
+        &self,
+        event: &SpellCastFromHandEvent,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        let player_id = event.player_id();
+
+        // Take the card out of the player's hand; a cast spell is gone for good
+        debug!("Taking spell from player's hand.");
+        let card_from_hand = game_state
+            .hand_mut(player_id)
+            .take_card(event.hand_card_id());
+
+        let (mana_amount, upon_cast) = {
+            let definition = game_state
+                .creature_definition(card_from_hand.definition_id())
+                .expect("The cast card's definition must be known.");
+
+            info!("Player {:?} casts {}", player_id, definition.title());
+
+            (definition.cost(), definition.upon_cast())
+        };
+
+        // Spend the mana
+        dispatcher
+            .dispatch_triggered(
+                PlayerSpendManaEvent::new(player_id, u32::try_from(mana_amount).unwrap_or(0)),
+                game_state,
+            )
+            .await;
+
+        // Resolve the spell's effect
+        upon_cast
+            .action(card_from_hand.card_id(), player_id, game_state, dispatcher)
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_logic::{
+            event_dispatch::{tests::make_test_dispatcher, EventDispatcher},
+            events::SpellCastFromHandEvent,
+        },
+        game_state::{game_state::GameState, CardInHand},
+        v2::{actions::UponCastAction, builder::CreatureDefinitionBuilder},
+    };
+    use async_trait::async_trait;
+    use protocol::entities::{CreatureInstanceId, PlayerId};
+    use std::sync::{Arc, Mutex};
+
+    /// Records who cast the spell, and how much mana they had left while it resolved.
+    struct RecordCastAction(Arc<Mutex<Option<(CreatureInstanceId, PlayerId, u32)>>>);
+
+    #[async_trait]
+    impl UponCastAction for RecordCastAction {
+        async fn action(
+            &self,
+            card_id: CreatureInstanceId,
+            player_id: PlayerId,
+            game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            assert!(game_state.hand(player_id).card(card_id).is_none());
+
+            let mana = game_state.board().player_hero(player_id).get(|h| h.mana());
+            *self.0.lock().unwrap() = Some((card_id, player_id, mana));
+        }
+    }
+
+    #[test]
+    fn cast_expects_spell_leaves_hand_mana_spent_and_effect_runs() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let cast = Arc::new(Mutex::new(None));
+        let definition = CreatureDefinitionBuilder::new()
+            .cost(2)
+            .upon_cast(RecordCastAction(Arc::clone(&cast)))
+            .build();
+        let definition_id = definition.definition_id();
+        game_state.add_creature_definition(definition);
+
+        let card_id = CreatureInstanceId::new();
+        game_state
+            .hand_mut(player_a)
+            .add_card(CardInHand::new_with_id(card_id, definition_id));
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(5));
+
+        smol::block_on(dispatcher.dispatch(
+            SpellCastFromHandEvent::new(player_a, card_id),
+            &mut game_state,
+        ))
+        .unwrap();
+
+        assert_eq!(Some((card_id, player_a, 3)), *cast.lock().unwrap());
+        assert!(game_state.hand(player_a).is_empty());
+        assert!(game_state.board().player_creature_ids(player_a).is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 use log::info;
 
 use crate::{
-    game_logic::{event_handlers::EventHandler, events::CreatureDealsDamageEvent, EventDispatcher},
-    game_state::board::BoardView,
-    game_state::GameState,
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler,
+        events::CreatureDealsDamageEvent,
+    },
+    game_state::game_state::GameState,
 };
 use async_trait::async_trait;
 
@@ -21,11 +23,15 @@ impl EventHandler for CreatureDealsDamageHandler {
         _dispatcher: &mut EventDispatcher,
     ) {
         let title = game_state
-            .board()
-            .creature_instance(event.creature_id())
-            .definition()
-            .title();
+            .creature_definition_of(event.creature_id())
+            .map_or("An unknown creature", |d| d.title());
 
-        info!("{} deals {} damage", title, event.damage_amount());
+        info!(
+            "{} ({:?}) deals {} damage to {:?}",
+            title,
+            event.creature_id(),
+            event.damage_amount(),
+            event.target_id()
+        );
     }
 }
@@ -1,11 +1,15 @@
 use log::info;
 
 use crate::{
-    game_logic::{event_handlers::EventHandler, events::CreatureDestroyedEvent, EventDispatcher},
-    game_state::board::BoardView,
-    game_state::GameState,
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler,
+        events::CreatureDestroyedEvent,
+    },
+    game_state::game_state::GameState,
+    v2::CreatureInstance,
 };
 use async_trait::async_trait;
+use protocol::entities::Keyword;
 
 #[derive(Default)]
 pub struct CreatureDestroyedEventHandler;
@@ -20,26 +24,223 @@ impl EventHandler for CreatureDestroyedEventHandler {
         game_state: &mut GameState,
         dispatcher: &mut EventDispatcher,
     ) {
-        let pos = game_state.board().pos_with_creature(event.creature_id());
-        let creature_instance = game_state
-            .board_mut()
-            .take_creature_by_id(event.creature_id());
+        let creature_id = event.creature_id();
+
+        let title = game_state.creature_definition_of(creature_id).map_or_else(
+            || "An unknown creature".to_string(),
+            |d| d.title().to_string(),
+        );
+
+        let Some(pos) = game_state.board().creature_pos(creature_id) else {
+            info!(
+                "{} is no longer on the board (instance id: {:?})",
+                title, creature_id
+            );
+            return;
+        };
+
+        let upon_death = game_state
+            .creature_definition_of(creature_id)
+            .map(|d| d.upon_death());
 
-        let title = creature_instance.definition().title().to_string();
-        let creature_id = creature_instance.id();
-        let upon_death = creature_instance.definition().upon_death();
+        // Avengers are chosen while the creature is still on the board, so its companions are known.
+        // Vengeance is decided by the keywords they have now, and allies dying at the same time do not avenge.
+        let avengers: Vec<_> = {
+            let board = game_state.board();
+            let companions = board.companions_of(creature_id);
+
+            board
+                .player_creature_ids(pos.player_id)
+                .into_iter()
+                .filter(|id| *id != creature_id)
+                .filter(|id| {
+                    board.creature_by_id(*id).is_some_and(|c| {
+                        c.get(|c| c.has_keyword(Keyword::Vengeance) && c.health() > 0)
+                    })
+                })
+                .filter_map(|id| {
+                    game_state
+                        .creature_definition_of(id)
+                        .map(|d| (id, companions.contains(&id), d.upon_ally_destroyed()))
+                })
+                .collect()
+        };
+
+        game_state.board_mut().remove_creature(creature_id);
 
         info!("{} was destroyed (instance id: {:?})", title, creature_id);
 
-        game_state.board_mut().add_to_graveyard(creature_instance);
+        if let Some(upon_death) = upon_death {
+            upon_death
+                .action(creature_id, pos, game_state, dispatcher)
+                .await;
+        }
 
-        info!(
-            "{} was added to the graveyard (instance id: {:?})",
-            title, creature_id
+        for (avenger_id, was_companion, upon_ally_destroyed) in avengers {
+            // The death action may have destroyed an avenger too.
+            let still_standing = game_state
+                .board()
+                .creature_by_id(avenger_id)
+                .is_some_and(|c| c.get(CreatureInstance::health) > 0);
+
+            if still_standing {
+                upon_ally_destroyed
+                    .action(
+                        avenger_id,
+                        creature_id,
+                        was_companion,
+                        game_state,
+                        dispatcher,
+                    )
+                    .await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreatureDestroyedEventHandler;
+    use crate::{
+        game_logic::{
+            event_dispatch::{tests::make_test_dispatcher, EventDispatcher},
+            event_handlers::EventHandler,
+            events::CreatureDestroyedEvent,
+        },
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::{
+            actions::{UponAllyDestroyedAction, UponDeathAction},
+            builder::CreatureDefinitionBuilder,
+            BuffBuilder,
+        },
+    };
+    use async_trait::async_trait;
+    use protocol::entities::{BoardPos, CreatureInstanceId, Keyword, PlayerId, RowId};
+    use std::sync::{Arc, Mutex};
+
+    struct RecordDeathAction(Arc<Mutex<Vec<BoardPos>>>);
+
+    #[async_trait]
+    impl UponDeathAction for RecordDeathAction {
+        async fn action(
+            &self,
+            instance_id: CreatureInstanceId,
+            pos: BoardPos,
+            game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            // The creature has already left the board when its death action runs.
+            assert!(game_state.board().creature_by_id(instance_id).is_none());
+            self.0.lock().unwrap().push(pos);
+        }
+    }
+
+    /// Records each vengeance as (avenger, fallen ally, whether they were companions).
+    struct RecordVengeanceAction(Arc<Mutex<Vec<(CreatureInstanceId, CreatureInstanceId, bool)>>>);
+
+    #[async_trait]
+    impl UponAllyDestroyedAction for RecordVengeanceAction {
+        async fn action(
+            &self,
+            instance_id: CreatureInstanceId,
+            ally_id: CreatureInstanceId,
+            was_companion: bool,
+            _game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((instance_id, ally_id, was_companion));
+        }
+    }
+
+    #[test]
+    fn destroyed_expects_upon_death_runs_with_last_pos() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let deaths = Arc::new(Mutex::new(Vec::new()));
+        let mut builder = CreatureDefinitionBuilder::new();
+        builder.upon_death(RecordDeathAction(Arc::clone(&deaths)));
+
+        let first_pos = BoardPos::new(player_a, RowId::FrontRow, 1);
+        let second_pos = BoardPos::new(player_a, RowId::BackRow, 3);
+        let first = place_creature(&mut game_state, &builder, first_pos);
+        let second = place_creature(&mut game_state, &builder, second_pos);
+
+        for creature_id in [second, first] {
+            smol::block_on(CreatureDestroyedEventHandler.handle(
+                &CreatureDestroyedEvent::new(creature_id),
+                &mut game_state,
+                &mut dispatcher,
+            ));
+        }
+
+        assert_eq!(vec![second_pos, first_pos], *deaths.lock().unwrap());
+        assert!(game_state.board().player_creature_ids(player_a).is_empty());
+    }
+
+    #[test]
+    fn destroyed_expects_allies_with_vengeance_avenge_in_board_order() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let vengeances = Arc::new(Mutex::new(Vec::new()));
+        let mut avenger = CreatureDefinitionBuilder::new();
+        avenger
+            .health(1)
+            .upon_ally_destroyed(RecordVengeanceAction(Arc::clone(&vengeances)));
+
+        let fallen = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_a, RowId::FrontRow, 1),
+        );
+        let companion = place_creature(
+            &mut game_state,
+            &avenger,
+            BoardPos::new(player_a, RowId::BackRow, 1),
+        );
+        let ally = place_creature(
+            &mut game_state,
+            &avenger,
+            BoardPos::new(player_a, RowId::FrontRow, 3),
+        );
+        let silenced = place_creature(
+            &mut game_state,
+            &avenger,
+            BoardPos::new(player_a, RowId::BackRow, 4),
+        );
+        game_state
+            .board_mut()
+            .creature_by_id_mut(silenced)
+            .unwrap()
+            .get_mut(|c| {
+                c.add_buff(
+                    BuffBuilder::new(silenced)
+                        .lose_keyword(Keyword::Vengeance)
+                        .build(),
+                );
+            });
+        place_creature(
+            &mut game_state,
+            &avenger,
+            BoardPos::new(player_b, RowId::FrontRow, 1),
         );
 
-        upon_death
-            .action(creature_id, pos, game_state, dispatcher)
-            .await;
+        smol::block_on(CreatureDestroyedEventHandler.handle(
+            &CreatureDestroyedEvent::new(fallen),
+            &mut game_state,
+            &mut dispatcher,
+        ));
+
+        assert_eq!(
+            vec![(ally, fallen, false), (companion, fallen, true)],
+            *vengeances.lock().unwrap()
+        );
     }
 }
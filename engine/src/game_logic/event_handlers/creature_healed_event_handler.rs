@@ -1,8 +1,11 @@
 use log::info;
 
 use crate::{
-    game_logic::{event_handlers::EventHandler, events::CreatureHealedEvent, EventDispatcher},
-    game_state::GameState,
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::CreatureHealedEvent,
+    },
+    game_state::game_state::GameState,
+    v2::CreatureInstance,
 };
 use async_trait::async_trait;
 
@@ -19,23 +22,25 @@ impl EventHandler for CreatureHealedEventHandler {
         game_state: &mut GameState,
         _dispatcher: &mut EventDispatcher,
     ) {
-        let healed_creature = game_state
-            .board_mut()
-            .creature_instance_mut(event.creature_id());
-
-        let starting_health = healed_creature.health();
-        let max_health = healed_creature.definition().health();
-
-        let new_health = std::cmp::min(max_health, starting_health + event.heal_amount() as i32);
+        let mut board = game_state.board_mut();
+        let Some(mut healed_creature) = board.creature_by_id_mut(event.creature_id()) else {
+            info!(
+                "Creature {:?} is no longer on the board, so it cannot be healed.",
+                event.creature_id()
+            );
+            return;
+        };
+
+        let starting_health = healed_creature.get(CreatureInstance::health);
+        healed_creature.get_mut(|c| c.heal(event.heal_amount()));
+        let new_health = healed_creature.get(CreatureInstance::health);
 
         info!(
-            "{} heals {} health, from {} to {}.",
-            healed_creature.definition().title(),
+            "Creature {:?} heals {} health, from {} to {}.",
+            event.creature_id(),
             event.heal_amount(),
             starting_health,
             new_health
         );
-
-        healed_creature.receive_heal(event.heal_amount());
     }
 }
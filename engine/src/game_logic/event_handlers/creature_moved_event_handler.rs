@@ -0,0 +1,106 @@
+use log::info;
+
+use crate::{
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::CreatureMovedEvent,
+    },
+    game_state::game_state::GameState,
+};
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct CreatureMovedEventHandler;
+
+#[async_trait]
+impl EventHandler for CreatureMovedEventHandler {
+    type Event = CreatureMovedEvent;
+
+    async fn handle(
+        &self,
+        event: &CreatureMovedEvent,
+        game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+        info!(
+            "Creature {:?} moves to {:?} ({:?})",
+            event.creature_id(),
+            event.to(),
+            event.reason()
+        );
+
+        game_state
+            .board_mut()
+            .move_creature(event.creature_id(), event.to());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_logic::{
+            event_dispatch::tests::make_recording_dispatcher,
+            events::{CreatureMovedEvent, MoveReason},
+        },
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::{builder::CreatureDefinitionBuilder, BuffBuilder, CreatureInstance},
+    };
+    use protocol::{
+        entities::{BoardPos, PlayerId, RowId},
+        from_server::{Notification, VisualEvent},
+    };
+
+    #[test]
+    fn move_expects_creature_keeps_buffs_and_both_players_shown() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let (mut dispatcher, owner, opponent) = make_recording_dispatcher(&game_state);
+
+        let from = BoardPos::new(player_a, RowId::BackRow, 1);
+        let to = BoardPos::new(player_a, RowId::FrontRow, 3);
+        let creature_id = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new()
+                .attack(1)
+                .health(1)
+                .width(2),
+            from,
+        );
+        game_state
+            .board_mut()
+            .creature_by_id_mut(creature_id)
+            .unwrap()
+            .get_mut(|c| c.add_buff(BuffBuilder::new(creature_id).attack(2).build()));
+
+        smol::block_on(dispatcher.dispatch(
+            CreatureMovedEvent::new(creature_id, to, MoveReason::Effect),
+            &mut game_state,
+        ))
+        .unwrap();
+
+        let board = game_state.board();
+        assert_eq!(
+            vec![to, BoardPos::new(player_a, RowId::FrontRow, 4)],
+            board.slots_with_creature(creature_id)
+        );
+        assert!(board.creature_at_pos(from).is_none());
+        assert_eq!(
+            3,
+            board
+                .creature_by_id(creature_id)
+                .unwrap()
+                .get(CreatureInstance::attack)
+        );
+
+        for notifier in [owner, opponent] {
+            let seen = notifier.0.lock().unwrap();
+            let [Notification::VisualEvent(VisualEvent::CreatureMoved(moved))] = seen.as_slice()
+            else {
+                panic!("Expected one move, found {seen:?}");
+            };
+            assert_eq!(
+                (creature_id, from, to),
+                (moved.creature_id, moved.from, moved.to)
+            );
+        }
+    }
+}
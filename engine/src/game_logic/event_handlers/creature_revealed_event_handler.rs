@@ -0,0 +1,147 @@
+use log::info;
+
+use crate::{
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler,
+        events::CreatureRevealedEvent,
+    },
+    game_state::game_state::GameState,
+    v2::CreatureInstance,
+};
+use async_trait::async_trait;
+use protocol::{
+    entities::{AsId, IsEntity},
+    from_server::{EntityUpdate, Notification},
+};
+
+/// The properties of a creature's view that are hidden from the opponent until it is revealed.
+const REVEALED_PROPERTIES: [&str; 5] = ["definition", "cost", "attack", "health", "keywords"];
+
+#[derive(Default)]
+pub struct CreatureRevealedEventHandler;
+
+#[async_trait]
+impl EventHandler for CreatureRevealedEventHandler {
+    type Event = CreatureRevealedEvent;
+
+    async fn handle(
+        &self,
+        event: &CreatureRevealedEvent,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        let creature_id = event.creature_id();
+
+        {
+            let mut board = game_state.board_mut();
+            let Some(mut creature) = board.creature_by_id_mut(creature_id) else {
+                return;
+            };
+            if !creature.get(CreatureInstance::is_hidden) {
+                return;
+            }
+            creature.get_mut(CreatureInstance::reveal);
+        }
+
+        let Some(owner) = game_state
+            .board()
+            .creature_pos(creature_id)
+            .map(|pos| pos.player_id)
+        else {
+            return;
+        };
+        let opponent = game_state.other_player(owner);
+
+        info!("{:?} is revealed.", creature_id);
+
+        // The opponent's copy of the creature still holds the face-down placeholder.
+        let Some(view) = game_state.creature_view(creature_id, opponent) else {
+            return;
+        };
+        let view_json = serde_json::to_value(&view).expect("Creature views always serialize.");
+
+        let update = EntityUpdate {
+            id: creature_id.as_id(),
+            entity_type_id: protocol::entities::CreatureInstance::type_id(),
+            property_names: REVEALED_PROPERTIES.map(String::from).to_vec(),
+            property_values: REVEALED_PROPERTIES
+                .iter()
+                .map(|property| view_json[property].to_string())
+                .collect(),
+        };
+
+        dispatcher
+            .player_notifier(opponent)
+            .notify(Notification::EntityUpdate(update))
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_logic::{
+            event_dispatch::tests::make_recording_dispatcher, events::CreatureRevealedEvent,
+        },
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::builder::CreatureDefinitionBuilder,
+    };
+    use protocol::{
+        entities::{BoardPos, Keyword, PlayerId, RowId},
+        from_server::{Notification, VisualEvent},
+    };
+
+    #[test]
+    fn reveal_expects_both_shown_and_opponent_sent_definition_once() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let (mut dispatcher, owner, opponent) = make_recording_dispatcher(&game_state);
+
+        let creature_id = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new()
+                .title("Lurker".to_string())
+                .health(1)
+                .keyword(Keyword::Hidden),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        assert!(game_state
+            .creature_view(creature_id, player_b)
+            .unwrap()
+            .definition()
+            .is_hidden());
+
+        for _ in 0..2 {
+            smol::block_on(
+                dispatcher.dispatch(CreatureRevealedEvent::new(creature_id), &mut game_state),
+            )
+            .unwrap();
+        }
+
+        assert!(!game_state
+            .creature_view(creature_id, player_b)
+            .unwrap()
+            .definition()
+            .is_hidden());
+
+        let owner_seen = owner.0.lock().unwrap();
+        assert!(matches!(
+            owner_seen.as_slice(),
+            [Notification::VisualEvent(VisualEvent::CreatureRevealed(_))]
+        ));
+
+        let opponent_seen = opponent.0.lock().unwrap();
+        let [Notification::VisualEvent(VisualEvent::CreatureRevealed(revealed)), Notification::EntityUpdate(update)] =
+            opponent_seen.as_slice()
+        else {
+            panic!("Expected one reveal, found {opponent_seen:?}");
+        };
+        assert_eq!(
+            "Lurker",
+            revealed.creature.definition().revealed().unwrap().title
+        );
+        assert_eq!("definition", update.property_names[0]);
+        assert!(update.property_values[0].contains("Lurker"));
+    }
+}
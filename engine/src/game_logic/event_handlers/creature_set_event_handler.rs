@@ -1,9 +1,17 @@
+use log::info;
+
 use crate::{
-    game_logic::{event_handlers::EventHandler, events::CreatureSetEvent, EventDispatcher},
-    game_state::GameState,
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::CreatureSetEvent,
+    },
+    game_state::game_state::GameState,
+    v2::CreatureInstance,
 };
 use async_trait::async_trait;
-use log::info;
+use protocol::{
+    entities::{EntityPosition, Keyword},
+    from_server::{EntityAdded, Notification},
+};
 
 #[derive(Default)]
 pub struct CreatureSetEventHandler;
@@ -16,31 +24,52 @@ impl EventHandler for CreatureSetEventHandler {
         &self,
         event: &CreatureSetEvent,
         game_state: &mut GameState,
-        _dispatcher: &mut EventDispatcher,
+        dispatcher: &mut EventDispatcher,
     ) {
         let player_id = event.player_id();
-        let target_position = event.target_position();
+        let pos = event.target_position();
 
-        let card_instance = game_state
-            .board_mut()
-            .take_tracked_pending_card(event.card_id())
-            .expect("The pending card was expected but not found");
+        let (creature, upon_summon) = {
+            let definition = game_state
+                .creature_definition(event.definition_id())
+                .expect("The set creature's definition must be known.");
 
-        if card_instance.id() != event.card_id() {
-            panic!(
-                "Took a card from purgatory, but it wasn't the one in the summon event request."
+            info!(
+                "{} is set on pos {:?} by player {:?}",
+                definition.title(),
+                pos,
+                player_id
             );
-        }
 
-        info!(
-            "{} is set on pos {:?} by player {:?}",
-            card_instance.definition().title(),
-            target_position,
-            player_id
-        );
+            (
+                CreatureInstance::new_from_definition(event.card_id(), definition),
+                definition.upon_summon(),
+            )
+        };
 
-        game_state
-            .board_mut()
-            .set_creature_at_pos(target_position, card_instance);
+        let instance_id = creature.instance_id();
+        let has_summon = creature.has_keyword(Keyword::Summon);
+
+        game_state.board_mut().set_creature_at_pos(creature, pos);
+
+        // Each player is sent the creature as they are allowed to see it
+        for viewer in [game_state.player_a_id(), game_state.player_b_id()] {
+            if let Some(view) = game_state.creature_view(instance_id, viewer) {
+                dispatcher
+                    .player_notifier(viewer)
+                    .notify(Notification::EntityAdded(EntityAdded::new(
+                        instance_id,
+                        view.into(),
+                        EntityPosition::BoardPos(pos),
+                    )))
+                    .await;
+            }
+        }
+
+        if has_summon {
+            upon_summon
+                .action(instance_id, pos, game_state, dispatcher)
+                .await;
+        }
     }
 }
@@ -1,17 +1,12 @@
 use log::info;
-use protocol::{
-    entities::{CreatureDefinition, CreatureInstance, Id, IsEntity},
-    from_server::EntityUpdate,
-};
 
 use crate::{
     game_logic::{
-        event_handlers::EventHandler,
-        events::{CreatureDestroyedEvent, CreatureTakesDamageEvent},
-        EventDispatcher,
+        event_dispatch::EventDispatcher, event_handlers::EventHandler,
+        events::CreatureTakesDamageEvent,
     },
-    game_state::board::BoardView,
-    game_state::GameState,
+    game_state::game_state::GameState,
+    v2::CreatureInstance,
 };
 use async_trait::async_trait;
 
@@ -28,41 +23,132 @@ impl EventHandler for CreatureTakesDamageHandler {
         game_state: &mut GameState,
         dispatcher: &mut EventDispatcher,
     ) {
-        let creature_instance = game_state.board().creature_instance(event.creature_id());
-        let title = creature_instance.definition().title();
+        let creature_id = event.creature_id();
 
-        info!("{} takes {} damage", title, event.damage_amount());
+        // Companions are found before the damage, since the creature may not survive it.
+        let companions: Vec<_> = game_state
+            .board()
+            .companions_of(creature_id)
+            .into_iter()
+            .filter_map(|companion_id| {
+                game_state
+                    .creature_definition_of(companion_id)
+                    .map(|d| (companion_id, d.upon_companion_damaged()))
+            })
+            .collect();
 
-        let entity_update = EntityUpdate {
-            id: Id::new(),
-            entity_type_id: CreatureInstance::type_id(),
-            property_names: vec!["testing".into()],
-            property_values: vec!["testing".into()],
-        };
+        let health = {
+            let mut board = game_state.board_mut();
+            let Some(mut creature) = board.creature_by_id_mut(creature_id) else {
+                info!(
+                    "{:?} is no longer on the board, so takes no damage",
+                    creature_id
+                );
+                return;
+            };
 
-        game_state.update_by_id(event.creature_id(), |c| {
-            c.take_damage(event.damage_amount());
-        });
+            creature.get_mut(|c| c.take_damage(event.damage_amount()));
+            creature.get(CreatureInstance::health)
+        };
 
-        let card_trigger = game_state
-            .board()
-            .creature_instance(event.creature_id())
-            .definition()
-            .upon_receive_damage();
+        info!(
+            "{:?} takes {} damage, leaving {} health",
+            creature_id,
+            event.damage_amount(),
+            health
+        );
 
-        card_trigger
-            .action(event.creature_id(), game_state, dispatcher)
-            .await;
+        if health <= 0 {
+            dispatcher.destroy_creature(creature_id, game_state).await;
+        }
 
-        if game_state
-            .board()
-            .creature_instance(event.creature_id())
-            .health()
-            <= 0
-        {
-            dispatcher
-                .dispatch(CreatureDestroyedEvent::new(event.creature_id()), game_state)
+        for (companion_id, upon_companion_damaged) in companions {
+            upon_companion_damaged
+                .action(
+                    companion_id,
+                    creature_id,
+                    event.damage_amount(),
+                    game_state,
+                    dispatcher,
+                )
                 .await;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_logic::{
+            event_dispatch::{tests::make_test_dispatcher, EventDispatcher},
+            events::CreatureTakesDamageEvent,
+        },
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::{actions::UponCompanionDamagedAction, builder::CreatureDefinitionBuilder},
+    };
+    use async_trait::async_trait;
+    use protocol::entities::{BoardPos, CreatureInstanceId, PlayerId, RowId};
+    use std::sync::{Arc, Mutex};
+
+    /// Records each companion damage as (companion, amount, whether the companion survived).
+    struct RecordCompanionDamaged(Arc<Mutex<Vec<(CreatureInstanceId, usize, bool)>>>);
+
+    #[async_trait]
+    impl UponCompanionDamagedAction for RecordCompanionDamaged {
+        async fn action(
+            &self,
+            _instance_id: CreatureInstanceId,
+            companion_id: CreatureInstanceId,
+            damage_amount: usize,
+            game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            let survived = game_state.board().creature_by_id(companion_id).is_some();
+            self.0
+                .lock()
+                .unwrap()
+                .push((companion_id, damage_amount, survived));
+        }
+    }
+
+    #[test]
+    fn damage_expects_upon_companion_damaged_runs_after_damage_resolves() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let damaged = Arc::new(Mutex::new(Vec::new()));
+        let mut watcher = CreatureDefinitionBuilder::new();
+        watcher
+            .health(5)
+            .upon_companion_damaged(RecordCompanionDamaged(Arc::clone(&damaged)));
+        place_creature(
+            &mut game_state,
+            &watcher,
+            BoardPos::new(player_a, RowId::BackRow, 0),
+        );
+        let companion = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(3),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let stranger = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(3),
+            BoardPos::new(player_a, RowId::FrontRow, 1),
+        );
+
+        for (creature_id, amount) in [(companion, 1), (stranger, 1), (companion, 2)] {
+            smol::block_on(dispatcher.dispatch(
+                CreatureTakesDamageEvent::new(creature_id, amount),
+                &mut game_state,
+            ))
+            .unwrap();
+        }
+
+        assert_eq!(
+            vec![(companion, 1, true), (companion, 2, false)],
+            *damaged.lock().unwrap()
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     game_logic::{
         event_dispatch::EventDispatcher,
         event_handlers::EventHandler,
-        events::{AddCardToHandEvent, CreatureTakesDamageEvent, DrawCardEvent},
+        events::{AddCardToHandEvent, DrawCardEvent, HeroTakesDamageEvent},
     },
     game_state::game_state::GameState,
 };
@@ -23,39 +23,119 @@ impl EventHandler for DrawCardEventHandler {
         dispatcher: &mut EventDispatcher,
     ) {
         let player_id = event.player_id();
-        let deck = game_state.deck(player_id);
-        let len = deck.get(|d| d.len());
-        info!("Player {player_id:?} draws a card. New deck len: {len}");
-
-        // info!(
-        //     "Player {:?} draws a card. Deck size before draw: {}",
-        //     player_id,
-        //     game_state.deck(player_id).len()
-        // );
-
-        // let card = game_state.draw_card(player_id);
-
-        // if let Some(card) = card {
-        //     let card_id = card.id();
-        //     game_state.board_mut().track_pending_card(card);
-        //     let add_to_hand_event = AddCardToHandEvent::new(player_id, card_id);
-        //     dispatcher.dispatch(add_to_hand_event, game_state).await;
-        // } else {
-        //     info!(
-        //         "Player {:?} had no cards in deck, so drew nothing. Hero receives 1 damage.",
-        //         player_id
-        //     );
-
-        //     let hero_id = game_state
-        //         .board()
-        //         .all_characters_slots()
-        //         .for_player(player_id)
-        //         .heroes_only()
-        //         .creatures()
-        //         .expect_single()
-        //         .id();
-
-        //     let hero_damaged_event = CreatureTakesDamageEvent::new(hero_id, 1);
-        //     dispatcher.dispatch(hero_damaged_event, game_state).await;
+
+        let mut drawn = None;
+        let mut empty_draw_count = 0;
+        {
+            let mut deck = game_state.deck_mut(player_id);
+            deck.get_mut(|d| {
+                drawn = d.draw();
+                empty_draw_count = d.empty_draw_count();
+            });
+        }
+
+        if let Some(card) = drawn {
+            info!(
+                "Player {:?} draws a card. Deck size after draw: {}",
+                player_id,
+                game_state.deck(player_id).get(|d| d.len())
+            );
+
+            let card_id = game_state.next_instance_id();
+            let add_to_hand_event =
+                AddCardToHandEvent::new(player_id, card_id, card.definition_id());
+            dispatcher
+                .dispatch_triggered(add_to_hand_event, game_state)
+                .await;
+        } else {
+            let damage = game_state.rules().fatigue.damage(empty_draw_count);
+
+            info!(
+                "Player {:?} had no cards in deck, so drew nothing. Hero receives {} fatigue damage.",
+                player_id, damage
+            );
+
+            if damage > 0 {
+                let hero_damaged_event = HeroTakesDamageEvent::new(player_id, damage);
+                dispatcher
+                    .dispatch_triggered(hero_damaged_event, game_state)
+                    .await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DrawCardEventHandler;
+    use crate::{
+        game_logic::{
+            event_dispatch::tests::make_test_dispatcher, event_handlers::EventHandler,
+            events::DrawCardEvent,
+        },
+        game_state::{game_state::GameState, CardInDeck, FatigueRule, GameRules},
+        v2::CreatureDefinitionId,
+    };
+    use protocol::entities::PlayerId;
+
+    #[test]
+    fn draw_card_when_deck_has_cards_expects_top_card_in_hand() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let bottom = CreatureDefinitionId::new();
+        let top = CreatureDefinitionId::new();
+        game_state.deck_mut(player_a).get_mut(|d| {
+            d.add_card(CardInDeck::new(bottom));
+            d.add_card(CardInDeck::new(top));
+        });
+
+        smol::block_on(DrawCardEventHandler.handle(
+            &DrawCardEvent::new(player_a),
+            &mut game_state,
+            &mut dispatcher,
+        ));
+
+        assert_eq!(1, game_state.deck(player_a).get(|d| d.len()));
+        assert_eq!(1, game_state.hand(player_a).len());
+        assert_eq!(
+            vec![top],
+            game_state
+                .hand(player_a)
+                .cards()
+                .map(|c| c.get(|c| c.definition_id()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn draw_card_when_deck_empty_expects_incrementing_fatigue() {
+        let rules = GameRules {
+            fatigue: FatigueRule::Incrementing,
+            ..GameRules::default()
+        };
+        let mut game_state = GameState::new_with_rules(PlayerId::new(), PlayerId::new(), rules);
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let starting_health = game_state.board().player_hero(player_a).get(|h| h.health());
+
+        smol::block_on(async {
+            for _ in 0..3 {
+                DrawCardEventHandler
+                    .handle(
+                        &DrawCardEvent::new(player_a),
+                        &mut game_state,
+                        &mut dispatcher,
+                    )
+                    .await;
+            }
+        });
+
+        let health = game_state.board().player_hero(player_a).get(|h| h.health());
+
+        assert_eq!(starting_health - (1 + 2 + 3), health);
+        assert!(game_state.hand(player_a).is_empty());
     }
 }
@@ -2,11 +2,9 @@ use log::info;
 
 use crate::{
     game_logic::{
-        event_handlers::EventHandler,
-        events::{EndTurnEvent, TurnStartEvent},
-        EventDispatcher,
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::EndTurnEvent,
     },
-    game_state::{GameState, GameStateView, IterAddons},
+    game_state::game_state::GameState,
 };
 use async_trait::async_trait;
 
@@ -19,28 +17,14 @@ impl EventHandler for EndTurnEventHandler {
 
     async fn handle(
         &self,
-        _event: &EndTurnEvent,
+        event: &EndTurnEvent,
         game_state: &mut GameState,
-        dispatcher: &mut EventDispatcher,
+        _dispatcher: &mut EventDispatcher,
     ) {
-        info!("Player {:?} ends turn", game_state.cur_player_id());
-
-        // Trigger 'upon turn end' events
-        let end_turn_actions = game_state
-            .iter()
-            .creatures()
-            .map(|c| (c.id(), c.definition().upon_turn_end()))
-            .collect::<Vec<_>>()
-            .into_iter();
-
-        for (id, action) in end_turn_actions {
-            action.action(id, game_state, dispatcher).await;
-        }
+        info!("Player {:?} ends turn", event.0);
 
         let next_player = game_state.set_next_player_turn();
 
-        dispatcher
-            .dispatch(TurnStartEvent(next_player), game_state)
-            .await;
+        info!("Next turn belongs to player {:?}", next_player);
     }
 }
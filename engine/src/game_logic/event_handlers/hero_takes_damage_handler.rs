@@ -0,0 +1,38 @@
+use log::info;
+
+use crate::{
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::HeroTakesDamageEvent,
+    },
+    game_state::game_state::GameState,
+};
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct HeroTakesDamageHandler;
+
+#[async_trait]
+impl EventHandler for HeroTakesDamageHandler {
+    type Event = HeroTakesDamageEvent;
+
+    async fn handle(
+        &self,
+        event: &HeroTakesDamageEvent,
+        game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+        let player_id = event.player_id();
+
+        let mut board = game_state.board_mut();
+        let mut hero = board.player_hero_mut(player_id);
+        hero.get_mut(|h| h.take_damage(event.damage_amount()));
+        let health = hero.get(|h| h.health());
+
+        info!(
+            "Hero of player {:?} takes {} damage. Health is now {}.",
+            player_id,
+            event.damage_amount(),
+            health
+        );
+    }
+}
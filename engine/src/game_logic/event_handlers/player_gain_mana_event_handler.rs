@@ -1,8 +1,10 @@
 use log::info;
 
 use crate::{
-    game_logic::{event_handlers::EventHandler, events::PlayerGainManaEvent, EventDispatcher},
-    game_state::GameState,
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::PlayerGainManaEvent,
+    },
+    game_state::game_state::GameState,
 };
 use async_trait::async_trait;
 
@@ -25,6 +27,13 @@ impl EventHandler for PlayerGainManaEventHandler {
             event.gain_count()
         );
 
-        game_state.raise_mana_limit(event.player_id(), event.gain_count());
+        let cap = game_state.rules().max_mana_limit;
+
+        let mut board = game_state.board_mut();
+        let mut hero = board.player_hero_mut(event.player_id());
+        hero.get_mut(|h| {
+            h.raise_mana_limit(event.gain_count(), cap);
+            h.refresh_mana();
+        });
     }
 }
@@ -1,8 +1,10 @@
 use log::info;
 
 use crate::{
-    game_logic::{event_handlers::EventHandler, events::PlayerSpendManaEvent, EventDispatcher},
-    game_state::{GameState, GameStateView},
+    game_logic::{
+        event_dispatch::EventDispatcher, event_handlers::EventHandler, events::PlayerSpendManaEvent,
+    },
+    game_state::game_state::GameState,
 };
 use async_trait::async_trait;
 
@@ -23,13 +25,8 @@ impl EventHandler for PlayerSpendManaEventHandler {
 
         info!("Player {:?} spends {} mana.", player_id, event.mana_count());
 
-        let cur_mana = game_state.player_mana(player_id);
-
-        assert!(
-            event.mana_count() <= cur_mana,
-            "Player does not have enough mana."
-        );
-
-        game_state.reduce_mana(event.player_id(), event.mana_count());
+        let mut board = game_state.board_mut();
+        let mut hero = board.player_hero_mut(player_id);
+        hero.get_mut(|h| h.spend_mana(event.mana_count()));
     }
 }
@@ -2,12 +2,11 @@ use log::info;
 
 use crate::{
     game_logic::{
+        event_dispatch::EventDispatcher,
         event_handlers::EventHandler,
-        events::{CreatureTakesDamageEvent, PosTakesDamageEvent},
-        EventDispatcher,
+        events::{CreatureTakesDamageEvent, HeroTakesDamageEvent, PosTakesDamageEvent},
     },
-    game_state::board::BoardView,
-    game_state::GameState,
+    game_state::game_state::GameState,
 };
 use async_trait::async_trait;
 
@@ -25,16 +24,147 @@ impl EventHandler for PosTakesDamageHandler {
         dispatcher: &mut EventDispatcher,
     ) {
         info!(
-            "Slot {:?} takes {} damage",
-            event.pos(),
+            "Slots {:?} take {} damage",
+            event.slots(),
             event.damage_amount()
         );
 
-        if let Some(creature_there) = game_state.board().creature_at_pos(event.pos()) {
-            let damage_event =
-                CreatureTakesDamageEvent::new(creature_there.id(), event.damage_amount());
+        let (hero_slots, creature_slots): (Vec<_>, Vec<_>) =
+            event.slots().iter().partition(|pos| pos.row_id.is_hero());
 
-            dispatcher.dispatch(damage_event, game_state).await;
+        // Creatures are hit once per slot, ordered by the first slot they were hit in.
+        let hits = game_state
+            .board()
+            .creature_hits_for_slots(creature_slots.into_iter().copied());
+
+        // Everything in the area is hit before anything hit is destroyed.
+        dispatcher.begin_simultaneous();
+
+        for (creature_id, count) in hits {
+            for _ in 0..count {
+                let damage_event =
+                    CreatureTakesDamageEvent::new(creature_id, event.damage_amount());
+
+                dispatcher
+                    .dispatch_triggered(damage_event, game_state)
+                    .await;
+            }
+        }
+
+        for pos in hero_slots {
+            let damage_event = HeroTakesDamageEvent::new(pos.player_id, event.damage_amount());
+
+            dispatcher
+                .dispatch_triggered(damage_event, game_state)
+                .await;
+        }
+
+        dispatcher.end_simultaneous(game_state).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_logic::{
+            event_dispatch::tests::{make_recording_dispatcher, make_test_dispatcher},
+            events::PosTakesDamageEvent,
+        },
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::{builder::CreatureDefinitionBuilder, CreatureInstance},
+    };
+    use protocol::{
+        entities::{BoardPos, PlayerId, RowId},
+        from_server::{Notification, VisualEvent},
+    };
+
+    #[test]
+    fn handle_when_slot_covered_by_wide_creature_expects_damaged_once() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_b = game_state.player_b_id();
+
+        let wide = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(5).width(2),
+            BoardPos::new(player_b, RowId::BackRow, 2),
+        );
+
+        let mut dispatcher = make_test_dispatcher(&game_state);
+        smol::block_on(async {
+            for index in [1, 3] {
+                dispatcher
+                    .dispatch(
+                        PosTakesDamageEvent::new(BoardPos::new(player_b, RowId::BackRow, index), 2),
+                        &mut game_state,
+                    )
+                    .await
+                    .unwrap();
+            }
+        });
+
+        assert_eq!(
+            3,
+            game_state
+                .board()
+                .creature_by_id(wide)
+                .unwrap()
+                .get(CreatureInstance::health)
+        );
+    }
+
+    #[test]
+    fn handle_when_area_covers_wide_creature_expects_damaged_per_slot() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_b = game_state.player_b_id();
+
+        let wide = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(5).width(2),
+            BoardPos::new(player_b, RowId::BackRow, 2),
+        );
+        let hero_health = game_state.board().player_hero(player_b).get(|h| h.health());
+
+        let area = [1, 2, 3]
+            .into_iter()
+            .map(|index| BoardPos::new(player_b, RowId::BackRow, index))
+            .chain([BoardPos::hero_pos(player_b)])
+            .collect();
+
+        let mut dispatcher = make_test_dispatcher(&game_state);
+        smol::block_on(dispatcher.dispatch(PosTakesDamageEvent::area(area, 2), &mut game_state))
+            .unwrap();
+
+        assert_eq!(
+            1,
+            game_state
+                .board()
+                .creature_by_id(wide)
+                .unwrap()
+                .get(CreatureInstance::health)
+        );
+        assert_eq!(
+            hero_health - 2,
+            game_state.board().player_hero(player_b).get(|h| h.health())
+        );
+    }
+
+    #[test]
+    fn handle_when_slot_empty_expects_only_shown() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_b = game_state.player_b_id();
+        let (mut dispatcher, notifier_a, notifier_b) = make_recording_dispatcher(&game_state);
+
+        let pos = BoardPos::new(player_b, RowId::FrontRow, 0);
+        smol::block_on(dispatcher.dispatch(PosTakesDamageEvent::new(pos, 3), &mut game_state))
+            .unwrap();
+
+        for notifier in [notifier_a, notifier_b] {
+            let seen = notifier.0.lock().unwrap();
+            let [Notification::VisualEvent(VisualEvent::PosTakesDamage(hit))] = seen.as_slice()
+            else {
+                panic!("Expected one hit, found {seen:?}");
+            };
+            assert_eq!((vec![pos], 3), (hit.slots.clone(), hit.damage_amount));
         }
     }
 }
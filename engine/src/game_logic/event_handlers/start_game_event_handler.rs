@@ -1,14 +1,10 @@
 use log::info;
-use protocol::{
-    entities::{BoardPos, Entity, EntityPosition, PlayerHero},
-    from_server::{EntityAdded, Notification},
-};
 
 use crate::{
     game_logic::{
         event_dispatch::EventDispatcher,
         event_handlers::EventHandler,
-        events::{DrawCardEvent, StartGameEvent, TurnStartEvent},
+        events::{DrawCardEvent, StartGameEvent},
     },
     game_state::game_state::GameState,
 };
@@ -70,13 +66,24 @@ impl EventHandler for StartGameEventHandler {
         //     )))
         //     .await;
 
-        // 2. Players draw initial hand
+        // 2. Shuffle both decks and flip for who goes first, using the game's seeded RNG
+        game_state.shuffle_deck(player_a_id);
+        game_state.shuffle_deck(player_b_id);
+
+        let first_player_id = game_state.flip_for_first_player();
+        info!(
+            "Game seed: {}. Player {:?} goes first.",
+            game_state.seed(),
+            first_player_id
+        );
+
+        // 3. Players draw initial hand
         for _ in 0..START_GAME_CARD_COUNT {
             dispatcher
-                .dispatch(DrawCardEvent::new(player_a_id), game_state)
+                .dispatch_triggered(DrawCardEvent::new(player_a_id), game_state)
                 .await;
             dispatcher
-                .dispatch(DrawCardEvent::new(player_b_id), game_state)
+                .dispatch_triggered(DrawCardEvent::new(player_b_id), game_state)
                 .await;
         }
     }
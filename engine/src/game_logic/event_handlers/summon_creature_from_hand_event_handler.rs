@@ -2,13 +2,18 @@ use log::{debug, info};
 
 use crate::{
     game_logic::{
+        event_dispatch::EventDispatcher,
         event_handlers::EventHandler,
-        events::{CreatureSetEvent, CreatureSummonedFromHandEvent, PlayerSpendManaEvent},
-        EventDispatcher,
+        events::{CreatureSummonedFromHandEvent, PlayerSpendManaEvent},
     },
-    game_state::GameState,
+    game_state::game_state::GameState,
+    v2::CreatureInstance,
 };
 use async_trait::async_trait;
+use protocol::{
+    entities::{EntityPosition, Keyword},
+    from_server::{EntityAdded, Notification},
+};
 
 #[derive(Default)]
 pub struct SummonCreatureFromHandEventHandler;
@@ -24,6 +29,7 @@ impl EventHandler for SummonCreatureFromHandEventHandler {
         dispatcher: &mut EventDispatcher,
     ) {
         let player_id = event.player_id();
+        let pos = event.board_pos();
 
         // Take the card out of the player's hand
         debug!("Taking card from player's hand.");
@@ -31,44 +37,225 @@ impl EventHandler for SummonCreatureFromHandEventHandler {
             .hand_mut(player_id)
             .take_card(event.hand_card_id());
 
-        {
-            let creature_name = card_from_hand.definition().title();
-            info!("Player summons {} ({:?})", creature_name, player_id);
-        }
+        let (creature, mana_amount, upon_summon) = {
+            let definition = game_state
+                .creature_definition(card_from_hand.definition_id())
+                .expect("The summoned card's definition must be known.");
 
-        let mana_amount = card_from_hand.definition().cost();
-        let upon_summon = card_from_hand.definition().upon_summon();
-        let card_instance_id = card_from_hand.id();
+            info!("Player {:?} summons {}", player_id, definition.title());
 
-        game_state.board_mut().track_pending_card(card_from_hand);
+            (
+                CreatureInstance::new_from_definition(card_from_hand.card_id(), definition),
+                definition.cost(),
+                definition.upon_summon(),
+            )
+        };
 
-        // Spend the mana
-        {
-            dispatcher
-                .dispatch(
-                    PlayerSpendManaEvent::new(player_id, mana_amount as u32),
-                    game_state,
-                )
-                .await;
+        let instance_id = creature.instance_id();
+        let has_summon = creature.has_keyword(Keyword::Summon);
+
+        // Set the creature on the board
+        game_state.board_mut().set_creature_at_pos(creature, pos);
+
+        // Each player is sent the creature as they are allowed to see it
+        for viewer in [game_state.player_a_id(), game_state.player_b_id()] {
+            if let Some(view) = game_state.creature_view(instance_id, viewer) {
+                dispatcher
+                    .player_notifier(viewer)
+                    .notify(Notification::EntityAdded(EntityAdded::new(
+                        instance_id,
+                        view.into(),
+                        EntityPosition::BoardPos(pos),
+                    )))
+                    .await;
+            }
         }
 
-        let pos = event.board_pos();
+        // Spend the mana
+        dispatcher
+            .dispatch_triggered(
+                PlayerSpendManaEvent::new(player_id, u32::try_from(mana_amount).unwrap_or(0)),
+                game_state,
+            )
+            .await;
 
         // Perform the "upon summon"
-        {
+        if has_summon {
             upon_summon
-                .action(card_instance_id, pos, game_state, dispatcher)
+                .action(instance_id, pos, game_state, dispatcher)
                 .await;
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SummonCreatureFromHandEventHandler;
+    use crate::game_agent::tests::RecordingNotifier;
+    use crate::{
+        game_logic::{
+            event_dispatch::{
+                tests::{make_recording_dispatcher, make_test_dispatcher},
+                EventDispatcher,
+            },
+            event_handlers::EventHandler,
+            events::CreatureSummonedFromHandEvent,
+        },
+        game_state::{game_state::GameState, CardInHand},
+        v2::{actions::UponSummonAction, builder::CreatureDefinitionBuilder},
+    };
+    use async_trait::async_trait;
+    use protocol::{
+        entities::{
+            BoardPos, CreatureInstance as CreatureInstanceView, CreatureInstanceId, EntityPosition,
+            Keyword, PlayerId, RowId,
+        },
+        from_server::{Notification, VisualEvent},
+    };
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    struct RecordSummonAction(Arc<AtomicBool>);
+
+    #[async_trait]
+    impl UponSummonAction for RecordSummonAction {
+        async fn action(
+            &self,
+            instance_id: CreatureInstanceId,
+            pos: BoardPos,
+            game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            // The creature is already on the board when its summon action runs.
+            let on_board = game_state
+                .board()
+                .creature_at_pos(pos)
+                .map(|c| c.get(|c| c.instance_id()));
+
+            assert_eq!(Some(instance_id), on_board);
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn summon_expects_card_moves_to_board_mana_spent_and_upon_summon_runs() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let summoned = Arc::new(AtomicBool::new(false));
+        let definition = CreatureDefinitionBuilder::new()
+            .cost(3)
+            .attack(2)
+            .health(4)
+            .upon_summon(RecordSummonAction(Arc::clone(&summoned)))
+            .build();
+        let definition_id = definition.definition_id();
+        game_state.add_creature_definition(definition);
 
-        // Set the card instance on the board
+        let other_card = CardInHand::new(definition_id);
+        let other_card_id = other_card.card_id();
+        let card_id = CreatureInstanceId::new();
         {
-            dispatcher
-                .dispatch(
-                    CreatureSetEvent::new(player_id, card_instance_id, pos),
-                    game_state,
-                )
-                .await;
+            let mut hand = game_state.hand_mut(player_a);
+            hand.add_card(CardInHand::new_with_id(card_id, definition_id));
+            hand.add_card(other_card);
+        }
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(5));
+
+        let pos = BoardPos::new(player_a, RowId::FrontRow, 1);
+        smol::block_on(SummonCreatureFromHandEventHandler.handle(
+            &CreatureSummonedFromHandEvent::new(player_a, pos, card_id),
+            &mut game_state,
+            &mut dispatcher,
+        ));
+
+        assert!(summoned.load(Ordering::SeqCst));
+
+        let (instance_id, attack, health) = game_state
+            .board()
+            .creature_at_pos(pos)
+            .expect("The creature should be on the board.")
+            .get(|c| (c.instance_id(), c.attack(), c.health()));
+        assert_eq!((card_id, 2, 4), (instance_id, attack, health));
+
+        assert_eq!(
+            2,
+            game_state.board().player_hero(player_a).get(|h| h.mana())
+        );
+
+        // The remaining card shifts into the first hand slot.
+        let hand = game_state.hand(player_a);
+        assert_eq!(1, hand.len());
+        assert!(hand.card(card_id).is_none());
+        assert!(hand.card(other_card_id).is_some());
+    }
+
+    #[test]
+    fn summon_when_hidden_expects_opponent_sees_only_cost_bucket() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let (mut dispatcher, owner, opponent) = make_recording_dispatcher(&game_state);
+
+        let definition = CreatureDefinitionBuilder::new()
+            .title("Lurker".to_string())
+            .cost(5)
+            .keyword(Keyword::Hidden)
+            .build();
+        let definition_id = definition.definition_id();
+        game_state.add_creature_definition(definition);
+        let card_id = CreatureInstanceId::new();
+        game_state
+            .hand_mut(player_a)
+            .add_card(CardInHand::new_with_id(card_id, definition_id));
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(5));
+
+        let pos = BoardPos::new(player_a, RowId::FrontRow, 0);
+        smol::block_on(dispatcher.dispatch(
+            CreatureSummonedFromHandEvent::new(player_a, pos, card_id),
+            &mut game_state,
+        ))
+        .unwrap();
+
+        // Each player is sent the summon, then the new entity.
+        let seen_by = |notifier: &RecordingNotifier| {
+            let notifications = notifier.0.lock().unwrap();
+            let Some(Notification::VisualEvent(VisualEvent::CreatureSummonedFromHand(summoned))) =
+                notifications.first()
+            else {
+                panic!("Expected the summon first, found {notifications:?}");
+            };
+            let added = notifications
+                .iter()
+                .find_map(|n| match n {
+                    Notification::EntityAdded(added) => Some(added.clone()),
+                    _ => None,
+                })
+                .expect("Expected the summoned creature to be added.");
+            assert_eq!(EntityPosition::BoardPos(pos), added.position);
+
+            (
+                summoned.creature.clone(),
+                added.entity.unpack::<CreatureInstanceView>(),
+            )
+        };
+
+        for creature in <[_; 2]>::from(seen_by(&opponent)) {
+            assert!(creature.definition().is_hidden());
+            assert_eq!(6, creature.cost());
+        }
+        for creature in <[_; 2]>::from(seen_by(&owner)) {
+            let definition = creature.definition().revealed().unwrap();
+            assert_eq!(("Lurker", 5), (definition.title.as_str(), creature.cost()));
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::{
         events::{DrawCardEvent, PlayerGainManaEvent, TurnStartEvent},
     },
     game_state::game_state::GameState,
+    v2::CreatureInstance,
 };
 use async_trait::async_trait;
 use log::info;
@@ -18,38 +19,138 @@ impl EventHandler for TurnStartHandler {
 
     async fn handle(
         &self,
-        _event: &TurnStartEvent,
+        event: &TurnStartEvent,
         game_state: &mut GameState,
         dispatcher: &mut EventDispatcher,
     ) {
-        let player_id = game_state.cur_player_turn();
+        let player_id = event.0;
         info!("Turn started for player {:?}", player_id);
 
+        // The player's creatures can attack again, including any summoned last turn.
+        let creature_ids = game_state.board().player_creature_ids(player_id);
+        for creature_id in creature_ids {
+            let mut board = game_state.board_mut();
+            if let Some(mut creature) = board.creature_by_id_mut(creature_id) {
+                creature.get_mut(CreatureInstance::ready_for_turn);
+            }
+        }
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_id)
+            .get_mut(|h| h.ready_hero_power());
+
+        let mana_gain = game_state.rules().mana_limit_gain_per_turn;
         dispatcher
-            .dispatch(PlayerGainManaEvent::new(player_id, 1), game_state)
+            .dispatch_triggered(PlayerGainManaEvent::new(player_id, mana_gain), game_state)
             .await;
 
-        // game_state.refresh_player_mana(player_id);
-        let _player_hero = game_state.board_mut().player_hero_mut(player_id);
-        // update the mana here...
-
         dispatcher
-            .dispatch(DrawCardEvent::new(player_id), game_state)
+            .dispatch_triggered(DrawCardEvent::new(player_id), game_state)
             .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TurnStartHandler;
+    use crate::{
+        game_logic::{
+            event_dispatch::tests::make_test_dispatcher, event_handlers::EventHandler,
+            events::TurnStartEvent,
+        },
+        game_state::{
+            game_state::{tests::place_creature, GameState},
+            GameRules,
+        },
+        v2::{builder::CreatureDefinitionBuilder, CreatureInstance},
+    };
+    use protocol::entities::{BoardPos, PlayerId, RowId};
+
+    #[test]
+    fn turn_start_expects_mana_limit_grows_to_cap_and_mana_refills() {
+        let rules = GameRules {
+            max_mana_limit: 3,
+            ..GameRules::default()
+        };
+        let mut game_state = GameState::new_with_rules(PlayerId::new(), PlayerId::new(), rules);
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let mana = |game_state: &GameState| {
+            game_state
+                .board()
+                .player_hero(player_a)
+                .get(|h| (h.mana_limit(), h.mana()))
+        };
+
+        for expected_limit in [1, 2, 3, 3] {
+            smol::block_on(TurnStartHandler.handle(
+                &TurnStartEvent(player_a),
+                &mut game_state,
+                &mut dispatcher,
+            ));
+
+            assert_eq!((expected_limit, expected_limit), mana(&game_state));
+
+            // Spend some mana, so the next turn start has something to refill.
+            game_state
+                .board_mut()
+                .player_hero_mut(player_a)
+                .get_mut(|h| h.spend_mana(1));
+        }
+    }
+
+    #[test]
+    fn turn_start_expects_only_active_players_creatures_can_attack_again() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let creature_a = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(1),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let creature_b = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(1),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+
+        let can_attack = |game_state: &GameState, id| {
+            game_state
+                .board()
+                .creature_by_id(id)
+                .unwrap()
+                .get(CreatureInstance::can_attack)
+        };
+
+        assert!(!can_attack(&game_state, creature_a));
+
+        smol::block_on(TurnStartHandler.handle(
+            &TurnStartEvent(player_a),
+            &mut game_state,
+            &mut dispatcher,
+        ));
+
+        assert!(can_attack(&game_state, creature_a));
+        assert!(!can_attack(&game_state, creature_b));
+
+        game_state
+            .board_mut()
+            .creature_by_id_mut(creature_a)
+            .unwrap()
+            .get_mut(CreatureInstance::set_has_attacked);
+        assert!(!can_attack(&game_state, creature_a));
+
+        smol::block_on(TurnStartHandler.handle(
+            &TurnStartEvent(player_a),
+            &mut game_state,
+            &mut dispatcher,
+        ));
 
-        // let turn_start_actions = game_state
-        //     .board()
-        //     .player_side(player_id)
-        //     .iter()
-        //     .filter_map(|s| {
-        //         s.maybe_creature()
-        //             .map(|c| (c.id(), c.definition().upon_turn_start()))
-        //     })
-        //     .collect::<Vec<_>>()
-        //     .into_iter();
-
-        // for (id, action) in turn_start_actions {
-        //     action.action(id, game_state, dispatcher).await;
-        // }
+        assert!(can_attack(&game_state, creature_a));
     }
 }
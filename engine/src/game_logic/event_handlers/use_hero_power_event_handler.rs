@@ -0,0 +1,149 @@
+use log::info;
+
+use crate::{
+    game_logic::{
+        event_dispatch::EventDispatcher,
+        event_handlers::EventHandler,
+        events::{HeroPowerUsedEvent, PlayerSpendManaEvent},
+    },
+    game_state::game_state::GameState,
+};
+use async_trait::async_trait;
+
+#[derive(Default)]
+pub struct UseHeroPowerEventHandler;
+
+#[async_trait]
+impl EventHandler for UseHeroPowerEventHandler {
+    type Event = HeroPowerUsedEvent;
+
+    async fn handle(
+        &self,
+        event: &HeroPowerUsedEvent,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        let player_id = event.player_id();
+
+        let (mana_amount, effect) = {
+            let hero_power = game_state
+                .hero_power_of(player_id)
+                .expect("The hero power's definition must be known.");
+
+            info!("Player {:?} uses {}", player_id, hero_power.title());
+
+            (hero_power.cost(), hero_power.effect())
+        };
+
+        // Only once per turn
+        game_state
+            .board_mut()
+            .player_hero_mut(player_id)
+            .get_mut(|h| h.set_used_hero_power());
+
+        // Spend the mana
+        dispatcher
+            .dispatch_triggered(
+                PlayerSpendManaEvent::new(player_id, mana_amount),
+                game_state,
+            )
+            .await;
+
+        effect.action(player_id, game_state, dispatcher).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_logic::{
+            event_dispatch::{tests::make_test_dispatcher, EventDispatcher},
+            events::{HeroPowerUsedEvent, TurnStartEvent},
+        },
+        game_state::game_state::GameState,
+        v2::{actions::UponHeroPowerAction, HeroPowerDefinition, HeroPowerId},
+    };
+    use async_trait::async_trait;
+    use protocol::{entities::PlayerId, rule_violation::RuleViolation};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    struct CountUsesAction(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl UponHeroPowerAction for CountUsesAction {
+        async fn action(
+            &self,
+            _player_id: PlayerId,
+            _game_state: &mut GameState,
+            _dispatcher: &mut EventDispatcher,
+        ) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn use_hero_power_expects_mana_spent_and_only_once_per_turn() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let uses = Arc::new(AtomicUsize::new(0));
+        game_state.set_hero_power(
+            player_a,
+            HeroPowerDefinition::new(
+                HeroPowerId::new(),
+                "Tally",
+                "",
+                2,
+                CountUsesAction(Arc::clone(&uses)),
+            ),
+        );
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(5));
+
+        smol::block_on(dispatcher.dispatch(HeroPowerUsedEvent::new(player_a), &mut game_state))
+            .unwrap();
+
+        assert_eq!(1, uses.load(Ordering::SeqCst));
+        assert_eq!(
+            3,
+            game_state.board().player_hero(player_a).get(|h| h.mana())
+        );
+        assert_eq!(
+            Err(RuleViolation::HeroPowerAlreadyUsed {
+                player_id: player_a
+            }),
+            smol::block_on(dispatcher.dispatch(HeroPowerUsedEvent::new(player_a), &mut game_state))
+        );
+
+        // The next turn start makes it usable again.
+        smol::block_on(dispatcher.dispatch(TurnStartEvent(player_a), &mut game_state)).unwrap();
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(5));
+        smol::block_on(dispatcher.dispatch(HeroPowerUsedEvent::new(player_a), &mut game_state))
+            .unwrap();
+
+        assert_eq!(2, uses.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn use_hero_power_when_hero_has_none_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        assert_eq!(
+            Err(RuleViolation::NoHeroPower {
+                player_id: player_a
+            }),
+            smol::block_on(dispatcher.dispatch(HeroPowerUsedEvent::new(player_a), &mut game_state))
+        );
+    }
+}
@@ -1,44 +1,57 @@
 // mod add_buff_to_card_instance_event;
 mod add_card_to_hand_event;
 mod attack;
+mod cast_spell_from_hand_event;
 mod creature_deals_damage_event;
 mod creature_destroyed;
 mod creature_healed_event;
+mod creature_moved_event;
+mod creature_revealed_event;
 mod creature_set_event;
 mod creature_takes_damage_event;
 mod draw_card;
 mod end_turn;
+mod hero_takes_damage_event;
 mod player_gain_mana;
 mod player_spend_mana;
 mod pos_takes_damage_event;
 mod start_game_event;
 mod summon_creature_from_hand_event;
 mod turn_start_event;
+mod use_hero_power_event;
 
 // pub use add_buff_to_card_instance_event::AddBuffToCardInstanceEvent;
 pub use add_card_to_hand_event::AddCardToHandEvent;
 pub use attack::AttackEvent;
+pub use cast_spell_from_hand_event::SpellCastFromHandEvent;
 pub use creature_deals_damage_event::CreatureDealsDamageEvent;
 pub use creature_destroyed::CreatureDestroyedEvent;
 pub use creature_healed_event::CreatureHealedEvent;
+pub use creature_moved_event::{CreatureMovedEvent, MoveReason};
+pub use creature_revealed_event::CreatureRevealedEvent;
 pub use creature_set_event::*;
 pub use creature_takes_damage_event::CreatureTakesDamageEvent;
 pub use draw_card::DrawCardEvent;
 pub use end_turn::EndTurnEvent;
+pub use hero_takes_damage_event::HeroTakesDamageEvent;
 pub use player_gain_mana::PlayerGainManaEvent;
 pub use player_spend_mana::PlayerSpendManaEvent;
 pub use pos_takes_damage_event::PosTakesDamageEvent;
-use protocol::{entities::PlayerId, from_client::ClientAction, from_server::VisualEvent};
+use protocol::{
+    entities::PlayerId, from_client::ClientAction, from_server::VisualEvent,
+    rule_violation::RuleViolation,
+};
 pub use start_game_event::StartGameEvent;
 pub use summon_creature_from_hand_event::CreatureSummonedFromHandEvent;
 pub use turn_start_event::TurnStartEvent;
+pub use use_hero_power_event::HeroPowerUsedEvent;
 
 use enum_dispatch::enum_dispatch;
 use std::fmt::Debug;
 
 use crate::game_state::game_state::GameState;
 
-pub type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+pub type Result = std::result::Result<(), RuleViolation>;
 
 /// A marker trait defining an event in the game.
 #[enum_dispatch(GameEvent)]
@@ -78,6 +91,11 @@ pub enum GameEvent {
     CreatureSummonedFromHandEvent,
     PosTakesDamageEvent,
     CreatureHealedEvent,
+    HeroTakesDamageEvent,
+    CreatureRevealedEvent,
+    SpellCastFromHandEvent,
+    HeroPowerUsedEvent,
+    CreatureMovedEvent,
 }
 
 impl Debug for GameEvent {
@@ -99,6 +117,11 @@ impl Debug for GameEvent {
             Self::CreatureSummonedFromHandEvent(e) => e.fmt(f),
             Self::PosTakesDamageEvent(e) => e.fmt(f),
             Self::CreatureHealedEvent(e) => e.fmt(f),
+            Self::HeroTakesDamageEvent(e) => e.fmt(f),
+            Self::CreatureRevealedEvent(e) => e.fmt(f),
+            Self::SpellCastFromHandEvent(e) => e.fmt(f),
+            Self::HeroPowerUsedEvent(e) => e.fmt(f),
+            Self::CreatureMovedEvent(e) => e.fmt(f),
         }
     }
 }
@@ -106,8 +129,7 @@ impl Debug for GameEvent {
 impl GameEvent {
     #[must_use]
     pub fn is_end_turn(&self) -> bool {
-        false
-        // matches!(self, GameEvent::EndTurnEvent(_))
+        matches!(self, GameEvent::EndTurnEvent(_))
     }
 }
 
@@ -121,7 +143,13 @@ impl From<ClientAction> for GameEvent {
                 CreatureSummonedFromHandEvent::new(e.player_id, e.board_pos, e.card_id).into()
             }
             ClientAction::Attack(e) => AttackEvent::new(e.attacker, e.target).into(),
-            // ClientAction::DrawCard(e) => e.into(),
+            ClientAction::CastSpellFromHand(e) => {
+                SpellCastFromHandEvent::new(e.player_id, e.card_id).into()
+            }
+            ClientAction::UseHeroPower(e) => HeroPowerUsedEvent::new(e.player_id).into(),
+            ClientAction::MoveCreature(e) => {
+                CreatureMovedEvent::new(e.creature_id, e.to, MoveReason::TurnAction).into()
+            } // ClientAction::DrawCard(e) => e.into(),
         }
     }
 }
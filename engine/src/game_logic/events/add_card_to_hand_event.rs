@@ -1,19 +1,29 @@
-use crate::game_state::game_state::GameState;
-
 use super::{Event, VisualEvent};
-use protocol::entities::{CreatureInstanceId, PlayerId};
-use serde::{Deserialize, Serialize};
+use crate::{game_state::game_state::GameState, v2::CreatureDefinitionId};
+use protocol::{
+    entities::{CreatureInstance, CreatureInstanceId, PlayerId},
+    visual_events::CardAddedToHand,
+};
 
 #[derive(Debug)]
 pub struct AddCardToHandEvent {
     player_id: PlayerId,
     card_id: CreatureInstanceId,
+    definition_id: CreatureDefinitionId,
 }
 
 impl AddCardToHandEvent {
     #[must_use]
-    pub fn new(player_id: PlayerId, card_id: CreatureInstanceId) -> Self {
-        Self { player_id, card_id }
+    pub fn new(
+        player_id: PlayerId,
+        card_id: CreatureInstanceId,
+        definition_id: CreatureDefinitionId,
+    ) -> Self {
+        Self {
+            player_id,
+            card_id,
+            definition_id,
+        }
     }
 
     #[must_use]
@@ -25,40 +35,34 @@ impl AddCardToHandEvent {
     pub fn card_id(&self) -> CreatureInstanceId {
         self.card_id
     }
-}
 
-impl Event for AddCardToHandEvent {}
-
-// impl Event for AddCardToHandEvent {
-//     fn maybe_client_event(
-//         &self,
-//         player_id: PlayerId,
-//         game_state: &GameState,
-//     ) -> Option<VisualEvent> {
-//         let card = if self.player_id == player_id {
-//             let card = game_state
-//                 .board()
-//                 .creature_instance_all(self.card_id())
-//                 .player_view(self.player_id);
+    #[must_use]
+    pub fn definition_id(&self) -> CreatureDefinitionId {
+        self.definition_id
+    }
+}
 
-//             Some(card)
-//         } else {
-//             None
-//         };
+impl Event for AddCardToHandEvent {
+    fn maybe_client_event(
+        &self,
+        player_id: PlayerId,
+        game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        // Only the owner of the hand gets to see which card was added.
+        let card = if self.player_id == player_id {
+            game_state
+                .creature_definition(self.definition_id)
+                .map(|d| CreatureInstance::new(self.card_id, d.view(), Vec::new(), None))
+        } else {
+            None
+        };
 
-//         let event = AddCardToHandClientEvent {
-//             player_id: self.player_id,
-//             card_id: self.card_id,
-//             card,
-//         };
-//         // Some(ClientEventView::AddCardToHand(event))
-//         None
-//     }
-// }
+        let event = CardAddedToHand {
+            player_id: self.player_id,
+            card_id: self.card_id,
+            card,
+        };
 
-// #[derive(Serialize, Deserialize, Clone, Debug)]
-// pub struct AddCardToHandClientEvent {
-//     pub player_id: PlayerId,
-//     pub card_id: CreatureInstanceId,
-//     pub card: Option<UnitCardInstancePlayerView>,
-// }
+        Some(VisualEvent::CardAddedToHand(event))
+    }
+}
@@ -1,7 +1,12 @@
-use protocol::entities::CreatureInstanceId;
+use protocol::{
+    entities::{CreatureInstanceId, PlayerId},
+    rule_violation::RuleViolation,
+    visual_events::CreatureAttacksTarget,
+};
 use serde::{Deserialize, Serialize};
 
-use super::Event;
+use super::{Event, VisualEvent};
+use crate::game_state::game_state::GameState;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AttackEvent {
@@ -26,4 +31,231 @@ impl AttackEvent {
     }
 }
 
-impl Event for AttackEvent {}
+impl Event for AttackEvent {
+    fn validate(&self, game_state: &GameState) -> super::Result {
+        let board = game_state.board();
+
+        let attacker_pos = board
+            .creature_pos(self.attacker)
+            .ok_or(RuleViolation::NotOnBoard {
+                creature_id: self.attacker,
+            })?;
+
+        if attacker_pos.player_id != game_state.cur_player_turn() {
+            return Err(RuleViolation::NotYourTurn {
+                player_id: attacker_pos.player_id,
+            });
+        }
+
+        let (summoning_sick, has_attacked) = board
+            .creature_by_id(self.attacker)
+            .expect("The attacker is on the board.")
+            .get(|c| (c.is_summoning_sick(), c.has_attacked()));
+
+        if summoning_sick {
+            return Err(RuleViolation::SummoningSick {
+                creature_id: self.attacker,
+            });
+        }
+
+        if has_attacked {
+            return Err(RuleViolation::AlreadyAttacked {
+                creature_id: self.attacker,
+            });
+        }
+
+        game_state.validate_attack_target(self.attacker, self.target)
+    }
+
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        _game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        Some(VisualEvent::CreatureAttacksTarget(CreatureAttacksTarget {
+            attacker: self.attacker.id(),
+            target: self.target.id(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AttackEvent;
+    use crate::{
+        game_logic::events::Event,
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::{builder::CreatureDefinitionBuilder, CreatureInstance},
+    };
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, Keyword, PlayerId, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    #[test]
+    fn validate_when_target_behind_defender_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+
+        let attacker = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new()
+                .attack(1)
+                .health(1)
+                .keyword(Keyword::Eager),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let defender = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().keyword(Keyword::Defender),
+            BoardPos::new(player_b, RowId::FrontRow, 2),
+        );
+        let behind = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_b, RowId::BackRow, 2),
+        );
+        let beside = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_b, RowId::BackRow, 3),
+        );
+
+        assert_eq!(
+            Err(RuleViolation::TargetDefended {
+                pos: BoardPos::new(player_b, RowId::BackRow, 2)
+            }),
+            AttackEvent::new(attacker, behind).validate(&game_state)
+        );
+        assert!(AttackEvent::new(attacker, defender)
+            .validate(&game_state)
+            .is_ok());
+        assert!(AttackEvent::new(attacker, beside)
+            .validate(&game_state)
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_when_wide_target_partly_behind_defender_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+
+        let attacker = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().keyword(Keyword::Eager),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().keyword(Keyword::Defender),
+            BoardPos::new(player_b, RowId::FrontRow, 1),
+        );
+        let wide = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().width(2),
+            BoardPos::new(player_b, RowId::BackRow, 0),
+        );
+
+        assert_eq!(
+            Err(RuleViolation::TargetDefended {
+                pos: BoardPos::new(player_b, RowId::BackRow, 1)
+            }),
+            AttackEvent::new(attacker, wide).validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_target_on_own_side_or_missing_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let attacker = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().keyword(Keyword::Eager),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let ally = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_a, RowId::FrontRow, 1),
+        );
+        let own_hero = game_state
+            .board()
+            .player_hero(player_a)
+            .get(|h| h.instance_id());
+
+        assert_eq!(
+            Err(RuleViolation::CannotAttackOwnSide),
+            AttackEvent::new(attacker, ally).validate(&game_state)
+        );
+        assert_eq!(
+            Err(RuleViolation::CannotAttackOwnSide),
+            AttackEvent::new(attacker, own_hero).validate(&game_state)
+        );
+        let missing = CreatureInstanceId::new();
+        assert_eq!(
+            Err(RuleViolation::NotOnBoard {
+                creature_id: missing
+            }),
+            AttackEvent::new(attacker, missing).validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_not_attackers_turn_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_b = game_state.player_b_id();
+
+        let attacker = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().keyword(Keyword::Eager),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        let hero_a = game_state
+            .board()
+            .player_hero(game_state.player_a_id())
+            .get(|h| h.instance_id());
+
+        assert_eq!(
+            Err(RuleViolation::NotYourTurn {
+                player_id: player_b
+            }),
+            AttackEvent::new(attacker, hero_a).validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_attacker_summoning_sick_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let hero_b = game_state
+            .board()
+            .player_hero(game_state.player_b_id())
+            .get(|h| h.instance_id());
+
+        let attacker = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+
+        assert_eq!(
+            Err(RuleViolation::SummoningSick {
+                creature_id: attacker
+            }),
+            AttackEvent::new(attacker, hero_b).validate(&game_state)
+        );
+
+        game_state
+            .board_mut()
+            .creature_by_id_mut(attacker)
+            .unwrap()
+            .get_mut(CreatureInstance::ready_for_turn);
+
+        assert!(AttackEvent::new(attacker, hero_b)
+            .validate(&game_state)
+            .is_ok());
+    }
+}
@@ -0,0 +1,272 @@
+use crate::game_state::game_state::GameState;
+
+use super::{Event, VisualEvent};
+use protocol::{
+    entities::{CreatureInstanceId, PlayerId},
+    visual_events::SpellCastFromHand,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SpellCastFromHandEvent {
+    player_id: PlayerId,
+    hand_card_id: CreatureInstanceId,
+}
+
+impl SpellCastFromHandEvent {
+    #[must_use]
+    pub fn new(player_id: PlayerId, hand_card_id: CreatureInstanceId) -> Self {
+        Self {
+            player_id,
+            hand_card_id,
+        }
+    }
+
+    #[must_use]
+    pub fn player_id(&self) -> PlayerId {
+        self.player_id
+    }
+
+    #[must_use]
+    pub fn hand_card_id(&self) -> CreatureInstanceId {
+        self.hand_card_id
+    }
+}
+
+impl Event for SpellCastFromHandEvent {
+    fn validate(&self, game_state: &GameState) -> super::Result {
+        validation::validate_is_players_turn(self, game_state)?;
+        validation::validate_player_has_enough_mana(self, game_state)?;
+        validation::validate_spell_has_legal_target(self, game_state)?;
+
+        Ok(())
+    }
+
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        // Cast spells are shown to both players.
+        let definition = validation::cast_definition(self, game_state).ok()?;
+
+        Some(VisualEvent::SpellCastFromHand(SpellCastFromHand {
+            player_id: self.player_id,
+            card_id: self.hand_card_id,
+            spell: definition.view(),
+        }))
+    }
+}
+
+mod validation {
+    use log::debug;
+    use protocol::rule_violation::RuleViolation;
+
+    use crate::{game_state::game_state::GameState, v2::CreatureDefinition};
+
+    use super::SpellCastFromHandEvent;
+
+    /// The definition of the card being cast, which must be a spell in the player's hand.
+    pub fn cast_definition<'a>(
+        event: &SpellCastFromHandEvent,
+        game_state: &'a GameState,
+    ) -> Result<&'a CreatureDefinition, RuleViolation> {
+        let definition_id = game_state
+            .hand(event.player_id())
+            .card(event.hand_card_id())
+            .map(|c| c.get(|c| c.definition_id()))
+            .ok_or(RuleViolation::CardNotInHand {
+                player_id: event.player_id(),
+                card_id: event.hand_card_id(),
+            })?;
+
+        let definition =
+            game_state
+                .creature_definition(definition_id)
+                .ok_or(RuleViolation::UnknownCard {
+                    card_id: event.hand_card_id(),
+                })?;
+
+        if definition.is_spell() {
+            Ok(definition)
+        } else {
+            Err(RuleViolation::NotASpell {
+                card_id: event.hand_card_id(),
+            })
+        }
+    }
+
+    pub fn validate_is_players_turn(
+        event: &SpellCastFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the spell is cast on the player's own turn.");
+
+        if game_state.cur_player_turn() == event.player_id() {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotYourTurn {
+                player_id: event.player_id(),
+            })
+        }
+    }
+
+    pub fn validate_player_has_enough_mana(
+        event: &SpellCastFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the player has enough mana for the spell.");
+        let mana_cost = cast_definition(event, game_state)?.cost();
+        let mana_cost = u32::try_from(mana_cost).unwrap_or(0);
+
+        let player_mana = game_state
+            .board()
+            .player_hero(event.player_id())
+            .get(|h| h.mana());
+
+        if player_mana >= mana_cost {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotEnoughMana {
+                cost: mana_cost,
+                available: player_mana,
+            })
+        }
+    }
+
+    pub fn validate_spell_has_legal_target(
+        event: &SpellCastFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the spell has a legal target.");
+
+        if cast_definition(event, game_state)?
+            .spell_target()
+            .has_legal_target(event.player_id(), game_state)
+        {
+            Ok(())
+        } else {
+            Err(RuleViolation::NoLegalTarget {
+                card_id: event.hand_card_id(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpellCastFromHandEvent;
+    use crate::{
+        game_logic::events::Event,
+        game_state::{
+            game_state::{tests::place_creature, GameState},
+            CardInHand,
+        },
+        v2::{actions::DoNothingAction, builder::CreatureDefinitionBuilder, SpellTarget},
+    };
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, PlayerId, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    /// Adds a card built from `builder` to the player's hand, and gives them `mana`.
+    fn add_card_to_hand(
+        game_state: &mut GameState,
+        player_id: PlayerId,
+        builder: &CreatureDefinitionBuilder,
+        mana: u32,
+    ) -> CreatureInstanceId {
+        let definition = builder.build();
+        let definition_id = definition.definition_id();
+        game_state.add_creature_definition(definition);
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_id)
+            .get_mut(|h| h.set_mana(mana));
+
+        let card_id = CreatureInstanceId::new();
+        game_state
+            .hand_mut(player_id)
+            .add_card(CardInHand::new_with_id(card_id, definition_id));
+
+        card_id
+    }
+
+    #[test]
+    fn validate_when_card_is_creature_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            &CreatureDefinitionBuilder::new(),
+            10,
+        );
+
+        assert_eq!(
+            Err(RuleViolation::NotASpell { card_id }),
+            SpellCastFromHandEvent::new(player_a, card_id).validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_spell_costs_more_than_mana_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new()
+                .cost(2)
+                .upon_cast(DoNothingAction),
+            1,
+        );
+        let event = SpellCastFromHandEvent::new(player_a, card_id);
+
+        assert_eq!(
+            Err(RuleViolation::NotEnoughMana {
+                cost: 2,
+                available: 1
+            }),
+            event.validate(&game_state)
+        );
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(2));
+        assert!(event.validate(&game_state).is_ok());
+    }
+
+    #[test]
+    fn validate_when_no_enemy_creature_to_target_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new()
+                .upon_cast(DoNothingAction)
+                .spell_target(SpellTarget::EnemyCreature),
+            10,
+        );
+        let event = SpellCastFromHandEvent::new(player_a, card_id);
+
+        assert_eq!(
+            Err(RuleViolation::NoLegalTarget { card_id }),
+            event.validate(&game_state)
+        );
+
+        let enemy_pos = BoardPos::new(game_state.player_b_id(), RowId::BackRow, 0);
+        place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(1),
+            enemy_pos,
+        );
+        assert!(event.validate(&game_state).is_ok());
+    }
+}
@@ -1,5 +1,9 @@
-use super::Event;
-use protocol::entities::CreatureInstanceId;
+use super::{Event, VisualEvent};
+use crate::game_state::game_state::GameState;
+use protocol::{
+    entities::{CreatureInstanceId, PlayerId},
+    visual_events::CreatureDestroyed,
+};
 
 #[derive(Debug, Clone)]
 pub struct CreatureDestroyedEvent {
@@ -18,4 +22,14 @@ impl CreatureDestroyedEvent {
     }
 }
 
-impl Event for CreatureDestroyedEvent {}
+impl Event for CreatureDestroyedEvent {
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        _game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        Some(VisualEvent::CreatureDestroyed(CreatureDestroyed {
+            creature_id: self.creature_id,
+        }))
+    }
+}
@@ -0,0 +1,402 @@
+use crate::game_state::game_state::GameState;
+
+use super::{Event, VisualEvent};
+use protocol::{
+    entities::{BoardPos, CreatureInstanceId, PlayerId},
+    visual_events::CreatureMoved,
+};
+use serde::{Deserialize, Serialize};
+
+/// Why a creature is being moved, which decides the rules the move has to follow.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MoveReason {
+    /// Its owner moves the Versatile creature as a turn action.
+    TurnAction,
+    /// Another card's effect moves it, to a row it could have been placed in.
+    Effect,
+    /// Its own card text moves it, which may take it out of its usual row.
+    OwnEffect,
+}
+
+/// Moves a creature already on the board so its leftmost slot is at `to`,
+/// keeping its stats, buffs and state. It stays on its owner's side.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreatureMovedEvent {
+    creature_id: CreatureInstanceId,
+    to: BoardPos,
+    reason: MoveReason,
+}
+
+impl CreatureMovedEvent {
+    #[must_use]
+    pub fn new(creature_id: CreatureInstanceId, to: BoardPos, reason: MoveReason) -> Self {
+        Self {
+            creature_id,
+            to,
+            reason,
+        }
+    }
+
+    #[must_use]
+    pub fn creature_id(&self) -> CreatureInstanceId {
+        self.creature_id
+    }
+
+    #[must_use]
+    pub fn to(&self) -> BoardPos {
+        self.to
+    }
+
+    #[must_use]
+    pub fn reason(&self) -> MoveReason {
+        self.reason
+    }
+}
+
+impl Event for CreatureMovedEvent {
+    fn validate(&self, game_state: &GameState) -> super::Result {
+        validation::validate_is_on_board(self, game_state)?;
+        validation::validate_is_owners_side(self, game_state)?;
+        validation::validate_slots_available(self, game_state)?;
+        validation::validate_respects_placeableat(self, game_state)?;
+
+        if self.reason == MoveReason::TurnAction {
+            validation::validate_is_owners_turn(self, game_state)?;
+            validation::validate_is_versatile(self, game_state)?;
+        }
+
+        Ok(())
+    }
+
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        // Moves are shown to both players; the creature itself is unchanged, so hidden ones stay hidden.
+        let from = game_state.board().creature_pos(self.creature_id)?;
+
+        Some(VisualEvent::CreatureMoved(CreatureMoved {
+            creature_id: self.creature_id,
+            from,
+            to: self.to,
+        }))
+    }
+}
+
+mod validation {
+    use log::debug;
+    use protocol::{
+        entities::{BoardPos, Keyword, Position, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    use crate::{
+        game_state::game_state::GameState,
+        v2::{CreatureDefinition, CreatureInstance},
+    };
+
+    use super::{CreatureMovedEvent, MoveReason};
+
+    /// The current leftmost slot of the creature being moved.
+    fn current_pos(
+        event: &CreatureMovedEvent,
+        game_state: &GameState,
+    ) -> Result<BoardPos, RuleViolation> {
+        game_state
+            .board()
+            .creature_pos(event.creature_id())
+            .ok_or(RuleViolation::NotOnBoard {
+                creature_id: event.creature_id(),
+            })
+    }
+
+    fn moved_definition<'a>(
+        event: &CreatureMovedEvent,
+        game_state: &'a GameState,
+    ) -> Result<&'a CreatureDefinition, RuleViolation> {
+        game_state
+            .creature_definition_of(event.creature_id())
+            .ok_or(RuleViolation::NotOnBoard {
+                creature_id: event.creature_id(),
+            })
+    }
+
+    /// Whether the creature is Versatile right now, including keywords gained from buffs.
+    fn is_versatile(event: &CreatureMovedEvent, game_state: &GameState) -> bool {
+        game_state
+            .board()
+            .creature_by_id(event.creature_id())
+            .is_some_and(|c| c.get(|c| c.has_keyword(Keyword::Versatile)))
+    }
+
+    pub fn validate_is_on_board(
+        event: &CreatureMovedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the moved creature is on the board.");
+        current_pos(event, game_state).map(|_| ())
+    }
+
+    pub fn validate_is_owners_side(
+        event: &CreatureMovedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the creature stays on its owner's side.");
+        let player_id = current_pos(event, game_state)?.player_id;
+
+        if event.to().player_id == player_id {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotYourSide {
+                player_id,
+                pos: event.to(),
+            })
+        }
+    }
+
+    pub fn validate_slots_available(
+        event: &CreatureMovedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the slots for the move are not occupied by another creature.");
+        let board = game_state.board();
+        let creature_width = board.slots_with_creature(event.creature_id()).len();
+        let requested_pos = event.to();
+
+        if !board.is_range_in_row(requested_pos, creature_width) {
+            return Err(RuleViolation::DoesNotFit {
+                pos: requested_pos,
+                width: creature_width,
+            });
+        }
+
+        for i in 0..creature_width {
+            let mut look_pos = requested_pos;
+            look_pos.row_index += i;
+
+            // A creature may move over the slots it is leaving.
+            let occupant = board
+                .creature_at_pos(look_pos)
+                .map(|c| c.get(CreatureInstance::instance_id));
+
+            if occupant.is_some_and(|id| id != event.creature_id()) {
+                return Err(RuleViolation::SlotOccupied { pos: look_pos });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_respects_placeableat(
+        event: &CreatureMovedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the slots are in the card's placable positions.");
+
+        // A card's own text decides where it moves itself.
+        if event.reason() == MoveReason::OwnEffect {
+            return Ok(());
+        }
+
+        let placeable_at = moved_definition(event, game_state)?.placeable_at();
+        let attempted_row = event.to().row_id;
+
+        // Versatile creatures can go in either row.
+        if is_versatile(event, game_state) {
+            return Ok(());
+        }
+
+        if (placeable_at == Position::Back && attempted_row == RowId::FrontRow)
+            || (placeable_at == Position::Front && attempted_row == RowId::BackRow)
+        {
+            Err(RuleViolation::NotPlaceableAt {
+                pos: event.to(),
+                placeable_at,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn validate_is_owners_turn(
+        event: &CreatureMovedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the move is made on the owner's own turn.");
+        let player_id = current_pos(event, game_state)?.player_id;
+
+        if game_state.cur_player_turn() == player_id {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotYourTurn { player_id })
+        }
+    }
+
+    pub fn validate_is_versatile(
+        event: &CreatureMovedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the creature is Versatile, so its owner can move it.");
+        if is_versatile(event, game_state) {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotVersatile {
+                creature_id: event.creature_id(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CreatureMovedEvent, MoveReason};
+    use crate::{
+        game_logic::events::Event,
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::builder::CreatureDefinitionBuilder,
+    };
+    use protocol::{
+        entities::{BoardPos, Keyword, PlayerId, Position, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    #[test]
+    fn validate_when_wide_creature_slides_over_its_own_slots_expects_ok() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let creature = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().width(2),
+            BoardPos::new(player_a, RowId::FrontRow, 1),
+        );
+        place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_a, RowId::FrontRow, 4),
+        );
+
+        let move_to = |index| {
+            CreatureMovedEvent::new(
+                creature,
+                BoardPos::new(player_a, RowId::FrontRow, index),
+                MoveReason::Effect,
+            )
+        };
+
+        assert!(move_to(2).validate(&game_state).is_ok());
+        assert_eq!(
+            Err(RuleViolation::SlotOccupied {
+                pos: BoardPos::new(player_a, RowId::FrontRow, 4)
+            }),
+            move_to(3).validate(&game_state)
+        );
+        assert_eq!(
+            Err(RuleViolation::DoesNotFit {
+                pos: BoardPos::new(player_a, RowId::FrontRow, 5),
+                width: 2
+            }),
+            move_to(5).validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_moved_to_other_side_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+
+        let creature = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let to = BoardPos::new(player_b, RowId::FrontRow, 0);
+
+        assert_eq!(
+            Err(RuleViolation::NotYourSide {
+                player_id: player_a,
+                pos: to
+            }),
+            CreatureMovedEvent::new(creature, to, MoveReason::Effect).validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_moved_out_of_position_expects_only_own_effect_ok() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let creature = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().placeable_at(Position::Back),
+            BoardPos::new(player_a, RowId::BackRow, 0),
+        );
+        let to = BoardPos::new(player_a, RowId::FrontRow, 0);
+
+        assert_eq!(
+            Err(RuleViolation::NotPlaceableAt {
+                pos: to,
+                placeable_at: Position::Back
+            }),
+            CreatureMovedEvent::new(creature, to, MoveReason::Effect).validate(&game_state)
+        );
+        assert!(CreatureMovedEvent::new(creature, to, MoveReason::OwnEffect)
+            .validate(&game_state)
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_turn_action_expects_versatile_on_owners_turn() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+
+        let plain = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let versatile = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new()
+                .placeable_at(Position::Front)
+                .keyword(Keyword::Versatile),
+            BoardPos::new(player_a, RowId::FrontRow, 1),
+        );
+        let enemy = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().keyword(Keyword::Versatile),
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+
+        assert_eq!(
+            Err(RuleViolation::NotVersatile { creature_id: plain }),
+            CreatureMovedEvent::new(
+                plain,
+                BoardPos::new(player_a, RowId::BackRow, 0),
+                MoveReason::TurnAction
+            )
+            .validate(&game_state)
+        );
+        assert!(CreatureMovedEvent::new(
+            versatile,
+            BoardPos::new(player_a, RowId::BackRow, 1),
+            MoveReason::TurnAction
+        )
+        .validate(&game_state)
+        .is_ok());
+        assert_eq!(
+            Err(RuleViolation::NotYourTurn {
+                player_id: player_b
+            }),
+            CreatureMovedEvent::new(
+                enemy,
+                BoardPos::new(player_b, RowId::BackRow, 0),
+                MoveReason::TurnAction
+            )
+            .validate(&game_state)
+        );
+    }
+}
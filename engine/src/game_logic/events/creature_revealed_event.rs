@@ -0,0 +1,46 @@
+use super::{Event, VisualEvent};
+use crate::game_state::game_state::GameState;
+use protocol::{
+    entities::{CreatureInstanceId, PlayerId},
+    visual_events::CreatureRevealed,
+};
+
+/// Turns a hidden creature face up. Raised by the engine when the creature first fights.
+#[derive(Debug, Clone)]
+pub struct CreatureRevealedEvent {
+    creature_id: CreatureInstanceId,
+}
+
+impl CreatureRevealedEvent {
+    #[must_use]
+    pub fn new(creature_id: CreatureInstanceId) -> Self {
+        Self { creature_id }
+    }
+
+    #[must_use]
+    pub fn creature_id(&self) -> CreatureInstanceId {
+        self.creature_id
+    }
+}
+
+impl Event for CreatureRevealedEvent {
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        // Both players are shown the creature, including its definition.
+        let board = game_state.board();
+        let creature = board.creature_by_id(self.creature_id)?;
+        if !creature.get(|c| c.is_hidden()) {
+            return None;
+        }
+
+        let definition = game_state.creature_definition_of(self.creature_id)?;
+
+        Some(VisualEvent::CreatureRevealed(CreatureRevealed {
+            board_pos: board.creature_pos(self.creature_id)?,
+            creature: creature.get(|c| c.view(definition)),
+        }))
+    }
+}
@@ -1,23 +1,32 @@
 use super::{Event, VisualEvent};
-use crate::game_state::game_state::GameState;
-use protocol::entities::{BoardPos, CreatureInstanceId, PlayerId};
+use crate::{game_state::game_state::GameState, v2::CreatureDefinitionId};
+use protocol::{
+    entities::{BoardPos, CreatureInstance, CreatureInstanceId, Keyword, PlayerId},
+    visual_events::CreatureSetOnBoard,
+};
 
-#[derive(Debug)]
+/// Summons a new creature onto its player's side by an effect, rather than from their hand,
+/// such as a token made by a hero power. It costs no mana and can happen on either turn.
+#[derive(Debug, Clone)]
 pub struct CreatureSetEvent {
     player_id: PlayerId,
+    definition_id: CreatureDefinitionId,
     card_id: CreatureInstanceId,
     target_position: BoardPos,
 }
 
 impl CreatureSetEvent {
+    /// `card_id` is the new creature's id, usually from `GameState::next_instance_id`.
     #[must_use]
     pub fn new(
         player_id: PlayerId,
+        definition_id: CreatureDefinitionId,
         card_id: CreatureInstanceId,
         target_position: BoardPos,
     ) -> Self {
         Self {
             player_id,
+            definition_id,
             card_id,
             target_position,
         }
@@ -28,6 +37,11 @@ impl CreatureSetEvent {
         self.player_id
     }
 
+    #[must_use]
+    pub fn definition_id(&self) -> CreatureDefinitionId {
+        self.definition_id
+    }
+
     #[must_use]
     pub fn card_id(&self) -> CreatureInstanceId {
         self.card_id
@@ -39,33 +53,197 @@ impl CreatureSetEvent {
     }
 }
 
-// #[derive(Debug, Serialize, Deserialize, Clone)]
-// pub struct CreatureSetClientEvent {
-//     pub player_id: PlayerId,
-//     pub card_id: CreatureInstanceId,
-//     pub pos: BoardPos,
-//     pub card: UnitCardInstancePlayerView,
-// }
-
 impl Event for CreatureSetEvent {
+    fn validate(&self, game_state: &GameState) -> super::Result {
+        validation::validate_is_players_side(self, game_state)?;
+        validation::validate_slots_available(self, game_state)?;
+        validation::validate_respects_placeableat(self, game_state)?;
+
+        Ok(())
+    }
+
     fn maybe_client_event(
         &self,
         player_id: PlayerId,
         game_state: &GameState,
     ) -> Option<VisualEvent> {
-        // let view = game_state
-        //     .board()
-        //     .creature_instance_all(self.card_id())
-        //     .player_view(self.player_id); // todo: this is wrong, since both players get the owning player's view
-
-        // let event = CreatureSetClientEvent {
-        //     player_id: self.player_id,
-        //     card_id: self.card_id,
-        //     pos: self.target_position,
-        //     card: view,
-        // };
-
-        // Some(ClientEventView::UnitSet(event))
-        None
+        // Like a summon from the hand, hidden creatures are face down for the opponent.
+        let definition = validation::set_definition(self, game_state).ok()?;
+
+        let creature = if definition.has_keyword(Keyword::Hidden) && player_id != self.player_id {
+            CreatureInstance::hidden(
+                self.card_id,
+                definition.hidden_cost_bucket(),
+                definition.width(),
+            )
+        } else {
+            CreatureInstance::new(self.card_id, definition.view(), Vec::new(), None)
+        };
+
+        Some(VisualEvent::CreatureSetOnBoard(CreatureSetOnBoard {
+            player_id: self.player_id,
+            board_pos: self.target_position,
+            creature,
+        }))
+    }
+}
+
+mod validation {
+    use log::debug;
+    use protocol::{
+        entities::{Keyword, Position, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    use crate::{game_state::game_state::GameState, v2::CreatureDefinition};
+
+    use super::CreatureSetEvent;
+
+    /// The definition of the creature being set, which must be a known creature.
+    pub fn set_definition<'a>(
+        event: &CreatureSetEvent,
+        game_state: &'a GameState,
+    ) -> Result<&'a CreatureDefinition, RuleViolation> {
+        let definition = game_state
+            .creature_definition(event.definition_id())
+            .ok_or(RuleViolation::UnknownCard {
+                card_id: event.card_id(),
+            })?;
+
+        if definition.is_spell() {
+            Err(RuleViolation::NotACreature {
+                card_id: event.card_id(),
+            })
+        } else {
+            Ok(definition)
+        }
+    }
+
+    pub fn validate_is_players_side(
+        event: &CreatureSetEvent,
+        _game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the creature is set on the player's own side.");
+        let player_id = event.player_id();
+        let requested_pos = event.target_position();
+
+        if requested_pos.player_id == player_id {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotYourSide {
+                player_id,
+                pos: requested_pos,
+            })
+        }
+    }
+
+    pub fn validate_slots_available(
+        event: &CreatureSetEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the slots for the set creature are not already occupied.");
+        let creature_width = set_definition(event, game_state)?.width();
+        let requested_pos = event.target_position();
+
+        if !game_state
+            .board()
+            .is_range_in_row(requested_pos, creature_width)
+        {
+            return Err(RuleViolation::DoesNotFit {
+                pos: requested_pos,
+                width: creature_width,
+            });
+        }
+
+        for i in 0..creature_width {
+            let mut look_pos = requested_pos;
+            look_pos.row_index += i;
+
+            if game_state.board().creature_at_pos(look_pos).is_some() {
+                return Err(RuleViolation::SlotOccupied { pos: look_pos });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_respects_placeableat(
+        event: &CreatureSetEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the slots are in the card's placable positions.");
+        let definition = set_definition(event, game_state)?;
+        let placeable_at = definition.placeable_at();
+        let attempted_row = event.target_position().row_id;
+
+        // Versatile creatures can go in either row.
+        if definition.has_keyword(Keyword::Versatile) {
+            return Ok(());
+        }
+
+        if (placeable_at == Position::Back && attempted_row == RowId::FrontRow)
+            || (placeable_at == Position::Front && attempted_row == RowId::BackRow)
+        {
+            Err(RuleViolation::NotPlaceableAt {
+                pos: event.target_position(),
+                placeable_at,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreatureSetEvent;
+    use crate::{
+        game_logic::events::Event,
+        game_state::game_state::{tests::place_creature, GameState},
+        v2::builder::CreatureDefinitionBuilder,
+    };
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, PlayerId, Position, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    #[test]
+    fn validate_expects_set_follows_summon_placement_rules() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let definition = CreatureDefinitionBuilder::new()
+            .health(1)
+            .placeable_at(Position::Back)
+            .build();
+        let definition_id = definition.definition_id();
+        game_state.add_creature_definition(definition);
+
+        let taken = BoardPos::new(player_a, RowId::BackRow, 0);
+        place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().health(1),
+            taken,
+        );
+        let set_at =
+            |pos| CreatureSetEvent::new(player_a, definition_id, CreatureInstanceId::new(), pos);
+
+        assert_eq!(
+            Err(RuleViolation::SlotOccupied { pos: taken }),
+            set_at(taken).validate(&game_state)
+        );
+
+        let front = BoardPos::new(player_a, RowId::FrontRow, 1);
+        assert_eq!(
+            Err(RuleViolation::NotPlaceableAt {
+                pos: front,
+                placeable_at: Position::Back
+            }),
+            set_at(front).validate(&game_state)
+        );
+
+        assert!(set_at(BoardPos::new(player_a, RowId::BackRow, 1))
+            .validate(&game_state)
+            .is_ok());
     }
 }
@@ -1,6 +1,10 @@
-use protocol::entities::CreatureInstanceId;
+use protocol::{
+    entities::{CreatureInstanceId, PlayerId},
+    visual_events::CreatureTakesDamage,
+};
 
-use super::Event;
+use super::{Event, VisualEvent};
+use crate::game_state::game_state::GameState;
 
 #[derive(Debug, Clone)]
 pub struct CreatureTakesDamageEvent {
@@ -28,4 +32,15 @@ impl CreatureTakesDamageEvent {
     }
 }
 
-impl Event for CreatureTakesDamageEvent {}
+impl Event for CreatureTakesDamageEvent {
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        _game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        Some(VisualEvent::CreatureTakesDamage(CreatureTakesDamage {
+            creature_id: self.creature_id,
+            damage_amount: self.damage_amount,
+        }))
+    }
+}
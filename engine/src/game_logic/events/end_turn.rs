@@ -1,7 +1,7 @@
 use crate::game_state::game_state::GameState;
 
 use super::{Event, VisualEvent};
-use protocol::entities::PlayerId;
+use protocol::{entities::PlayerId, rule_violation::RuleViolation, visual_events::TurnEnded};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -12,16 +12,15 @@ impl Event for EndTurnEvent {
         if game_state.cur_player_turn() == self.0 {
             Ok(())
         } else {
-            super::Result::Err("Cannot end a different player's turn.".into())
+            Err(RuleViolation::NotYourTurn { player_id: self.0 })
         }
     }
 
     fn maybe_client_event(
         &self,
-        player_id: PlayerId,
+        _player_id: PlayerId,
         _game_state: &GameState,
     ) -> Option<VisualEvent> {
-        None
-        // Some(ClientEventView::TurnEnded(self.0))
+        Some(VisualEvent::TurnEnded(TurnEnded { player_id: self.0 }))
     }
 }
@@ -0,0 +1,42 @@
+use super::{Event, VisualEvent};
+use crate::game_state::game_state::GameState;
+use protocol::{entities::PlayerId, visual_events::HeroTakesDamage};
+
+#[derive(Debug, Clone)]
+pub struct HeroTakesDamageEvent {
+    player_id: PlayerId,
+    damage_amount: usize,
+}
+
+impl HeroTakesDamageEvent {
+    #[must_use]
+    pub fn new(player_id: PlayerId, damage_amount: usize) -> Self {
+        Self {
+            player_id,
+            damage_amount,
+        }
+    }
+
+    #[must_use]
+    pub fn player_id(&self) -> PlayerId {
+        self.player_id
+    }
+
+    #[must_use]
+    pub fn damage_amount(&self) -> usize {
+        self.damage_amount
+    }
+}
+
+impl Event for HeroTakesDamageEvent {
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        _game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        Some(VisualEvent::HeroTakesDamage(HeroTakesDamage {
+            player_id: self.player_id,
+            damage_amount: self.damage_amount,
+        }))
+    }
+}
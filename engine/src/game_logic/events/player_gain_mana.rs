@@ -1,8 +1,10 @@
 use crate::game_state::game_state::GameState;
 
 use super::{Event, VisualEvent};
-use protocol::entities::PlayerId;
+use protocol::{entities::PlayerId, visual_events::PlayerGainMana};
 
+/// Raises a player's mana limit by `gain_count` (up to the rules' cap),
+/// then refills their available mana to the new limit.
 #[derive(Debug, Clone)]
 pub struct PlayerGainManaEvent {
     player_id: PlayerId,
@@ -32,13 +34,22 @@ impl PlayerGainManaEvent {
 impl Event for PlayerGainManaEvent {
     fn maybe_client_event(
         &self,
-        player_id: PlayerId,
-        _game_state: &GameState,
+        _player_id: PlayerId,
+        game_state: &GameState,
     ) -> Option<VisualEvent> {
-        // Some(ClientEventView::PlayerGainMana(
-        //     self.player_id,
-        //     self.gain_count as usize,
-        // ))
-        None
+        // Client events are created before the event is handled,
+        // so send the values the player will have afterwards.
+        let cap = game_state.rules().max_mana_limit;
+
+        let mana_limit = game_state
+            .board()
+            .player_hero(self.player_id)
+            .get(|h| h.mana_limit_after_gain(self.gain_count, cap));
+
+        Some(VisualEvent::PlayerGainMana(PlayerGainMana {
+            player_id: self.player_id,
+            mana_limit,
+            available_mana: mana_limit,
+        }))
     }
 }
@@ -1,7 +1,6 @@
-use crate::game_state::game_state::GameState;
-
 use super::{Event, VisualEvent};
-use protocol::entities::PlayerId;
+use crate::game_state::game_state::GameState;
+use protocol::{entities::PlayerId, rule_violation::RuleViolation, visual_events::PlayerSpendMana};
 
 #[derive(Debug, Clone)]
 pub struct PlayerSpendManaEvent {
@@ -30,31 +29,36 @@ impl PlayerSpendManaEvent {
 }
 
 impl Event for PlayerSpendManaEvent {
-    // fn validate(&self, game_state: &GameState) -> super::Result {
-    //     let mana_count = game_state.player_mana(self.player_id());
-
-    //     if mana_count >= self.mana_count() {
-    //         Ok(())
-    //     } else {
-    //         Err(format!(
-    //             "Player {:?} only has {} mana, but tried to spend {} mana.",
-    //             self.player_id(),
-    //             mana_count,
-    //             self.mana_count()
-    //         )
-    //         .into())
-    //     }
-    // }
-
-    // fn maybe_client_event(
-    //     &self,
-    //     player_id: PlayerId,
-    //     _game_state: &GameState,
-    // ) -> Option<VisualEvent> {
-    //     None
-    //     // Some(ClientEventView::PlayerSpendMana {
-    //     //     player_id: self.player_id,
-    //     //     spent_mana_count: self.mana_count as usize,
-    //     // })
-    // }
+    fn validate(&self, game_state: &GameState) -> super::Result {
+        let mana_count = game_state
+            .board()
+            .player_hero(self.player_id())
+            .get(|h| h.mana());
+
+        if mana_count >= self.mana_count() {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotEnoughMana {
+                cost: self.mana_count(),
+                available: mana_count,
+            })
+        }
+    }
+
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        let cur_mana = game_state
+            .board()
+            .player_hero(self.player_id)
+            .get(|h| h.mana());
+
+        Some(VisualEvent::PlayerSpendMana(PlayerSpendMana {
+            player_id: self.player_id,
+            spent_mana_count: self.mana_count,
+            available_mana: cur_mana.saturating_sub(self.mana_count),
+        }))
+    }
 }
@@ -1,22 +1,40 @@
-use protocol::entities::BoardPos;
+use protocol::{
+    entities::{BoardPos, PlayerId},
+    visual_events::PosTakesDamage,
+};
 
-use super::Event;
+use super::{Event, VisualEvent};
+use crate::game_state::game_state::GameState;
 
+/// Damage dealt to slots on the board, rather than to whatever stands in them.
+///
+/// Area effects apply per slot: a creature is hit once for every one of its slots
+/// that is listed, so a 2-wide creature entirely inside the area is hit twice.
+/// Empty slots take the damage harmlessly, and a hero row slot damages that player's hero.
 #[derive(Debug, Clone)]
 pub struct PosTakesDamageEvent {
-    pos: BoardPos,
+    slots: Vec<BoardPos>,
     damage_amount: usize,
 }
 
 impl PosTakesDamageEvent {
     #[must_use]
     pub fn new(pos: BoardPos, damage_amount: usize) -> Self {
-        Self { pos, damage_amount }
+        Self::area(vec![pos], damage_amount)
     }
 
+    /// Damages every slot in `slots` at once.
     #[must_use]
-    pub fn pos(&self) -> BoardPos {
-        self.pos
+    pub fn area(slots: Vec<BoardPos>, damage_amount: usize) -> Self {
+        Self {
+            slots,
+            damage_amount,
+        }
+    }
+
+    #[must_use]
+    pub fn slots(&self) -> &[BoardPos] {
+        &self.slots
     }
 
     #[must_use]
@@ -25,4 +43,77 @@ impl PosTakesDamageEvent {
     }
 }
 
-impl Event for PosTakesDamageEvent {}
+impl Event for PosTakesDamageEvent {
+    fn validate(&self, game_state: &GameState) -> super::Result {
+        validation::validate_slots_exist(self, game_state)
+    }
+
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        _game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        // Shown even when every slot is empty, so the hit can still be drawn.
+        Some(VisualEvent::PosTakesDamage(PosTakesDamage {
+            slots: self.slots.clone(),
+            damage_amount: self.damage_amount,
+        }))
+    }
+}
+
+mod validation {
+    use log::debug;
+    use protocol::{entities::BoardPos, rule_violation::RuleViolation};
+
+    use crate::game_state::game_state::GameState;
+
+    use super::PosTakesDamageEvent;
+
+    pub fn validate_slots_exist(
+        event: &PosTakesDamageEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the damaged slots are on the board.");
+
+        let missing = event.slots().iter().find(|pos| {
+            !game_state.board().is_range_in_row(**pos, 1)
+                && **pos != BoardPos::hero_pos(pos.player_id)
+        });
+
+        match missing {
+            Some(pos) => Err(RuleViolation::NoSuchSlot { pos: *pos }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PosTakesDamageEvent;
+    use crate::{game_logic::events::Event, game_state::game_state::GameState};
+    use protocol::{
+        entities::{BoardPos, PlayerId, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    #[test]
+    fn validate_when_slot_off_board_expects_err() {
+        let game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let off_board = BoardPos::new(player_a, RowId::FrontRow, 6);
+        let beside_hero = BoardPos::new(player_a, RowId::Hero, 1);
+
+        for pos in [off_board, beside_hero] {
+            assert_eq!(
+                Err(RuleViolation::NoSuchSlot { pos }),
+                PosTakesDamageEvent::area(vec![BoardPos::new(player_a, RowId::BackRow, 0), pos], 1)
+                    .validate(&game_state)
+            );
+        }
+
+        assert!(PosTakesDamageEvent::new(BoardPos::hero_pos(player_a), 1)
+            .validate(&game_state)
+            .is_ok());
+    }
+}
@@ -1,7 +1,10 @@
 use crate::game_state::game_state::GameState;
 
 use super::{Event, VisualEvent};
-use protocol::entities::{BoardPos, CreatureInstanceId, PlayerId};
+use protocol::{
+    entities::{BoardPos, CreatureInstance, CreatureInstanceId, Keyword, PlayerId},
+    visual_events::CreatureSummonedFromHand,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -39,10 +42,12 @@ impl CreatureSummonedFromHandEvent {
 
 impl Event for CreatureSummonedFromHandEvent {
     fn validate(&self, game_state: &GameState) -> super::Result {
+        validation::validate_is_players_turn(self, game_state)?;
         validation::validate_is_players_side(self, game_state)?;
-        // validation::validate_slots_available(self, game_state)?;
-        // validation::validate_respects_placeableat(self, game_state)?;
-        // validation::validate_player_has_enough_mana(self, game_state)?;
+        validation::validate_slots_available(self, game_state)?;
+        validation::validate_respects_placeableat(self, game_state)?;
+        validation::validate_player_has_enough_mana(self, game_state)?;
+        validation::validate_summon_condition(self, game_state)?;
 
         Ok(())
     }
@@ -50,75 +55,116 @@ impl Event for CreatureSummonedFromHandEvent {
     fn maybe_client_event(
         &self,
         player_id: PlayerId,
-        _game_state: &GameState,
+        game_state: &GameState,
     ) -> Option<VisualEvent> {
-        // let client_event = SummonCreatureFromHandClientEvent {
-        //     player_id: self.player_id,
-        //     board_pos: self.board_pos,
-        //     hand_card_id: self.hand_card_id,
-        // };
+        // Summoned creatures are visible to both players, though hidden ones are face down for the opponent.
+        let definition = validation::summoned_definition(self, game_state).ok()?;
 
-        // Some(ClientEventView::CreatureSummonedFromHand(client_event))
-        None
-    }
-}
+        let creature = if definition.has_keyword(Keyword::Hidden) && player_id != self.player_id {
+            CreatureInstance::hidden(
+                self.hand_card_id,
+                definition.hidden_cost_bucket(),
+                definition.width(),
+            )
+        } else {
+            CreatureInstance::new(self.hand_card_id, definition.view(), Vec::new(), None)
+        };
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct SummonCreatureFromHandClientEvent {
-    pub player_id: PlayerId,
-    pub board_pos: BoardPos,
-    pub hand_card_id: CreatureInstanceId,
+        Some(VisualEvent::CreatureSummonedFromHand(
+            CreatureSummonedFromHand {
+                player_id: self.player_id,
+                board_pos: self.board_pos,
+                creature,
+            },
+        ))
+    }
 }
 
 mod validation {
     use log::debug;
-    use protocol::entities::{Position, RowId};
+    use protocol::{
+        entities::{Keyword, Position, RowId},
+        rule_violation::RuleViolation,
+    };
 
-    use crate::game_state::game_state::GameState;
+    use crate::{game_state::game_state::GameState, v2::CreatureDefinition};
 
     use super::CreatureSummonedFromHandEvent;
 
-    // pub fn validate_slots_available<'a>(
-    //     event: &CreatureSummonedFromHandEvent,
-    //     game_state: &GameState,
-    // ) -> super::super::Result {
-    //     debug!("Validating the slots for the summon are not already occupied.");
-    //     let creature_width = game_state
-    //         .hand(event.player_id())
-    //         .card(event.hand_card_id())
-    //         .width();
-
-    //     debug!("board pos?");
-    //     let requested_pos = event.board_pos();
-
-    //     debug!("is range in row?");
-    //     if !game_state
-    //         .board()
-    //         .is_range_in_row(requested_pos, creature_width)
-    //     {
-    //         return Err(format!(
-    //             "Creature has width {} and cannot be summoned at {:?}",
-    //             creature_width, requested_pos
-    //         )
-    //         .into());
-    //     }
-    //     debug!("is range in row finished");
-
-    //     for i in 0..creature_width {
-    //         let mut look_pos = requested_pos;
-    //         look_pos.row_index += i;
-
-    //         if game_state.board().creature_at_pos(look_pos).is_some() {
-    //             return Err(format!(
-    //                 "Cannot summon at pos {:?} with width {} since a creature occupies pos {:?}",
-    //                 requested_pos, creature_width, look_pos
-    //             )
-    //             .into());
-    //         }
-    //     }
-
-    //     Ok(())
-    // }
+    /// The definition of the card being summoned, which must be a creature in the player's hand.
+    pub fn summoned_definition<'a>(
+        event: &CreatureSummonedFromHandEvent,
+        game_state: &'a GameState,
+    ) -> Result<&'a CreatureDefinition, RuleViolation> {
+        let definition_id = game_state
+            .hand(event.player_id())
+            .card(event.hand_card_id())
+            .map(|c| c.get(|c| c.definition_id()))
+            .ok_or(RuleViolation::CardNotInHand {
+                player_id: event.player_id(),
+                card_id: event.hand_card_id(),
+            })?;
+
+        let definition =
+            game_state
+                .creature_definition(definition_id)
+                .ok_or(RuleViolation::UnknownCard {
+                    card_id: event.hand_card_id(),
+                })?;
+
+        if definition.is_spell() {
+            Err(RuleViolation::NotACreature {
+                card_id: event.hand_card_id(),
+            })
+        } else {
+            Ok(definition)
+        }
+    }
+
+    pub fn validate_slots_available(
+        event: &CreatureSummonedFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the slots for the summon are not already occupied.");
+        let creature_width = summoned_definition(event, game_state)?.width();
+        let requested_pos = event.board_pos();
+
+        if !game_state
+            .board()
+            .is_range_in_row(requested_pos, creature_width)
+        {
+            return Err(RuleViolation::DoesNotFit {
+                pos: requested_pos,
+                width: creature_width,
+            });
+        }
+
+        for i in 0..creature_width {
+            let mut look_pos = requested_pos;
+            look_pos.row_index += i;
+
+            if game_state.board().creature_at_pos(look_pos).is_some() {
+                return Err(RuleViolation::SlotOccupied { pos: look_pos });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_is_players_turn(
+        event: &CreatureSummonedFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the summon is made on the player's own turn.");
+
+        if game_state.cur_player_turn() == event.player_id() {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotYourTurn {
+                player_id: event.player_id(),
+            })
+        }
+    }
 
     pub fn validate_is_players_side(
         event: &CreatureSummonedFromHandEvent,
@@ -131,62 +177,344 @@ mod validation {
         if requested_pos.player_id == player_id {
             Ok(())
         } else {
-            Err(format!(
-                "Player {:?} cannot summon a creature on player {:?}'s side",
-                player_id, requested_pos.player_id
-            )
-            .into())
+            Err(RuleViolation::NotYourSide {
+                player_id,
+                pos: requested_pos,
+            })
+        }
+    }
+
+    pub fn validate_player_has_enough_mana(
+        event: &CreatureSummonedFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the player has enough mana for the summon.");
+        let mana_cost = summoned_definition(event, game_state)?.cost();
+        let mana_cost = u32::try_from(mana_cost).unwrap_or(0);
+
+        let player_mana = game_state
+            .board()
+            .player_hero(event.player_id())
+            .get(|h| h.mana());
+
+        if player_mana >= mana_cost {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotEnoughMana {
+                cost: mana_cost,
+                available: player_mana,
+            })
+        }
+    }
+
+    pub fn validate_summon_condition(
+        event: &CreatureSummonedFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the card's summon condition is met.");
+
+        if summoned_definition(event, game_state)?
+            .summon_condition_met(event.player_id(), game_state)
+        {
+            Ok(())
+        } else {
+            Err(RuleViolation::SummonConditionNotMet {
+                card_id: event.hand_card_id(),
+            })
+        }
+    }
+
+    pub fn validate_respects_placeableat(
+        event: &CreatureSummonedFromHandEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the slots are in the card's placable positions.");
+        let definition = summoned_definition(event, game_state)?;
+        let placeable_at = definition.placeable_at();
+        let attempted_row = event.board_pos().row_id;
+
+        // Versatile creatures can go in either row.
+        if definition.has_keyword(Keyword::Versatile) {
+            return Ok(());
         }
+
+        if (placeable_at == Position::Back && attempted_row == RowId::FrontRow)
+            || (placeable_at == Position::Front && attempted_row == RowId::BackRow)
+        {
+            Err(RuleViolation::NotPlaceableAt {
+                pos: event.board_pos(),
+                placeable_at,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreatureSummonedFromHandEvent;
+    use crate::{
+        game_logic::events::Event,
+        game_state::{game_state::GameState, CardInHand},
+        v2::{actions::DoNothingAction, builder::CreatureDefinitionBuilder, CreatureInstance},
+    };
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, Keyword, PlayerId, Position, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    /// Adds a card built from `builder` to the player's hand, and gives them plenty of mana.
+    fn add_card_to_hand(
+        game_state: &mut GameState,
+        player_id: PlayerId,
+        builder: &CreatureDefinitionBuilder,
+    ) -> CreatureInstanceId {
+        let definition = builder.build();
+        let definition_id = definition.definition_id();
+        game_state.add_creature_definition(definition);
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_id)
+            .get_mut(|h| h.set_mana(10));
+
+        let card_id = CreatureInstanceId::new();
+        game_state
+            .hand_mut(player_id)
+            .add_card(CardInHand::new_with_id(card_id, definition_id));
+
+        card_id
     }
 
-    // pub fn validate_player_has_enough_mana<'a, G>(
-    //     event: &CreatureSummonedFromHandEvent,
-    //     game_state: &GameState,
-    // ) -> super::super::Result {
-    //     debug!("Validating the player has enough mana for the summon.");
-    //     let card = game_state
-    //         .hand(event.player_id())
-    //         .card(event.hand_card_id());
-    //     let mana_cost = card.definition().cost() as u32;
-    //     let player_mana = game_state.player_mana(event.player_id());
-
-    //     if player_mana >= mana_cost {
-    //         Ok(())
-    //     } else {
-    //         Err(format!(
-    //             "Card costs {} mana, but player only has {}.",
-    //             mana_cost, player_mana
-    //         )
-    //         .into())
-    //     }
-    // }
-
-    // pub fn validate_respects_placeableat<'a>(
-    //     event: &CreatureSummonedFromHandEvent,
-    //     game_state: &GameState,
-    // ) -> super::super::Result {
-    //     debug!("Validating the slots are in the card's placable positions.");
-    //     let player_id = event.player_id();
-    //     let card_id = event.hand_card_id();
-
-    //     let placeable_at = game_state
-    //         .hand(player_id)
-    //         .card(card_id)
-    //         .definition()
-    //         .placeable_at();
-
-    //     let attempted_row = event.board_pos().row_id;
-
-    //     if (placeable_at == Position::Back && attempted_row == RowId::FrontRow)
-    //         || (placeable_at == Position::Front && attempted_row == RowId::BackRow)
-    //     {
-    //         Err(format!(
-    //             "Cannot place in {:?} when card is only placeable at {:?}",
-    //             attempted_row, placeable_at
-    //         )
-    //         .into())
-    //     } else {
-    //         Ok(())
-    //     }
-    // }
+    #[test]
+    fn validate_when_card_costs_more_than_mana_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new().cost(3),
+        );
+
+        let event = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+            card_id,
+        );
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(2));
+        assert_eq!(
+            Err(RuleViolation::NotEnoughMana {
+                cost: 3,
+                available: 2
+            }),
+            event.validate(&game_state)
+        );
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_mana(3));
+        assert!(event.validate(&game_state).is_ok());
+    }
+
+    #[test]
+    fn validate_when_card_not_in_hand_expects_err() {
+        let game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = CreatureInstanceId::new();
+        let event = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+            card_id,
+        );
+
+        assert_eq!(
+            Err(RuleViolation::CardNotInHand {
+                player_id: player_a,
+                card_id
+            }),
+            event.validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_card_is_spell_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new().upon_cast(DoNothingAction),
+        );
+
+        let event = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+            card_id,
+        );
+
+        assert_eq!(
+            Err(RuleViolation::NotACreature { card_id }),
+            event.validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_not_players_turn_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_b = game_state.player_b_id();
+
+        let card_id =
+            add_card_to_hand(&mut game_state, player_b, &CreatureDefinitionBuilder::new());
+
+        let event = CreatureSummonedFromHandEvent::new(
+            player_b,
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+            card_id,
+        );
+
+        assert_eq!(
+            Err(RuleViolation::NotYourTurn {
+                player_id: player_b
+            }),
+            event.validate(&game_state)
+        );
+    }
+
+    #[test]
+    fn validate_when_too_wide_for_row_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new().width(2),
+        );
+
+        let last_slot = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::BackRow, 5),
+            card_id,
+        );
+        let second_last_slot = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::BackRow, 4),
+            card_id,
+        );
+        let hero_row =
+            CreatureSummonedFromHandEvent::new(player_a, BoardPos::hero_pos(player_a), card_id);
+
+        assert_eq!(
+            Err(RuleViolation::DoesNotFit {
+                pos: BoardPos::new(player_a, RowId::BackRow, 5),
+                width: 2
+            }),
+            last_slot.validate(&game_state)
+        );
+        assert!(hero_row.validate(&game_state).is_err());
+        assert!(second_last_slot.validate(&game_state).is_ok());
+    }
+
+    #[test]
+    fn validate_when_slot_occupied_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new().width(2),
+        );
+
+        let occupied = BoardPos::new(player_a, RowId::FrontRow, 3);
+        let blocker = CreatureInstance::new_from_definition(
+            CreatureInstanceId::new(),
+            &CreatureDefinitionBuilder::new().build(),
+        );
+        game_state
+            .board_mut()
+            .set_creature_at_pos(blocker, occupied);
+
+        let overlapping = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::FrontRow, 2),
+            card_id,
+        );
+        let beside = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::FrontRow, 4),
+            card_id,
+        );
+
+        assert_eq!(
+            Err(RuleViolation::SlotOccupied { pos: occupied }),
+            overlapping.validate(&game_state)
+        );
+        assert!(beside.validate(&game_state).is_ok());
+    }
+
+    #[test]
+    fn validate_when_wrong_row_for_placeable_at_expects_err() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new().placeable_at(Position::Back),
+        );
+
+        let front = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+            card_id,
+        );
+        let back = CreatureSummonedFromHandEvent::new(
+            player_a,
+            BoardPos::new(player_a, RowId::BackRow, 0),
+            card_id,
+        );
+
+        assert_eq!(
+            Err(RuleViolation::NotPlaceableAt {
+                pos: BoardPos::new(player_a, RowId::FrontRow, 0),
+                placeable_at: Position::Back
+            }),
+            front.validate(&game_state)
+        );
+        assert!(back.validate(&game_state).is_ok());
+    }
+
+    #[test]
+    fn validate_when_versatile_expects_either_row() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let card_id = add_card_to_hand(
+            &mut game_state,
+            player_a,
+            CreatureDefinitionBuilder::new()
+                .placeable_at(Position::Back)
+                .keyword(Keyword::Versatile),
+        );
+
+        for row_id in [RowId::FrontRow, RowId::BackRow] {
+            let event = CreatureSummonedFromHandEvent::new(
+                player_a,
+                BoardPos::new(player_a, row_id, 0),
+                card_id,
+            );
+
+            assert!(event.validate(&game_state).is_ok());
+        }
+    }
 }
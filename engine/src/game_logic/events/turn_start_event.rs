@@ -1,7 +1,7 @@
 use crate::game_state::game_state::GameState;
 
 use super::{Event, VisualEvent};
-use protocol::{entities::PlayerId, visual_events::TurnStarted};
+use protocol::{entities::PlayerId, rule_violation::RuleViolation, visual_events::TurnStarted};
 
 #[derive(Debug, Clone)]
 pub struct TurnStartEvent(pub PlayerId);
@@ -9,17 +9,17 @@ pub struct TurnStartEvent(pub PlayerId);
 impl Event for TurnStartEvent {
     fn maybe_client_event(
         &self,
-        player_id: PlayerId,
+        _player_id: PlayerId,
         _game_state: &GameState,
     ) -> Option<VisualEvent> {
-        Some(VisualEvent::TurnStarted(TurnStarted { player_id }))
+        Some(VisualEvent::TurnStarted(TurnStarted { player_id: self.0 }))
     }
 
     fn validate(&self, game_state: &GameState) -> super::Result {
         if game_state.cur_player_turn() == self.0 {
             Ok(())
         } else {
-            super::Result::Err("Turn start not valid for a different player's turn.".into())
+            Err(RuleViolation::NotYourTurn { player_id: self.0 })
         }
     }
 }
@@ -0,0 +1,137 @@
+use crate::game_state::game_state::GameState;
+
+use super::{Event, VisualEvent};
+use protocol::{entities::PlayerId, visual_events::HeroPowerUsed};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HeroPowerUsedEvent {
+    player_id: PlayerId,
+}
+
+impl HeroPowerUsedEvent {
+    #[must_use]
+    pub fn new(player_id: PlayerId) -> Self {
+        Self { player_id }
+    }
+
+    #[must_use]
+    pub fn player_id(&self) -> PlayerId {
+        self.player_id
+    }
+}
+
+impl Event for HeroPowerUsedEvent {
+    fn validate(&self, game_state: &GameState) -> super::Result {
+        validation::validate_is_players_turn(self, game_state)?;
+        validation::validate_not_used_this_turn(self, game_state)?;
+        validation::validate_player_has_enough_mana(self, game_state)?;
+        validation::validate_condition_is_met(self, game_state)?;
+
+        Ok(())
+    }
+
+    fn maybe_client_event(
+        &self,
+        _player_id: PlayerId,
+        game_state: &GameState,
+    ) -> Option<VisualEvent> {
+        let hero_power = game_state.hero_power_of(self.player_id)?;
+
+        Some(VisualEvent::HeroPowerUsed(HeroPowerUsed {
+            player_id: self.player_id,
+            hero_power: hero_power.view(),
+        }))
+    }
+}
+
+mod validation {
+    use log::debug;
+    use protocol::rule_violation::RuleViolation;
+
+    use crate::game_state::game_state::GameState;
+
+    use super::HeroPowerUsedEvent;
+
+    pub fn validate_is_players_turn(
+        event: &HeroPowerUsedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the hero power is used on the player's own turn.");
+
+        if game_state.cur_player_turn() == event.player_id() {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotYourTurn {
+                player_id: event.player_id(),
+            })
+        }
+    }
+
+    pub fn validate_not_used_this_turn(
+        event: &HeroPowerUsedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the hero power has not been used this turn.");
+        let used = game_state
+            .board()
+            .player_hero(event.player_id())
+            .get(|h| h.has_used_hero_power());
+
+        if used {
+            Err(RuleViolation::HeroPowerAlreadyUsed {
+                player_id: event.player_id(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn validate_player_has_enough_mana(
+        event: &HeroPowerUsedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the player has enough mana for the hero power.");
+        let mana_cost = game_state
+            .hero_power_of(event.player_id())
+            .ok_or(RuleViolation::NoHeroPower {
+                player_id: event.player_id(),
+            })?
+            .cost();
+
+        let player_mana = game_state
+            .board()
+            .player_hero(event.player_id())
+            .get(|h| h.mana());
+
+        if player_mana >= mana_cost {
+            Ok(())
+        } else {
+            Err(RuleViolation::NotEnoughMana {
+                cost: mana_cost,
+                available: player_mana,
+            })
+        }
+    }
+
+    pub fn validate_condition_is_met(
+        event: &HeroPowerUsedEvent,
+        game_state: &GameState,
+    ) -> super::super::Result {
+        debug!("Validating the hero power's condition is met.");
+        let hero_power =
+            game_state
+                .hero_power_of(event.player_id())
+                .ok_or(RuleViolation::NoHeroPower {
+                    player_id: event.player_id(),
+                })?;
+
+        if hero_power.condition_met(event.player_id(), game_state) {
+            Ok(())
+        } else {
+            Err(RuleViolation::HeroPowerConditionNotMet {
+                player_id: event.player_id(),
+            })
+        }
+    }
+}
@@ -1,3 +0,0 @@
-pub enum Keywords {
-    Defender,
-}
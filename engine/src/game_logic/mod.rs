@@ -3,7 +3,6 @@
 pub mod event_dispatch;
 mod event_handlers;
 pub mod events;
-// mod keywords;
 // mod passive_effect;
 
 // pub use buff::{Buff, BuffBuilder, BuffView};
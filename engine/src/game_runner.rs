@@ -7,6 +7,7 @@ use crate::{
     game_state::game_state::GameState,
 };
 use log::info;
+use protocol::entities::PlayerId;
 
 /// A runner for a game.
 /// Maintains the current `GameState` at any given moment,
@@ -33,7 +34,8 @@ impl GameRunner {
     }
 
     /// Run a game until completion.
-    pub async fn run_game(mut self) {
+    /// Returns the `PlayerId` of the winner, or `None` if the game ended in a draw.
+    pub async fn run_game(mut self) -> Option<PlayerId> {
         let player_a_notifier = self.player_a_handler.make_notifier().await;
         let player_b_notifier = self.player_b_handler.make_notifier().await;
 
@@ -51,7 +53,9 @@ impl GameRunner {
 
         let mut game_state = self.game_state;
 
-        dispatcher.dispatch(StartGameEvent, &mut game_state).await;
+        dispatcher
+            .dispatch_triggered(StartGameEvent, &mut game_state)
+            .await;
 
         while !game_state.is_game_over() {
             let client = if game_state.cur_player_turn() == game_state.player_a_id() {
@@ -63,7 +67,10 @@ impl GameRunner {
             GameRunner::player_take_turn_stage(client, &mut game_state, &mut dispatcher).await;
         }
 
-        info!("Game is over.");
+        let winner = game_state.winner();
+        info!("Game is over. Winner: {:?}", winner);
+
+        winner
     }
 
     async fn player_take_turn_stage(
@@ -75,7 +82,7 @@ impl GameRunner {
         info!("Turn starts for player: {:?}", cur_player_id);
 
         dispatcher
-            .dispatch(TurnStartEvent(cur_player_id), game_state)
+            .dispatch_triggered(TurnStartEvent(cur_player_id), game_state)
             .await;
 
         handler_player.on_turn_start(game_state).await;
@@ -93,7 +100,11 @@ impl GameRunner {
 
             let turn_is_over = action.is_end_turn();
 
-            dispatcher.dispatch(action, game_state).await;
+            if let Err(violation) = dispatcher.dispatch(action, game_state).await {
+                info!("Rejected action from player {cur_player_id:?}: {violation}");
+                handler_player.on_rule_violation(violation).await;
+                continue;
+            }
 
             if turn_is_over {
                 info!("Turn ends for player: {:?}", cur_player_id);
@@ -106,20 +117,30 @@ impl GameRunner {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::game_agent::{
+        tests::{MockTestPrompter, StubNotifier},
+        ClientNotifier, Prompter,
+    };
     use crate::{
-        game_agent::{
-            tests::{MockTestPrompter, StubNotifier},
-            ClientNotifier, Prompter,
-        },
-        game_state,
+        game_logic::event_dispatch::tests::make_test_dispatcher,
+        game_state::{CardInDeck, GameRules},
+        v2::builder::CreatureDefinitionBuilder,
     };
     use async_trait::async_trait;
-    use protocol::{client_actions::EndTurn, entities::PlayerId, from_client::ClientAction};
+    use protocol::{
+        client_actions::{Attack, EndTurn, SummonCreatureFromHand},
+        entities::{BoardPos, CreatureInstanceId, PlayerId, RowId},
+        from_client::ClientAction,
+        rule_violation::RuleViolation,
+    };
     use std::sync::Arc;
 
+    type TurnStartCheck = Box<dyn FnMut(&GameState) + Send + Sync>;
+
     struct TestClient {
         action_queue: Vec<ClientAction>,
-        on_turn_start_queue: Vec<Box<dyn FnMut(&GameState) + Send + Sync>>,
+        on_turn_start_queue: Vec<TurnStartCheck>,
+        rule_violations: Vec<RuleViolation>,
         notifier: StubNotifier,
     }
 
@@ -128,6 +149,7 @@ pub mod tests {
             Self {
                 action_queue: Vec::new(),
                 on_turn_start_queue: Vec::new(),
+                rule_violations: Vec::new(),
                 notifier: StubNotifier,
             }
         }
@@ -136,7 +158,7 @@ pub mod tests {
             self.action_queue.push(action);
         }
 
-        fn add_turn_start_check(&mut self, check: Box<dyn FnMut(&GameState) + Send + Sync>) {
+        fn add_turn_start_check(&mut self, check: TurnStartCheck) {
             self.on_turn_start_queue.push(check);
         }
     }
@@ -155,6 +177,10 @@ pub mod tests {
                 .expect("No actions left in the queue")
         }
 
+        async fn on_rule_violation(&mut self, violation: RuleViolation) {
+            self.rule_violations.push(violation);
+        }
+
         async fn make_prompter(&self) -> Arc<dyn Prompter> {
             Arc::new(MockTestPrompter::new())
         }
@@ -164,14 +190,13 @@ pub mod tests {
         }
     }
 
-    #[test]
-    pub fn gamerunner_when_game_run_expects_game_ends() {
-        let _ = env_logger::builder().is_test(true).try_init();
-        info!("starting...");
+    /// Runs a game where both players only ever end their turn, returning the winner.
+    fn run_end_turn_only_game(seed: u64) -> Option<PlayerId> {
         let mut client_a = Box::new(TestClient::new());
         let mut client_b = Box::new(TestClient::new());
 
-        let game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let game_state =
+            GameState::new_with_seed(PlayerId::new(), PlayerId::new(), GameRules::default(), seed);
 
         for _ in 0..100 {
             client_a.add_action(ClientAction::EndTurn(EndTurn {
@@ -185,11 +210,254 @@ pub mod tests {
 
         let runner = GameRunner::new(client_a, client_b, game_state);
 
+        smol::block_on(async { runner.run_game().await })
+    }
+
+    #[test]
+    pub fn gamerunner_when_game_run_expects_game_ends() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        info!("starting...");
+
+        // Both decks are empty, so the game ends once fatigue
+        // brings the first player's hero down.
+        assert!(run_end_turn_only_game(1).is_some());
+    }
+
+    /// A game driven straight from client actions, starting each turn like `run_game` does.
+    struct ScriptedGame {
+        game_state: GameState,
+        dispatcher: EventDispatcher,
+    }
+
+    impl ScriptedGame {
+        /// Both players have the same ids every time, and decks of 3/2 creatures costing 1.
+        fn new(seed: u64) -> Self {
+            let mut game_state = GameState::new_with_seed(
+                PlayerId::parse_str("6a1f3c1e-2b7d-4c4e-9f0a-0c3f5d7e9b21"),
+                PlayerId::parse_str("d2b8e4a7-5c1f-4a3b-8e6d-7f9a1b3c5d42"),
+                GameRules::default(),
+                seed,
+            );
+
+            let mut builder = CreatureDefinitionBuilder::new();
+            builder.cost(1).attack(3).health(2);
+            let definition = builder.build();
+            let definition_id = definition.definition_id();
+            game_state.add_creature_definition(definition);
+
+            for player_id in [game_state.player_a_id(), game_state.player_b_id()] {
+                for _ in 0..10 {
+                    game_state
+                        .deck_mut(player_id)
+                        .get_mut(|d| d.add_card(CardInDeck::new(definition_id)));
+                }
+            }
+
+            let mut dispatcher = make_test_dispatcher(&game_state);
+            smol::block_on(async {
+                dispatcher
+                    .dispatch_triggered(StartGameEvent, &mut game_state)
+                    .await;
+                dispatcher
+                    .dispatch_triggered(
+                        TurnStartEvent(game_state.cur_player_turn()),
+                        &mut game_state,
+                    )
+                    .await;
+            });
+
+            Self {
+                game_state,
+                dispatcher,
+            }
+        }
+
+        fn act(&mut self, action: ClientAction) -> crate::game_logic::events::Result {
+            let turn_is_over = matches!(action, ClientAction::EndTurn(_));
+
+            smol::block_on(async {
+                self.dispatcher
+                    .dispatch(action, &mut self.game_state)
+                    .await?;
+
+                if turn_is_over {
+                    let next_player = self.game_state.cur_player_turn();
+                    self.dispatcher
+                        .dispatch_triggered(TurnStartEvent(next_player), &mut self.game_state)
+                        .await;
+                }
+
+                Ok(())
+            })
+        }
+
+        fn hero_health(&self, player_id: PlayerId) -> i32 {
+            self.game_state
+                .board()
+                .player_hero(player_id)
+                .get(|h| h.health())
+        }
+    }
+
+    #[test]
+    pub fn gamerunner_when_same_seed_expects_recorded_actions_replay() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        for seed in 0..4 {
+            let mut recorded = ScriptedGame::new(seed);
+            let first = recorded.game_state.cur_player_turn();
+            let second = recorded.game_state.other_player(first);
+
+            let card_id = recorded
+                .game_state
+                .hand(first)
+                .cards()
+                .next()
+                .unwrap()
+                .get(|c| c.card_id());
+            let hero_id = recorded
+                .game_state
+                .board()
+                .player_hero(second)
+                .get(|h| h.instance_id());
+
+            let actions = vec![
+                ClientAction::SummonCreatureFromHand(SummonCreatureFromHand {
+                    player_id: first,
+                    board_pos: BoardPos::new(first, RowId::FrontRow, 0),
+                    card_id,
+                }),
+                ClientAction::EndTurn(EndTurn { player_id: first }),
+                ClientAction::EndTurn(EndTurn { player_id: second }),
+                ClientAction::Attack(Attack {
+                    attacker: card_id,
+                    target: hero_id,
+                }),
+            ];
+
+            for action in &actions {
+                recorded.act(action.clone()).unwrap();
+            }
+
+            let mut replayed = ScriptedGame::new(seed);
+            for action in actions {
+                assert_eq!(Ok(()), replayed.act(action));
+            }
+
+            assert!(replayed.hero_health(second) < replayed.hero_health(first));
+            assert_eq!(recorded.hero_health(second), replayed.hero_health(second));
+            assert_eq!(
+                recorded.game_state.hand(second).len(),
+                replayed.game_state.hand(second).len()
+            );
+        }
+    }
+
+    #[test]
+    pub fn gamerunner_when_turn_ends_expects_other_player_turn_starts() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let mut client_a = Box::new(TestClient::new());
+        let mut client_b = Box::new(TestClient::new());
+
+        let game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+
+        client_a.add_action(ClientAction::EndTurn(EndTurn {
+            player_id: player_a,
+        }));
+
+        client_a.add_turn_start_check(Box::new(move |game_state| {
+            assert_eq!(player_a, game_state.cur_player_turn());
+        }));
+
+        client_b.add_turn_start_check(Box::new(move |game_state| {
+            assert_eq!(player_b, game_state.cur_player_turn());
+        }));
+
+        client_b.add_action(ClientAction::EndTurn(EndTurn {
+            player_id: player_b,
+        }));
+
+        let mut dispatcher = EventDispatcher::new(
+            Arc::new(StubNotifier),
+            Arc::new(MockTestPrompter::new()),
+            player_a,
+            Arc::new(StubNotifier),
+            Arc::new(MockTestPrompter::new()),
+            player_b,
+        );
+
+        let mut game_state = game_state;
+
         smol::block_on(async {
-            runner.run_game().await;
+            GameRunner::player_take_turn_stage(client_a.as_mut(), &mut game_state, &mut dispatcher)
+                .await;
+
+            assert_eq!(player_b, game_state.cur_player_turn());
+
+            GameRunner::player_take_turn_stage(client_b.as_mut(), &mut game_state, &mut dispatcher)
+                .await;
+
+            assert_eq!(player_a, game_state.cur_player_turn());
         });
     }
 
+    #[test]
+    pub fn gamerunner_when_action_breaks_rule_expects_violation_reported_and_turn_continues() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let mut client_a = Box::new(TestClient::new());
+
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let unknown_creature = CreatureInstanceId::new();
+        let hero_b = game_state
+            .board()
+            .player_hero(player_b)
+            .get(|h| h.instance_id());
+
+        // Actions are taken from the back of the queue.
+        client_a.add_action(ClientAction::EndTurn(EndTurn {
+            player_id: player_a,
+        }));
+        client_a.add_action(ClientAction::Attack(Attack {
+            attacker: unknown_creature,
+            target: hero_b,
+        }));
+        client_a.add_action(ClientAction::EndTurn(EndTurn {
+            player_id: player_b,
+        }));
+
+        let mut dispatcher = EventDispatcher::new(
+            Arc::new(StubNotifier),
+            Arc::new(MockTestPrompter::new()),
+            player_a,
+            Arc::new(StubNotifier),
+            Arc::new(MockTestPrompter::new()),
+            player_b,
+        );
+
+        smol::block_on(GameRunner::player_take_turn_stage(
+            client_a.as_mut(),
+            &mut game_state,
+            &mut dispatcher,
+        ));
+
+        assert_eq!(
+            vec![
+                RuleViolation::NotYourTurn {
+                    player_id: player_b
+                },
+                RuleViolation::NotOnBoard {
+                    creature_id: unknown_creature
+                },
+            ],
+            client_a.rule_violations
+        );
+        assert_eq!(player_b, game_state.cur_player_turn());
+    }
+
     // #[test]
     // pub fn gamerunner_when_game_run_expects_game_ends() {
     //     let _ = env_logger::builder().is_test(true).try_init();
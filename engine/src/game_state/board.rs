@@ -1,11 +1,18 @@
 use super::{game_state::GameState, hero::HeroInstance};
 use crate::v2::CreatureInstance;
 use entity_arena::{id::EntityId, Entity, IsEntity, TypedEntity, Value};
-use protocol::entities::{BoardPos, EntityPosition, PlayerId};
+use protocol::entities::{BoardPos, CreatureInstanceId, EntityPosition, PlayerId, RowId, Tribe};
 use std::borrow::{Borrow, BorrowMut};
 
+/// The number of slots in each of a player's front and back rows.
+pub const ROW_LENGTH: usize = 6;
+
 /// A view over a `GameState` that provides board-level functionality,
 /// such as getting and setting `CreatureInstance`s at positions.
+///
+/// A creature with a width greater than 1 occupies that many adjacent slots in its row,
+/// starting at the position it was set at. Every covered slot refers to the same creature,
+/// so it can be found from any of them.
 pub struct Board<T>
 where
     T: Borrow<GameState>,
@@ -56,6 +63,164 @@ where
         game_state.positions_map().get(&entity_pos).copied()
     }
 
+    /// True if a creature of the given `width`, placed with its leftmost slot at `pos`,
+    /// fits within a front or back row.
+    pub fn is_range_in_row(&self, pos: impl Borrow<BoardPos>, width: usize) -> bool {
+        let pos = pos.borrow();
+
+        !pos.row_id.is_hero() && width > 0 && pos.row_index + width <= ROW_LENGTH
+    }
+
+    /// The creature on the board with the given id, if there is one.
+    pub fn creature_by_id(
+        &self,
+        instance_id: CreatureInstanceId,
+    ) -> Option<TypedEntity<CreatureInstance, &Value>> {
+        let game_state: &GameState = self.game_state.borrow();
+
+        game_state
+            .entity_arena()
+            .of_type::<CreatureInstance>()
+            .find(|c| c.get(|c| c.instance_id() == instance_id))
+    }
+
+    /// The ids of every creature on the given player's side of the board,
+    /// ordered front row first, then by the index of each creature's leftmost slot.
+    pub fn player_creature_ids(&self, player_id: PlayerId) -> Vec<CreatureInstanceId> {
+        let mut ids: Vec<CreatureInstanceId> = Vec::new();
+
+        for row_id in [RowId::FrontRow, RowId::BackRow] {
+            for row_index in 0..ROW_LENGTH {
+                let pos = BoardPos::new(player_id, row_id, row_index);
+
+                if let Some(instance_id) = self
+                    .creature_at_pos(pos)
+                    .map(|c| c.get(CreatureInstance::instance_id))
+                {
+                    if !ids.contains(&instance_id) {
+                        ids.push(instance_id);
+                    }
+                }
+            }
+        }
+
+        ids
+    }
+
+    /// The ids of the given player's creatures whose definitions belong to the tribe,
+    /// in the same order as `player_creature_ids`.
+    pub fn player_creature_ids_of_tribe(
+        &self,
+        player_id: PlayerId,
+        tribe: Tribe,
+    ) -> Vec<CreatureInstanceId> {
+        let game_state: &GameState = self.game_state.borrow();
+
+        self.player_creature_ids(player_id)
+            .into_iter()
+            .filter(|id| {
+                game_state
+                    .creature_definition_of(*id)
+                    .is_some_and(|d| d.has_tribe(tribe))
+            })
+            .collect()
+    }
+
+    /// The player whose hero has the given id, if any hero does.
+    pub fn hero_player_id(&self, instance_id: CreatureInstanceId) -> Option<PlayerId> {
+        let game_state: &GameState = self.game_state.borrow();
+
+        game_state
+            .entity_arena()
+            .of_type::<HeroInstance>()
+            .find(|h| h.get(|h| h.instance_id() == instance_id))
+            .map(|h| h.get(HeroInstance::player_id))
+    }
+
+    /// Every slot occupied by the creature with the given id, ordered from left to right.
+    /// Empty if the creature is not on the board.
+    pub fn slots_with_creature(&self, instance_id: CreatureInstanceId) -> Vec<BoardPos> {
+        let Some(entity_id) = self.creature_by_id(instance_id).map(|c| c.id()) else {
+            return Vec::new();
+        };
+
+        let game_state: &GameState = self.game_state.borrow();
+
+        let mut slots: Vec<BoardPos> = game_state
+            .positions_map()
+            .iter()
+            .filter(|(_, id)| **id == entity_id)
+            .filter_map(|(pos, _)| match pos {
+                EntityPosition::BoardPos(pos) => Some(*pos),
+                EntityPosition::Hand(..) => None,
+            })
+            .collect();
+
+        slots.sort_by_key(|pos| pos.row_index);
+
+        slots
+    }
+
+    /// The leftmost slot of the creature with the given id, if it is on the board.
+    pub fn creature_pos(&self, instance_id: CreatureInstanceId) -> Option<BoardPos> {
+        self.slots_with_creature(instance_id).first().copied()
+    }
+
+    /// A creature's companions are the creatures across from it: in its owner's other row,
+    /// covering at least one of the same indexes. A creature wider than its companion
+    /// can have several, ordered from left to right.
+    pub fn companions_of(&self, instance_id: CreatureInstanceId) -> Vec<CreatureInstanceId> {
+        let mut companions: Vec<CreatureInstanceId> = Vec::new();
+
+        for slot in self.slots_with_creature(instance_id) {
+            let Some(companion_id) = companion_slot(slot)
+                .and_then(|across| self.creature_at_pos(across))
+                .map(|c| c.get(CreatureInstance::instance_id))
+            else {
+                continue;
+            };
+
+            if !companions.contains(&companion_id) {
+                companions.push(companion_id);
+            }
+        }
+
+        companions
+    }
+
+    pub fn are_companions(&self, a: CreatureInstanceId, b: CreatureInstanceId) -> bool {
+        self.companions_of(a).contains(&b)
+    }
+
+    /// How many times each creature is hit by an effect that targets the given slots.
+    ///
+    /// Area effects apply per slot, not per creature: a creature is hit once
+    /// for every one of its slots in the area, so a 2-wide creature entirely inside
+    /// the area is hit twice. Empty slots are ignored.
+    /// Creatures are ordered by the first slot they were hit in.
+    pub fn creature_hits_for_slots(
+        &self,
+        slots: impl IntoIterator<Item = BoardPos>,
+    ) -> Vec<(CreatureInstanceId, usize)> {
+        let mut hits: Vec<(CreatureInstanceId, usize)> = Vec::new();
+
+        for slot in slots {
+            let Some(instance_id) = self
+                .creature_at_pos(slot)
+                .map(|c| c.get(CreatureInstance::instance_id))
+            else {
+                continue;
+            };
+
+            match hits.iter_mut().find(|(id, _)| *id == instance_id) {
+                Some((_, count)) => *count += 1,
+                None => hits.push((instance_id, 1)),
+            }
+        }
+
+        hits
+    }
+
     pub fn player_hero(&self, player_id: PlayerId) -> TypedEntity<HeroInstance, &Value> {
         let game_state: &GameState = self.game_state.borrow();
 
@@ -71,32 +236,104 @@ impl<T> Board<T>
 where
     T: BorrowMut<GameState>,
 {
+    /// Sets the creature on the board, with its leftmost slot at `position`.
+    /// All of the slots covered by the creature's width are occupied at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the creature does not fit in the row, or if any of its slots are already occupied.
     pub fn set_creature_at_pos(
         &mut self,
         creature: CreatureInstance,
         position: impl Borrow<BoardPos>,
     ) {
+        let position = *position.borrow();
+        let width = creature.width();
+
+        assert!(
+            self.is_range_in_row(position, width),
+            "A creature with width {width} does not fit at {position:?}."
+        );
+
+        let slots = covered_slots(position, width);
+
+        if let Some(occupied) = slots.iter().find(|s| self.entity_id_at_pos(*s).is_some()) {
+            panic!("Cannot set a creature at {position:?} since {occupied:?} is occupied.");
+        }
+
         let game_state: &mut GameState = self.game_state.borrow_mut();
-        let entity_arena = game_state.entity_arena_mut();
-        let entity_id = entity_arena.add(creature);
+        let entity_id = game_state.entity_arena_mut().add(creature);
 
-        let entity_pos = EntityPosition::BoardPos(*position.borrow());
-        game_state.positions_map_mut().insert(entity_pos, entity_id);
+        for slot in slots {
+            game_state
+                .positions_map_mut()
+                .insert(EntityPosition::BoardPos(slot), entity_id);
+        }
     }
 
+    /// Removes the entity at the given position from the board.
+    /// Every slot the entity occupies is cleared, so any of them may be given.
     pub fn remove_entity_at_pos(&mut self, position: impl Borrow<BoardPos>) {
         let game_state: &mut GameState = self.game_state.borrow_mut();
         let position_mapping = game_state.positions_map_mut();
 
         // What entity is in that position?
         let entity_pos = EntityPosition::BoardPos(*position.borrow());
-        let entity_id = position_mapping
-            .remove(entity_pos.borrow())
+        let entity_id = *position_mapping
+            .get(entity_pos.borrow())
             .expect("Attempted to remove at a position that had no entity.");
 
+        position_mapping.retain(|_, id| *id != entity_id);
+
         game_state.entity_arena_mut().remove(entity_id);
     }
 
+    /// The creature on the board with the given id, if there is one.
+    pub fn creature_by_id_mut(
+        &mut self,
+        instance_id: CreatureInstanceId,
+    ) -> Option<TypedEntity<CreatureInstance, &mut Value>> {
+        let game_state: &mut GameState = self.game_state.borrow_mut();
+
+        game_state
+            .entity_arena_mut()
+            .of_type_mut::<CreatureInstance>()
+            .find(|c| c.get(|c| c.instance_id() == instance_id))
+    }
+
+    /// Removes the creature with the given id from every slot it occupies, returning it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the creature is not on the board.
+    pub fn remove_creature(&mut self, instance_id: CreatureInstanceId) -> CreatureInstance {
+        let pos = self
+            .creature_pos(instance_id)
+            .expect("Attempted to remove a creature that is not on the board.");
+
+        let creature = self
+            .creature_at_pos(pos)
+            .expect("The creature must be at its own position.")
+            .get(CreatureInstance::clone);
+
+        self.remove_entity_at_pos(pos);
+
+        creature
+    }
+
+    /// Moves the creature with the given id so its leftmost slot is at `to`.
+    /// It may move over the slots it is leaving; the creature itself is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the creature is not on the board, does not fit at `to`,
+    /// or if another creature is in the way.
+    pub fn move_creature(&mut self, instance_id: CreatureInstanceId, to: impl Borrow<BoardPos>) {
+        let creature = self.remove_creature(instance_id);
+
+        self.set_creature_at_pos(creature, to);
+    }
+
     pub fn player_hero_mut(
         &mut self,
         player_id: PlayerId,
@@ -111,12 +348,32 @@ where
     }
 }
 
+/// The slot across from `pos`, at the same index in its player's other row.
+/// The hero row has no slot across from it.
+fn companion_slot(pos: BoardPos) -> Option<BoardPos> {
+    let row_id = match pos.row_id {
+        RowId::FrontRow => RowId::BackRow,
+        RowId::BackRow => RowId::FrontRow,
+        RowId::Hero => return None,
+    };
+
+    Some(BoardPos::new(pos.player_id, row_id, pos.row_index))
+}
+
+/// The slots covered by a creature of the given width whose leftmost slot is `position`.
+fn covered_slots(position: BoardPos, width: usize) -> Vec<BoardPos> {
+    (0..width)
+        .map(|i| BoardPos::new(position.player_id, position.row_id, position.row_index + i))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::v2::{CreatureDefinitionId, CreatureInstance};
+    use crate::v2::{builder::CreatureDefinitionBuilder, CreatureDefinitionId, CreatureInstance};
 
-    use super::GameState;
-    use protocol::entities::{BoardPos, PlayerId, RowId};
+    use super::{GameState, ROW_LENGTH};
+    use crate::game_state::game_state::tests::place_creature;
+    use protocol::entities::{BoardPos, CreatureInstanceId, PlayerId, RowId, Tribe};
 
     #[test]
     fn game_state_new_expects_can_get_board() {
@@ -137,7 +394,10 @@ mod tests {
 
         let mut board = game_state.board_mut();
 
-        let creature = CreatureInstance::new_from_definition_id(CreatureDefinitionId::new());
+        let creature = CreatureInstance::new_from_definition_id(
+            CreatureInstanceId::new(),
+            CreatureDefinitionId::new(),
+        );
 
         let position = BoardPos::new(player_a, RowId::FrontRow, 0);
 
@@ -161,7 +421,10 @@ mod tests {
             "Expected no creature since none was inserted yet."
         );
 
-        let creature = CreatureInstance::new_from_definition_id(CreatureDefinitionId::new());
+        let creature = CreatureInstance::new_from_definition_id(
+            CreatureInstanceId::new(),
+            CreatureDefinitionId::new(),
+        );
 
         board.set_creature_at_pos(creature, position);
 
@@ -181,7 +444,10 @@ mod tests {
 
         let position = BoardPos::new(player_a, RowId::FrontRow, 0);
 
-        let creature = CreatureInstance::new_from_definition_id(CreatureDefinitionId::new());
+        let creature = CreatureInstance::new_from_definition_id(
+            CreatureInstanceId::new(),
+            CreatureDefinitionId::new(),
+        );
 
         board.set_creature_at_pos(creature, position);
 
@@ -201,4 +467,187 @@ mod tests {
             "The creature was removed, so expected it to not be found."
         );
     }
+
+    fn make_creature(width: usize) -> CreatureInstance {
+        let definition = CreatureDefinitionBuilder::new().width(width).build();
+
+        CreatureInstance::new_from_definition(CreatureInstanceId::new(), &definition)
+    }
+
+    #[test]
+    fn board_when_wide_creature_set_expects_found_from_every_slot() {
+        let player_a = PlayerId::new();
+        let mut game_state = GameState::new(player_a, PlayerId::new());
+        let mut board = game_state.board_mut();
+
+        let creature = make_creature(2);
+        let instance_id = creature.instance_id();
+
+        board.set_creature_at_pos(creature, BoardPos::new(player_a, RowId::BackRow, 2));
+
+        for index in [2, 3] {
+            let found = board
+                .creature_at_pos(BoardPos::new(player_a, RowId::BackRow, index))
+                .map(|c| c.get(CreatureInstance::instance_id));
+
+            assert_eq!(Some(instance_id), found);
+        }
+
+        assert!(board
+            .creature_at_pos(BoardPos::new(player_a, RowId::BackRow, 4))
+            .is_none());
+
+        assert_eq!(
+            vec![
+                BoardPos::new(player_a, RowId::BackRow, 2),
+                BoardPos::new(player_a, RowId::BackRow, 3)
+            ],
+            board.slots_with_creature(instance_id)
+        );
+        assert_eq!(
+            Some(BoardPos::new(player_a, RowId::BackRow, 2)),
+            board.creature_pos(instance_id)
+        );
+    }
+
+    #[test]
+    fn board_when_wide_creature_removed_from_any_slot_expects_all_slots_cleared() {
+        let player_a = PlayerId::new();
+        let mut game_state = GameState::new(player_a, PlayerId::new());
+        let mut board = game_state.board_mut();
+
+        let creature = make_creature(2);
+        let instance_id = creature.instance_id();
+
+        board.set_creature_at_pos(creature, BoardPos::new(player_a, RowId::FrontRow, 0));
+        board.remove_entity_at_pos(BoardPos::new(player_a, RowId::FrontRow, 1));
+
+        assert!(board.slots_with_creature(instance_id).is_empty());
+        assert!(board
+            .creature_at_pos(BoardPos::new(player_a, RowId::FrontRow, 0))
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "is occupied")]
+    fn board_when_wide_creature_overlaps_occupied_slot_expects_panic() {
+        let player_a = PlayerId::new();
+        let mut game_state = GameState::new(player_a, PlayerId::new());
+        let mut board = game_state.board_mut();
+
+        board.set_creature_at_pos(
+            make_creature(1),
+            BoardPos::new(player_a, RowId::FrontRow, 1),
+        );
+        board.set_creature_at_pos(
+            make_creature(2),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+    }
+
+    #[test]
+    fn board_creature_hits_for_slots_expects_one_hit_per_covered_slot() {
+        let player_a = PlayerId::new();
+        let mut game_state = GameState::new(player_a, PlayerId::new());
+        let mut board = game_state.board_mut();
+
+        let wide = make_creature(2);
+        let wide_id = wide.instance_id();
+        let narrow = make_creature(1);
+        let narrow_id = narrow.instance_id();
+
+        board.set_creature_at_pos(wide, BoardPos::new(player_a, RowId::FrontRow, 0));
+        board.set_creature_at_pos(narrow, BoardPos::new(player_a, RowId::FrontRow, 2));
+
+        let whole_row = (0..ROW_LENGTH).map(|i| BoardPos::new(player_a, RowId::FrontRow, i));
+
+        assert_eq!(
+            vec![(wide_id, 2), (narrow_id, 1)],
+            board.creature_hits_for_slots(whole_row)
+        );
+
+        let one_slot = [BoardPos::new(player_a, RowId::FrontRow, 1)];
+
+        assert_eq!(vec![(wide_id, 1)], board.creature_hits_for_slots(one_slot));
+    }
+
+    #[test]
+    fn board_companions_of_expects_creatures_across_overlapping_slots() {
+        let player_a = PlayerId::new();
+        let player_b = PlayerId::new();
+        let mut game_state = GameState::new(player_a, player_b);
+        let mut board = game_state.board_mut();
+
+        let wide = make_creature(2);
+        let wide_id = wide.instance_id();
+        let left = make_creature(1);
+        let left_id = left.instance_id();
+        let right = make_creature(1);
+        let right_id = right.instance_id();
+        let alone = make_creature(1);
+        let alone_id = alone.instance_id();
+        let enemy = make_creature(1);
+        let enemy_id = enemy.instance_id();
+
+        board.set_creature_at_pos(wide, BoardPos::new(player_a, RowId::FrontRow, 1));
+        board.set_creature_at_pos(right, BoardPos::new(player_a, RowId::BackRow, 2));
+        board.set_creature_at_pos(left, BoardPos::new(player_a, RowId::BackRow, 1));
+        board.set_creature_at_pos(alone, BoardPos::new(player_a, RowId::BackRow, 4));
+        board.set_creature_at_pos(enemy, BoardPos::new(player_b, RowId::FrontRow, 4));
+
+        assert_eq!(vec![left_id, right_id], board.companions_of(wide_id));
+        assert_eq!(vec![wide_id], board.companions_of(right_id));
+        assert!(board.are_companions(left_id, wide_id));
+        assert!(!board.are_companions(left_id, right_id));
+        // Only the owner's own rows face each other.
+        assert!(board.companions_of(alone_id).is_empty());
+        assert!(board.companions_of(enemy_id).is_empty());
+        assert!(board.companions_of(CreatureInstanceId::new()).is_empty());
+    }
+
+    #[test]
+    fn board_player_creature_ids_of_tribe_expects_only_that_players_tribe() {
+        let player_a = PlayerId::new();
+        let player_b = PlayerId::new();
+        let mut game_state = GameState::new(player_a, player_b);
+
+        let mut cat = CreatureDefinitionBuilder::new();
+        cat.tribe(Tribe::Cat);
+        let mut dog = CreatureDefinitionBuilder::new();
+        dog.tribe(Tribe::Dog);
+
+        let back_cat = place_creature(
+            &mut game_state,
+            &cat,
+            BoardPos::new(player_a, RowId::BackRow, 0),
+        );
+        let front_cat = place_creature(
+            &mut game_state,
+            &cat,
+            BoardPos::new(player_a, RowId::FrontRow, 3),
+        );
+        place_creature(
+            &mut game_state,
+            &cat,
+            BoardPos::new(player_b, RowId::FrontRow, 0),
+        );
+        let dog = place_creature(
+            &mut game_state,
+            &dog,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+
+        let board = game_state.board();
+        assert_eq!(
+            vec![front_cat, back_cat],
+            board.player_creature_ids_of_tribe(player_a, Tribe::Cat)
+        );
+        assert_eq!(
+            vec![dog],
+            board.player_creature_ids_of_tribe(player_a, Tribe::Dog)
+        );
+        assert!(board
+            .player_creature_ids_of_tribe(player_b, Tribe::Dog)
+            .is_empty());
+    }
 }
@@ -10,9 +10,15 @@ pub struct CardInDeck {
 }
 
 impl CardInDeck {
+    #[must_use]
     pub fn new(definition_id: CreatureDefinitionId) -> Self {
         Self { definition_id }
     }
+
+    #[must_use]
+    pub fn definition_id(&self) -> CreatureDefinitionId {
+        self.definition_id
+    }
 }
 
 #[cfg(test)]
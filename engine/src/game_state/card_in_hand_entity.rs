@@ -1,17 +1,40 @@
 use crate::v2::CreatureDefinitionId;
 use entity_arena::{id::EntityTypeId, IsEntity};
 use isentity_macro_derive::entity;
+use protocol::entities::CreatureInstanceId;
 use serde::{Deserialize, Serialize};
 
 /// An entity representing a card in a hand.
-#[derive(Debug, Serialize, Deserialize)]
+/// The `card_id` is the id clients use to refer to the card,
+/// for example when summoning it from the hand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[entity("abd58415-88ea-4f27-9d9b-05602ed75b6b")]
 pub struct CardInHand {
+    card_id: CreatureInstanceId,
     definition_id: CreatureDefinitionId,
 }
 
 impl CardInHand {
+    #[must_use]
     pub fn new(definition_id: CreatureDefinitionId) -> Self {
-        Self { definition_id }
+        Self::new_with_id(CreatureInstanceId::new(), definition_id)
+    }
+
+    #[must_use]
+    pub fn new_with_id(card_id: CreatureInstanceId, definition_id: CreatureDefinitionId) -> Self {
+        Self {
+            card_id,
+            definition_id,
+        }
+    }
+
+    #[must_use]
+    pub fn card_id(&self) -> CreatureInstanceId {
+        self.card_id
+    }
+
+    #[must_use]
+    pub fn definition_id(&self) -> CreatureDefinitionId {
+        self.definition_id
     }
 }
@@ -5,6 +5,7 @@ use entity_arena::{
 };
 use isentity_macro_derive::entity;
 use protocol::entities::PlayerId;
+use rand::{seq::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,6 +14,7 @@ pub struct DeckEntity {
     id: EntityId,
     player_id: PlayerId,
     cards: Vec<CardInDeck>,
+    empty_draw_count: usize,
 }
 
 impl DeckEntity {
@@ -21,6 +23,7 @@ impl DeckEntity {
             id: EntityId::new(),
             cards: Vec::new(),
             player_id,
+            empty_draw_count: 0,
         }
     }
 
@@ -28,11 +31,40 @@ impl DeckEntity {
         self.player_id
     }
 
+    /// Adds a card to the top of the deck.
     pub fn add_card(&mut self, to_add: CardInDeck) {
         self.cards.push(to_add);
     }
 
+    /// Shuffles the deck using the given RNG.
+    /// Pass the `GameState`'s RNG so the resulting order is reproducible from the game's seed.
+    pub fn shuffle(&mut self, rng: &mut impl Rng) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Draws the card on top of the deck.
+    /// If the deck is empty, returns `None` and counts the attempt,
+    /// so fatigue can be applied based on `empty_draw_count()`.
+    pub fn draw(&mut self) -> Option<CardInDeck> {
+        let card = self.cards.pop();
+
+        if card.is_none() {
+            self.empty_draw_count += 1;
+        }
+
+        card
+    }
+
     pub fn len(&self) -> usize {
         self.cards.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// The number of times a card was drawn while the deck was empty.
+    pub fn empty_draw_count(&self) -> usize {
+        self.empty_draw_count
+    }
 }
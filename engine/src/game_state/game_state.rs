@@ -1,11 +1,28 @@
-use super::{board::Board, deck::DeckEntity, hand::Hand, hero::HeroInstance};
+use super::{
+    board::Board, card_in_deck_entity::CardInDeck, deck::DeckEntity, hand::Hand,
+    hero::HeroInstance, rules::GameRules,
+};
+use crate::v2::{
+    CardRegistry, CreatureDefinition, CreatureDefinitionId, CreatureInstance, HeroPowerDefinition,
+};
 use entity_arena::{id::EntityId, EntityArena, TypedEntity, Value};
-use protocol::entities::{EntityPosition, PlayerId};
+use protocol::{
+    entities::{BoardPos, CreatureInstanceId, EntityPosition, Keyword, PlayerId, RowId},
+    rule_violation::RuleViolation,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use std::collections::HashMap;
 
+/// The RNG used for every random choice in a game.
+/// `ChaCha8Rng` is used (instead of `StdRng`) because its output for a given seed is stable across `rand` releases.
+pub type GameRng = ChaCha8Rng;
+
 /// A struct representing the full state of a game at any given point in time.
 /// The `GameState` knows the `PlayerId`s of the two players, whose turn it currently is,
 /// and maintains the state of all entities.
+/// All randomness in a game comes from the `GameState`'s seeded RNG,
+/// so the same seed and the same sequence of client actions always produce the same game.
 #[derive(Debug)]
 pub struct GameState {
     player_a_id: PlayerId,
@@ -13,16 +30,42 @@ pub struct GameState {
     cur_player_turn: PlayerId,
     entity_arena: EntityArena,
     entity_positions: HashMap<EntityPosition, EntityId>,
+    card_registry: CardRegistry,
+    rules: GameRules,
+    seed: u64,
+    rng: GameRng,
 }
 
 impl GameState {
+    #[must_use]
     pub fn new(player_a_id: PlayerId, player_b_id: PlayerId) -> Self {
+        Self::new_with_rules(player_a_id, player_b_id, GameRules::default())
+    }
+
+    /// Creates a `GameState` under the given rules, with a randomly chosen seed.
+    #[must_use]
+    pub fn new_with_rules(player_a_id: PlayerId, player_b_id: PlayerId, rules: GameRules) -> Self {
+        Self::new_with_seed(player_a_id, player_b_id, rules, rand::random())
+    }
+
+    /// Creates a `GameState` under the given rules, whose RNG is seeded with `seed`.
+    #[must_use]
+    pub fn new_with_seed(
+        player_a_id: PlayerId,
+        player_b_id: PlayerId,
+        rules: GameRules,
+        seed: u64,
+    ) -> Self {
         let mut state = Self {
             player_a_id,
             player_b_id,
             cur_player_turn: player_a_id,
             entity_arena: EntityArena::new(),
             entity_positions: HashMap::new(),
+            card_registry: CardRegistry::new(),
+            rules,
+            seed,
+            rng: GameRng::seed_from_u64(seed),
         };
 
         let deck_a = DeckEntity::new(player_a_id);
@@ -31,20 +74,118 @@ impl GameState {
         state.entity_arena.add(deck_a);
         state.entity_arena.add(deck_b);
 
-        state.entity_arena.add(HeroInstance::new(player_a_id));
-        state.entity_arena.add(HeroInstance::new(player_b_id));
+        let hero_a = HeroInstance::new(player_a_id, state.next_instance_id());
+        let hero_b = HeroInstance::new(player_b_id, state.next_instance_id());
+        state.entity_arena.add(hero_a);
+        state.entity_arena.add(hero_b);
 
         state
     }
 
+    /// The game is over once any player's hero has no health remaining.
+    #[must_use]
     pub fn is_game_over(&self) -> bool {
-        true
+        self.entity_arena
+            .of_type::<HeroInstance>()
+            .any(|h| h.get(|h| h.health() <= 0))
+    }
+
+    /// The winner of the game, if the game is over and exactly one hero is still standing.
+    /// Returns `None` while the game is in progress, or if both heroes fell at the same time.
+    #[must_use]
+    pub fn winner(&self) -> Option<PlayerId> {
+        if !self.is_game_over() {
+            return None;
+        }
+
+        let mut survivors = self
+            .entity_arena
+            .of_type::<HeroInstance>()
+            .filter(|h| h.get(|h| h.health() > 0))
+            .map(|h| h.get(HeroInstance::player_id));
+
+        match (survivors.next(), survivors.next()) {
+            (Some(winner), None) => Some(winner),
+            _ => None,
+        }
     }
 
+    #[must_use]
     pub fn cur_player_turn(&self) -> PlayerId {
-        todo!()
+        self.cur_player_turn
+    }
+
+    /// Passes the turn to the other player, returning the `PlayerId` whose turn it now is.
+    pub fn set_next_player_turn(&mut self) -> PlayerId {
+        self.cur_player_turn = self.other_player(self.cur_player_turn);
+        self.cur_player_turn
+    }
+
+    /// Given one of the two players in the game, returns the other.
+    #[must_use]
+    pub fn other_player(&self, player_id: PlayerId) -> PlayerId {
+        if player_id == self.player_a_id {
+            self.player_b_id
+        } else if player_id == self.player_b_id {
+            self.player_a_id
+        } else {
+            panic!("PlayerId {player_id:?} was not part of the GameState.")
+        }
+    }
+
+    /// The seed this game's RNG was created from.
+    /// Recording it (along with the client actions) is enough to reproduce the game.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The game's RNG. Any random choice made during a game must draw from this.
+    pub fn rng_mut(&mut self) -> &mut GameRng {
+        &mut self.rng
+    }
+
+    /// A new id for a card or creature created during the game, such as a drawn card or a token.
+    /// Ids are drawn from the game's RNG, so a replayed game's actions name the same cards.
+    pub fn next_instance_id(&mut self) -> CreatureInstanceId {
+        CreatureInstanceId::from(id::Id::from_random_bytes(self.rng.gen()))
+    }
+
+    /// Flips a coin to decide which player goes first, and makes it that player's turn.
+    /// Returns the `PlayerId` of the player going first.
+    pub fn flip_for_first_player(&mut self) -> PlayerId {
+        self.cur_player_turn = if self.rng.gen_bool(0.5) {
+            self.player_a_id
+        } else {
+            self.player_b_id
+        };
+
+        self.cur_player_turn
+    }
+
+    /// Picks one of the given options at random, such as a random target.
+    /// Returns `None` if there are no options.
+    pub fn choose_random<T: Copy>(&mut self, options: &[T]) -> Option<T> {
+        if options.is_empty() {
+            return None;
+        }
+
+        let index = self.rng.gen_range(0..options.len());
+        Some(options[index])
+    }
+
+    /// Shuffles the given player's deck.
+    pub fn shuffle_deck(&mut self, player_id: PlayerId) {
+        let rng = &mut self.rng;
+
+        self.entity_arena
+            .of_type_mut::<DeckEntity>()
+            .find(|d| d.get(|d| d.player_id() == player_id))
+            .unwrap()
+            .get_mut(|d| d.shuffle(rng));
     }
 
+    #[must_use]
     pub fn board(&self) -> Board<&GameState> {
         Board::new(self)
     }
@@ -53,10 +194,40 @@ impl GameState {
         Board::new(self)
     }
 
+    /// Recomputes the buffs granted by passive effects from the creatures currently on the board.
+    /// Every passive buff is removed first, so a buff ends as soon as its source leaves
+    /// or its condition stops holding.
     pub fn evaluate_passives(&mut self) {
-        // todo!()
+        let creature_ids: Vec<CreatureInstanceId> = [self.player_a_id, self.player_b_id]
+            .into_iter()
+            .flat_map(|player_id| self.board().player_creature_ids(player_id))
+            .collect();
+
+        let mut board = self.board_mut();
+        for instance_id in &creature_ids {
+            if let Some(mut creature) = board.creature_by_id_mut(*instance_id) {
+                creature.get_mut(CreatureInstance::remove_passive_buffs);
+            }
+        }
+
+        let passive_buffs: Vec<_> = creature_ids
+            .iter()
+            .filter_map(|instance_id| {
+                self.creature_definition_of(*instance_id)
+                    .map(|definition| definition.passive_buffs(*instance_id, self))
+            })
+            .flatten()
+            .collect();
+
+        let mut board = self.board_mut();
+        for (receiver_id, buff) in passive_buffs {
+            if let Some(mut receiver) = board.creature_by_id_mut(receiver_id) {
+                receiver.get_mut(|c| c.add_buff(buff));
+            }
+        }
     }
 
+    #[must_use]
     pub fn deck(&self, player_id: PlayerId) -> TypedEntity<DeckEntity, &Value> {
         self.entity_arena
             .of_type::<DeckEntity>()
@@ -71,6 +242,7 @@ impl GameState {
             .unwrap()
     }
 
+    #[must_use]
     pub fn hand(&self, player_id: PlayerId) -> Hand<&GameState> {
         Hand::new(self, player_id)
     }
@@ -79,10 +251,211 @@ impl GameState {
         Hand::new(self, player_id)
     }
 
+    #[must_use]
+    pub fn rules(&self) -> &GameRules {
+        &self.rules
+    }
+
+    /// Replaces the definitions this game's cards are resolved against,
+    /// typically with the registry of every card from the `cards` crate.
+    pub fn set_card_registry(&mut self, card_registry: CardRegistry) {
+        self.card_registry = card_registry;
+    }
+
+    #[must_use]
+    pub fn card_registry(&self) -> &CardRegistry {
+        &self.card_registry
+    }
+
+    /// Makes a `CreatureDefinition` known to the game, so cards referring to it by id can be resolved.
+    pub fn add_creature_definition(&mut self, definition: CreatureDefinition) {
+        self.card_registry.register(definition);
+    }
+
+    #[must_use]
+    pub fn creature_definition(&self, id: CreatureDefinitionId) -> Option<&CreatureDefinition> {
+        self.card_registry.get(id)
+    }
+
+    /// Makes the hero power known to the game and gives it to the player's hero,
+    /// replacing any hero power the hero had.
+    pub fn set_hero_power(&mut self, player_id: PlayerId, hero_power: HeroPowerDefinition) {
+        let hero_power_id = hero_power.hero_power_id();
+
+        if self.card_registry.hero_power(hero_power_id).is_none() {
+            self.card_registry.register_hero_power(hero_power);
+        }
+
+        self.board_mut()
+            .player_hero_mut(player_id)
+            .get_mut(|h| h.set_hero_power(hero_power_id));
+    }
+
+    /// The definition of the player's hero power, if their hero has one.
+    #[must_use]
+    pub fn hero_power_of(&self, player_id: PlayerId) -> Option<&HeroPowerDefinition> {
+        let hero_power_id = self
+            .board()
+            .player_hero(player_id)
+            .get(|h| h.hero_power())?;
+
+        self.card_registry.hero_power(hero_power_id)
+    }
+
+    /// Checks every card of a player's deck against the registry and the match's legality set,
+    /// then adds them all to the player's deck and shuffles it.
+    /// If any card is unknown or illegal, the whole deck is rejected and nothing is added.
+    pub fn submit_deck(
+        &mut self,
+        player_id: PlayerId,
+        deck: &[CreatureDefinitionId],
+    ) -> Result<(), RuleViolation> {
+        for &definition_id in deck {
+            let definition = self.creature_definition(definition_id).ok_or(
+                RuleViolation::UnknownDefinition {
+                    definition_id: definition_id.id,
+                },
+            )?;
+
+            if !self.rules.legality.is_definition_legal(definition) {
+                return Err(RuleViolation::IllegalCard {
+                    definition_id: definition_id.id,
+                    title: definition.title().to_string(),
+                });
+            }
+        }
+
+        self.deck_mut(player_id).get_mut(|d| {
+            for &definition_id in deck {
+                d.add_card(CardInDeck::new(definition_id));
+            }
+        });
+        self.shuffle_deck(player_id);
+
+        Ok(())
+    }
+
+    /// The definition of the creature on the board with the given id,
+    /// if the creature is on the board and its definition is known.
+    #[must_use]
+    pub fn creature_definition_of(
+        &self,
+        instance_id: CreatureInstanceId,
+    ) -> Option<&CreatureDefinition> {
+        let definition_id = self
+            .board()
+            .creature_by_id(instance_id)?
+            .get(CreatureInstance::definition_id);
+
+        self.creature_definition(definition_id)
+    }
+
+    /// The view of a creature on the board that the given player is allowed to see.
+    /// The opponent of a hidden creature's owner only sees a face-down placeholder.
+    #[must_use]
+    pub fn creature_view(
+        &self,
+        instance_id: CreatureInstanceId,
+        viewer: PlayerId,
+    ) -> Option<protocol::entities::CreatureInstance> {
+        let definition = self.creature_definition_of(instance_id)?;
+        let board = self.board();
+        let owner = board.creature_pos(instance_id)?.player_id;
+        let creature = board.creature_by_id(instance_id)?;
+
+        Some(creature.get(|c| {
+            if c.is_hidden() && viewer != owner {
+                protocol::entities::CreatureInstance::hidden(
+                    instance_id,
+                    definition.hidden_cost_bucket(),
+                    c.width(),
+                )
+            } else {
+                c.view(definition)
+            }
+        }))
+    }
+
+    /// A back row slot is defended while a defender occupies the front row slot in front of it.
+    /// Defended slots cannot be attacked.
+    #[must_use]
+    pub fn is_pos_defended(&self, pos: BoardPos) -> bool {
+        if !pos.row_id.is_back() {
+            return false;
+        }
+
+        let in_front = BoardPos::new(pos.player_id, RowId::FrontRow, pos.row_index);
+
+        self.board()
+            .creature_at_pos(in_front)
+            .is_some_and(|c| c.get(|c| c.has_keyword(Keyword::Defender)))
+    }
+
+    /// Checks the targeting rules for an attack: the target must be an enemy hero or creature,
+    /// and no slot it occupies may be defended.
+    /// Whether the attacker can attack this turn is not checked here.
+    pub fn validate_attack_target(
+        &self,
+        attacker: CreatureInstanceId,
+        target: CreatureInstanceId,
+    ) -> Result<(), RuleViolation> {
+        let board = self.board();
+
+        let attacker_player_id = board
+            .creature_pos(attacker)
+            .ok_or(RuleViolation::NotOnBoard {
+                creature_id: attacker,
+            })?
+            .player_id;
+
+        let target_player_id = if let Some(player_id) = board.hero_player_id(target) {
+            player_id
+        } else {
+            let target_slots = board.slots_with_creature(target);
+
+            if target_slots.is_empty() {
+                return Err(RuleViolation::NotOnBoard {
+                    creature_id: target,
+                });
+            }
+
+            if let Some(defended) = target_slots.iter().find(|pos| self.is_pos_defended(**pos)) {
+                return Err(RuleViolation::TargetDefended { pos: *defended });
+            }
+
+            target_slots[0].player_id
+        };
+
+        if target_player_id == attacker_player_id {
+            return Err(RuleViolation::CannotAttackOwnSide);
+        }
+
+        Ok(())
+    }
+
+    /// Every hero and creature the attacker may target under the targeting rules,
+    /// so clients can highlight them: the enemy hero first, then the enemy creatures in board order.
+    /// Empty if the attacker is not on the board.
+    #[must_use]
+    pub fn legal_attack_targets(&self, attacker: CreatureInstanceId) -> Vec<CreatureInstanceId> {
+        let board = self.board();
+        let Some(attacker_pos) = board.creature_pos(attacker) else {
+            return Vec::new();
+        };
+        let enemy = self.other_player(attacker_pos.player_id);
+
+        std::iter::once(board.player_hero(enemy).get(HeroInstance::instance_id))
+            .chain(board.player_creature_ids(enemy))
+            .filter(|target| self.validate_attack_target(attacker, *target).is_ok())
+            .collect()
+    }
+
+    #[must_use]
     pub fn player_a_id(&self) -> PlayerId {
         self.player_a_id
     }
 
+    #[must_use]
     pub fn player_b_id(&self) -> PlayerId {
         self.player_b_id
     }
@@ -105,16 +478,46 @@ impl GameState {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use crate::{
         game_state::{
             card_in_deck_entity::CardInDeck, card_in_hand_entity::CardInHand, deck::DeckEntity,
+            rules::GameRules,
+        },
+        v2::{
+            actions::PassiveEffect, builder::CreatureDefinitionBuilder, Buff, BuffBuilder,
+            CreatureDefinitionId, CreatureInstance,
         },
-        v2::CreatureDefinitionId,
     };
 
     use super::GameState;
-    use protocol::entities::PlayerId;
+    use protocol::{
+        entities::{BoardPos, CreatureInstanceId, Keyword, PlayerId, RowId},
+        rule_violation::RuleViolation,
+    };
+
+    /// Registers the definition built by `builder` and sets a new instance of it at `pos`.
+    pub(crate) fn place_creature(
+        game_state: &mut GameState,
+        builder: &CreatureDefinitionBuilder,
+        pos: BoardPos,
+    ) -> CreatureInstanceId {
+        let definition = builder.build();
+        let creature =
+            CreatureInstance::new_from_definition(CreatureInstanceId::new(), &definition);
+        let instance_id = creature.instance_id();
+
+        // Builders are reused to place several creatures of the same definition.
+        if game_state
+            .creature_definition(definition.definition_id())
+            .is_none()
+        {
+            game_state.add_creature_definition(definition);
+        }
+        game_state.board_mut().set_creature_at_pos(creature, pos);
+
+        instance_id
+    }
 
     #[test]
     fn game_state_new_expects_can_get_deck() {
@@ -217,4 +620,357 @@ mod tests {
             assert_eq!(100, hero.get(|h| h.mana()));
         }
     }
+
+    #[test]
+    fn game_state_set_next_player_turn_expects_turn_alternates() {
+        let player_a = PlayerId::new();
+        let player_b = PlayerId::new();
+
+        let mut game_state = GameState::new(player_a, player_b);
+
+        assert_eq!(player_a, game_state.cur_player_turn());
+        assert_eq!(player_b, game_state.set_next_player_turn());
+        assert_eq!(player_b, game_state.cur_player_turn());
+        assert_eq!(player_a, game_state.set_next_player_turn());
+        assert_eq!(player_a, game_state.cur_player_turn());
+    }
+
+    #[test]
+    fn game_state_when_hero_has_no_health_expects_game_over_with_winner() {
+        let player_a = PlayerId::new();
+        let player_b = PlayerId::new();
+
+        let mut game_state = GameState::new(player_a, player_b);
+
+        assert!(!game_state.is_game_over());
+        assert_eq!(None, game_state.winner());
+
+        game_state
+            .board_mut()
+            .player_hero_mut(player_a)
+            .get_mut(|h| h.set_health(0));
+
+        assert!(game_state.is_game_over());
+        assert_eq!(Some(player_b), game_state.winner());
+    }
+
+    #[test]
+    fn game_state_when_both_heroes_have_no_health_expects_draw() {
+        let player_a = PlayerId::new();
+        let player_b = PlayerId::new();
+
+        let mut game_state = GameState::new(player_a, player_b);
+
+        for player_id in [player_a, player_b] {
+            game_state
+                .board_mut()
+                .player_hero_mut(player_id)
+                .get_mut(|h| h.set_health(-2));
+        }
+
+        assert!(game_state.is_game_over());
+        assert_eq!(None, game_state.winner());
+    }
+
+    fn shuffled_deck_order(seed: u64) -> Vec<CreatureDefinitionId> {
+        let player_a = PlayerId::new();
+        let player_b = PlayerId::new();
+
+        let mut game_state =
+            GameState::new_with_seed(player_a, player_b, GameRules::default(), seed);
+
+        let definition_ids: Vec<_> = (0..20)
+            .map(|i| CreatureDefinitionId::parse_str(&format!("00000000-0000-0000-0000-{i:012}")))
+            .collect();
+
+        game_state.deck_mut(player_a).get_mut(|d| {
+            for id in &definition_ids {
+                d.add_card(CardInDeck::new(*id));
+            }
+        });
+
+        game_state.shuffle_deck(player_a);
+
+        let mut order = Vec::new();
+        game_state.deck_mut(player_a).get_mut(|d| {
+            while let Some(card) = d.draw() {
+                order.push(card.definition_id());
+            }
+        });
+
+        order
+    }
+
+    #[test]
+    fn game_state_new_with_seed_expects_seed_recorded() {
+        let game_state =
+            GameState::new_with_seed(PlayerId::new(), PlayerId::new(), GameRules::default(), 42);
+
+        assert_eq!(42, game_state.seed());
+    }
+
+    /// A game whose legality set blocks one of two registered cards.
+    fn game_with_blocked_card() -> (GameState, CreatureDefinitionId, CreatureDefinitionId) {
+        let legal = CreatureDefinitionBuilder::new()
+            .title("Legal".to_string())
+            .build();
+        let blocked = CreatureDefinitionBuilder::new()
+            .title("Blocked".to_string())
+            .build();
+        let (legal_id, blocked_id) = (legal.definition_id(), blocked.definition_id());
+
+        let mut rules = GameRules::default();
+        rules.legality.block(blocked.content_hash());
+
+        let mut game_state = GameState::new_with_rules(PlayerId::new(), PlayerId::new(), rules);
+        game_state.add_creature_definition(legal);
+        game_state.add_creature_definition(blocked);
+
+        (game_state, legal_id, blocked_id)
+    }
+
+    #[test]
+    fn submit_deck_when_all_legal_expects_cards_in_deck() {
+        let (mut game_state, legal_id, _) = game_with_blocked_card();
+        let player_a = game_state.player_a_id();
+
+        assert_eq!(
+            Ok(()),
+            game_state.submit_deck(player_a, &[legal_id, legal_id])
+        );
+
+        assert_eq!(2, game_state.deck(player_a).get(DeckEntity::len));
+    }
+
+    #[test]
+    fn submit_deck_when_card_blocked_expects_deck_rejected() {
+        let (mut game_state, legal_id, blocked_id) = game_with_blocked_card();
+        let player_a = game_state.player_a_id();
+
+        assert_eq!(
+            Err(RuleViolation::IllegalCard {
+                definition_id: blocked_id.id,
+                title: "Blocked".to_string(),
+            }),
+            game_state.submit_deck(player_a, &[legal_id, blocked_id])
+        );
+        assert_eq!(0, game_state.deck(player_a).get(DeckEntity::len));
+    }
+
+    #[test]
+    fn submit_deck_when_card_unknown_expects_deck_rejected() {
+        let (mut game_state, legal_id, _) = game_with_blocked_card();
+        let player_a = game_state.player_a_id();
+        let unknown_id = CreatureDefinitionId::new();
+
+        assert_eq!(
+            Err(RuleViolation::UnknownDefinition {
+                definition_id: unknown_id.id,
+            }),
+            game_state.submit_deck(player_a, &[legal_id, unknown_id])
+        );
+    }
+
+    #[test]
+    fn game_state_shuffle_deck_when_same_seed_expects_same_order() {
+        assert_eq!(shuffled_deck_order(7), shuffled_deck_order(7));
+        assert_ne!(shuffled_deck_order(7), shuffled_deck_order(8));
+    }
+
+    #[test]
+    fn game_state_flip_for_first_player_when_same_seed_expects_same_player() {
+        let player_a = PlayerId::new();
+        let player_b = PlayerId::new();
+
+        let mut firsts = Vec::new();
+
+        for seed in 0..20 {
+            let mut game_state =
+                GameState::new_with_seed(player_a, player_b, GameRules::default(), seed);
+            let mut replayed =
+                GameState::new_with_seed(player_a, player_b, GameRules::default(), seed);
+
+            let first = game_state.flip_for_first_player();
+
+            assert_eq!(first, game_state.cur_player_turn());
+            assert_eq!(first, replayed.flip_for_first_player());
+
+            firsts.push(first);
+        }
+
+        assert!(firsts.contains(&player_a));
+        assert!(firsts.contains(&player_b));
+    }
+
+    #[test]
+    fn game_state_choose_random_expects_one_of_options() {
+        let mut game_state =
+            GameState::new_with_seed(PlayerId::new(), PlayerId::new(), GameRules::default(), 3);
+
+        assert_eq!(None, game_state.choose_random::<u8>(&[]));
+
+        for _ in 0..10 {
+            let chosen = game_state.choose_random(&[1, 2, 3]).unwrap();
+            assert!([1, 2, 3].contains(&chosen));
+        }
+    }
+
+    /// Gives +1/+1 to every other creature on its owner's side.
+    struct RallyPassive;
+
+    impl PassiveEffect for RallyPassive {
+        fn buffs(
+            &self,
+            instance_id: CreatureInstanceId,
+            game_state: &GameState,
+        ) -> Vec<(CreatureInstanceId, Buff)> {
+            let owner = game_state
+                .board()
+                .creature_pos(instance_id)
+                .unwrap()
+                .player_id;
+
+            game_state
+                .board()
+                .player_creature_ids(owner)
+                .into_iter()
+                .filter(|id| *id != instance_id)
+                .map(|id| {
+                    let buff = BuffBuilder::new(instance_id)
+                        .attack(1)
+                        .health(1)
+                        .from_passive()
+                        .build();
+                    (id, buff)
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn evaluate_passives_expects_buffs_only_while_source_on_board() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+
+        let source = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().passive_effect(RallyPassive),
+            BoardPos::new(player_a, RowId::BackRow, 0),
+        );
+        let ally = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new().attack(2).health(2),
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        let stats = |game_state: &GameState| {
+            game_state
+                .board()
+                .creature_by_id(ally)
+                .unwrap()
+                .get(|c| (c.attack(), c.health()))
+        };
+
+        game_state.evaluate_passives();
+        game_state.evaluate_passives();
+        assert_eq!((3, 3), stats(&game_state));
+
+        game_state.board_mut().remove_creature(source);
+        game_state.evaluate_passives();
+        assert_eq!((2, 2), stats(&game_state));
+    }
+
+    #[test]
+    fn is_pos_defended_expects_defender_keyword_from_buffs() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_b = game_state.player_b_id();
+        let behind = BoardPos::new(player_b, RowId::BackRow, 2);
+
+        let blocker = place_creature(
+            &mut game_state,
+            &CreatureDefinitionBuilder::new(),
+            BoardPos::new(player_b, RowId::FrontRow, 2),
+        );
+        assert!(!game_state.is_pos_defended(behind));
+
+        game_state
+            .board_mut()
+            .creature_by_id_mut(blocker)
+            .unwrap()
+            .get_mut(|c| {
+                c.add_buff(
+                    BuffBuilder::new(blocker)
+                        .gain_keyword(Keyword::Defender)
+                        .build(),
+                );
+            });
+        assert!(game_state.is_pos_defended(behind));
+    }
+
+    #[test]
+    fn legal_attack_targets_expects_enemies_not_behind_defenders() {
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        let player_b = game_state.player_b_id();
+        let creature = CreatureDefinitionBuilder::new();
+        let mut wide = CreatureDefinitionBuilder::new();
+        wide.width(2);
+
+        let attacker = place_creature(
+            &mut game_state,
+            &creature,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        place_creature(
+            &mut game_state,
+            &creature,
+            BoardPos::new(player_a, RowId::FrontRow, 1),
+        );
+        let defender = place_creature(
+            &mut game_state,
+            CreatureDefinitionBuilder::new()
+                .width(2)
+                .keyword(Keyword::Defender),
+            BoardPos::new(player_b, RowId::FrontRow, 3),
+        );
+        let behind = place_creature(
+            &mut game_state,
+            &creature,
+            BoardPos::new(player_b, RowId::BackRow, 3),
+        );
+        let partly_behind = place_creature(
+            &mut game_state,
+            &wide,
+            BoardPos::new(player_b, RowId::BackRow, 4),
+        );
+        let open = place_creature(
+            &mut game_state,
+            &wide,
+            BoardPos::new(player_b, RowId::BackRow, 0),
+        );
+        let hero_b = game_state
+            .board()
+            .player_hero(player_b)
+            .get(|h| h.instance_id());
+
+        assert_eq!(
+            vec![hero_b, defender, open],
+            game_state.legal_attack_targets(attacker)
+        );
+        assert_eq!(
+            Err(RuleViolation::TargetDefended {
+                pos: BoardPos::new(player_b, RowId::BackRow, 4)
+            }),
+            game_state.validate_attack_target(attacker, partly_behind)
+        );
+
+        game_state.board_mut().remove_creature(defender);
+
+        assert_eq!(
+            vec![hero_b, open, behind, partly_behind],
+            game_state.legal_attack_targets(attacker)
+        );
+        assert!(game_state
+            .legal_attack_targets(CreatureInstanceId::new())
+            .is_empty());
+    }
 }
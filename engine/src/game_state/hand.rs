@@ -1,6 +1,6 @@
 use super::{card_in_hand_entity::CardInHand, game_state::GameState};
 use entity_arena::{id::EntityId, TypedEntity, Value};
-use protocol::entities::{EntityPosition, PlayerId};
+use protocol::entities::{CreatureInstanceId, EntityPosition, PlayerId};
 use std::borrow::{Borrow, BorrowMut};
 
 pub struct Hand<T>
@@ -28,25 +28,45 @@ where
         }
     }
 
+    /// The ids of the cards in the hand, ordered by their slot.
     pub fn entity_ids(&self) -> Vec<EntityId> {
-        self.game_state
+        let mut slotted: Vec<(u8, EntityId)> = self
+            .game_state
             .borrow()
             .positions_map()
             .iter()
-            .filter(|(k, _)| matches!(k, EntityPosition::Hand(p, _) if *p == self.player_id))
-            .map(|(_, v)| v)
-            .copied()
-            .collect()
+            .filter_map(|(k, v)| match k {
+                EntityPosition::Hand(p, slot) if *p == self.player_id => Some((*slot, *v)),
+                _ => None,
+            })
+            .collect();
+
+        slotted.sort_by_key(|(slot, _)| *slot);
+
+        slotted.into_iter().map(|(_, v)| v).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entity_ids().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
+    /// The cards in the hand, ordered by their slot.
     pub fn cards(&self) -> impl Iterator<Item = TypedEntity<CardInHand, &Value>> {
         let ids = self.entity_ids();
 
         let arena = self.game_state.borrow().entity_arena();
 
-        arena
-            .of_type::<CardInHand>()
-            .filter(move |c| ids.contains(&c.id()))
+        ids.into_iter()
+            .map(move |id| arena.get(id).as_typed::<CardInHand>())
+    }
+
+    /// The card in the hand with the given id, if there is one.
+    pub fn card(&self, card_id: CreatureInstanceId) -> Option<TypedEntity<CardInHand, &Value>> {
+        self.cards().find(|c| c.get(|c| c.card_id() == card_id))
     }
 }
 
@@ -54,11 +74,49 @@ impl<T> Hand<T>
 where
     T: BorrowMut<GameState>,
 {
-    pub fn add_card(&mut self, card: CardInHand) {
+    /// Adds the card to the next free slot at the end of the hand,
+    /// returning the position it was added at.
+    pub fn add_card(&mut self, card: CardInHand) -> EntityPosition {
+        let slot = u8::try_from(self.entity_ids().len()).expect("The hand has too many cards.");
+
         let game_state = self.game_state.borrow_mut();
         let entity_id = game_state.entity_arena_mut().add(card);
 
-        let entity_pos = EntityPosition::Hand(self.player_id, 0);
+        let entity_pos = EntityPosition::Hand(self.player_id, slot);
         game_state.positions_map_mut().insert(entity_pos, entity_id);
+
+        entity_pos
+    }
+
+    /// Removes the card with the given id from the hand and returns it.
+    /// The cards after it shift down a slot, so the hand stays contiguous.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hand has no card with the given id.
+    pub fn take_card(&mut self, card_id: CreatureInstanceId) -> CardInHand {
+        let player_id = self.player_id;
+        let (entity_id, card) = self
+            .card(card_id)
+            .map(|c| (c.id(), c.get(CardInHand::clone)))
+            .expect("The hand must contain the card being taken.");
+
+        let remaining = self.entity_ids();
+
+        let game_state = self.game_state.borrow_mut();
+        game_state
+            .positions_map_mut()
+            .retain(|k, _| !matches!(k, EntityPosition::Hand(p, _) if *p == player_id));
+
+        let remaining = remaining.into_iter().filter(|id| *id != entity_id);
+        for (slot, id) in (0u8..).zip(remaining) {
+            game_state
+                .positions_map_mut()
+                .insert(EntityPosition::Hand(player_id, slot), id);
+        }
+
+        game_state.entity_arena_mut().remove(entity_id);
+
+        card
     }
 }
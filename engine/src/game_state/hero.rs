@@ -1,22 +1,31 @@
+use crate::v2::HeroPowerId;
 use entity_arena::{id::EntityTypeId, IsEntity};
 use isentity_macro_derive::entity;
-use protocol::entities::PlayerId;
+use protocol::entities::{CreatureInstanceId, PlayerId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize)]
 #[entity("dab552df-8955-4b6a-88fc-a9af53e0ee43")]
 pub struct HeroInstance {
     player_id: PlayerId,
+    instance_id: CreatureInstanceId,
     health: i32,
-    mana: i32,
+    mana_limit: u32,
+    mana: u32,
+    hero_power: Option<HeroPowerId>,
+    hero_power_used: bool,
 }
 
 impl HeroInstance {
-    pub fn new(player_id: PlayerId) -> Self {
+    pub fn new(player_id: PlayerId, instance_id: CreatureInstanceId) -> Self {
         Self {
             player_id,
+            instance_id,
             health: 100,
+            mana_limit: 0,
             mana: 0,
+            hero_power: None,
+            hero_power_used: false,
         }
     }
 
@@ -24,15 +33,90 @@ impl HeroInstance {
         self.health
     }
 
-    pub fn mana(&self) -> i32 {
+    pub fn set_health(&mut self, val: i32) {
+        self.health = val;
+    }
+
+    pub fn take_damage(&mut self, amount: usize) {
+        self.health -= i32::try_from(amount).unwrap_or(i32::MAX);
+    }
+
+    /// The mana currently available to spend this turn.
+    pub fn mana(&self) -> u32 {
         self.mana
     }
 
-    pub fn set_mana(&mut self, val: i32) {
+    pub fn set_mana(&mut self, val: u32) {
         self.mana = val;
     }
 
+    /// The amount of mana the player's available mana refills to each turn.
+    pub fn mana_limit(&self) -> u32 {
+        self.mana_limit
+    }
+
+    /// The mana limit after raising it by `amount`, without exceeding `cap`.
+    /// A limit already above `cap` is left as is.
+    pub fn mana_limit_after_gain(&self, amount: u32, cap: u32) -> u32 {
+        if self.mana_limit < cap {
+            self.mana_limit.saturating_add(amount).min(cap)
+        } else {
+            self.mana_limit
+        }
+    }
+
+    /// Raises the mana limit by `amount`, without exceeding `cap`.
+    pub fn raise_mana_limit(&mut self, amount: u32, cap: u32) {
+        self.mana_limit = self.mana_limit_after_gain(amount, cap);
+    }
+
+    /// Refills the available mana up to the mana limit.
+    pub fn refresh_mana(&mut self) {
+        self.mana = self.mana_limit;
+    }
+
+    /// Spends `amount` of the available mana.
+    ///
+    /// # Panics
+    ///
+    /// Panics if less than `amount` mana is available.
+    /// Events spending mana must validate this first.
+    pub fn spend_mana(&mut self, amount: u32) {
+        self.mana = self
+            .mana
+            .checked_sub(amount)
+            .expect("Player does not have enough mana.");
+    }
+
+    /// The hero's power, which is looked up in the `CardRegistry`.
+    pub fn hero_power(&self) -> Option<HeroPowerId> {
+        self.hero_power
+    }
+
+    pub fn set_hero_power(&mut self, hero_power: HeroPowerId) {
+        self.hero_power = Some(hero_power);
+    }
+
+    /// Whether the hero power has been used this turn. It can only be used once per turn.
+    pub fn has_used_hero_power(&self) -> bool {
+        self.hero_power_used
+    }
+
+    pub fn set_used_hero_power(&mut self) {
+        self.hero_power_used = true;
+    }
+
+    /// Makes the hero power usable again, at the start of the player's turn.
+    pub fn ready_hero_power(&mut self) {
+        self.hero_power_used = false;
+    }
+
     pub fn player_id(&self) -> PlayerId {
         self.player_id
     }
+
+    /// The id clients use to target this hero, such as when attacking it.
+    pub fn instance_id(&self) -> CreatureInstanceId {
+        self.instance_id
+    }
 }
@@ -5,3 +5,9 @@ mod deck;
 pub mod game_state;
 mod hand;
 mod hero;
+mod rules;
+
+pub use board::ROW_LENGTH;
+pub use card_in_deck_entity::CardInDeck;
+pub use card_in_hand_entity::CardInHand;
+pub use rules::{FatigueRule, GameRules};
@@ -0,0 +1,68 @@
+use crate::v2::LegalitySet;
+
+/// Describes how much damage a hero takes when their player
+/// must draw a card from an empty deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FatigueRule {
+    /// Drawing from an empty deck does nothing.
+    NoDamage,
+
+    /// Every draw from an empty deck deals the same amount of damage.
+    Fixed(usize),
+
+    /// The first draw from an empty deck deals 1 damage, the second deals 2, and so on.
+    Incrementing,
+}
+
+impl FatigueRule {
+    /// The damage dealt for the given draw from an empty deck,
+    /// where `empty_draw_count` is 1 for the first such draw.
+    #[must_use]
+    pub fn damage(&self, empty_draw_count: usize) -> usize {
+        match self {
+            FatigueRule::NoDamage => 0,
+            FatigueRule::Fixed(amount) => *amount,
+            FatigueRule::Incrementing => empty_draw_count,
+        }
+    }
+}
+
+/// The tunable rules a game is played under.
+#[derive(Debug, Clone)]
+pub struct GameRules {
+    pub fatigue: FatigueRule,
+
+    /// How much a player's mana limit grows at the start of each of their turns.
+    pub mana_limit_gain_per_turn: u32,
+
+    /// The mana limit never grows beyond this.
+    pub max_mana_limit: u32,
+
+    /// The cards players may put in their decks.
+    pub legality: LegalitySet,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            fatigue: FatigueRule::Fixed(1),
+            mana_limit_gain_per_turn: 1,
+            max_mana_limit: 10,
+            legality: LegalitySet::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FatigueRule;
+
+    #[test]
+    fn fatigue_rule_damage_expects_correct_amounts() {
+        assert_eq!(0, FatigueRule::NoDamage.damage(3));
+        assert_eq!(2, FatigueRule::Fixed(2).damage(1));
+        assert_eq!(2, FatigueRule::Fixed(2).damage(5));
+        assert_eq!(1, FatigueRule::Incrementing.damage(1));
+        assert_eq!(4, FatigueRule::Incrementing.damage(4));
+    }
+}
@@ -7,6 +7,8 @@
     clippy::cast_lossless,
     clippy::module_inception,
     clippy::missing_panics_doc,
+    clippy::missing_errors_doc,
+    clippy::struct_field_names,
     clippy::similar_names,
     clippy::cast_sign_loss,
     dead_code,
@@ -17,6 +19,6 @@ pub mod game_agent;
 pub mod game_logic;
 pub mod game_runner;
 pub mod game_state;
-mod v2;
+pub mod v2;
 
 // pub use game_logic::cards;
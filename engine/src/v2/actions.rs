@@ -0,0 +1,287 @@
+use super::Buff;
+use crate::{
+    game_logic::{event_dispatch::EventDispatcher, events::GameEvent},
+    game_state::game_state::GameState,
+};
+use async_trait::async_trait;
+use protocol::entities::{BoardPos, CreatureInstanceId, PlayerId};
+
+/// An action a creature performs when it is summoned from its owner's hand.
+/// It runs after the creature has been placed on the board at `pos`.
+#[async_trait]
+pub trait UponSummonAction: Send + Sync {
+    async fn action(
+        &self,
+        instance_id: CreatureInstanceId,
+        pos: BoardPos,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    );
+}
+
+impl std::fmt::Debug for dyn UponSummonAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{UponSummonAction}}")
+    }
+}
+
+/// An action a creature performs when it is destroyed.
+/// It runs after the creature has left the board; `pos` is where it last stood.
+#[async_trait]
+pub trait UponDeathAction: Send + Sync {
+    async fn action(
+        &self,
+        instance_id: CreatureInstanceId,
+        pos: BoardPos,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    );
+}
+
+impl std::fmt::Debug for dyn UponDeathAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{UponDeathAction}}")
+    }
+}
+
+/// An action a creature performs when another creature on its side is destroyed,
+/// while it has `Keyword::Vengeance`. It runs after the ally's own death action;
+/// `was_companion` is whether the ally stood across from it.
+#[async_trait]
+pub trait UponAllyDestroyedAction: Send + Sync {
+    async fn action(
+        &self,
+        instance_id: CreatureInstanceId,
+        ally_id: CreatureInstanceId,
+        was_companion: bool,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    );
+}
+
+impl std::fmt::Debug for dyn UponAllyDestroyedAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{UponAllyDestroyedAction}}")
+    }
+}
+
+/// An action a creature performs after one of its companions takes damage.
+/// It runs once the damage has been resolved, so a companion that died has already left the board,
+/// unless the damage was simultaneous and its death is still held back.
+#[async_trait]
+pub trait UponCompanionDamagedAction: Send + Sync {
+    async fn action(
+        &self,
+        instance_id: CreatureInstanceId,
+        companion_id: CreatureInstanceId,
+        damage_amount: usize,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    );
+}
+
+impl std::fmt::Debug for dyn UponCompanionDamagedAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{UponCompanionDamagedAction}}")
+    }
+}
+
+/// The effect of a spell, run when its owner casts it from their hand.
+/// The spell has already left the hand and been paid for; any targets are asked for through the caster's `Prompter`.
+#[async_trait]
+pub trait UponCastAction: Send + Sync {
+    async fn action(
+        &self,
+        card_id: CreatureInstanceId,
+        player_id: PlayerId,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    );
+}
+
+impl std::fmt::Debug for dyn UponCastAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{UponCastAction}}")
+    }
+}
+
+/// The effect of a hero power, run when its player uses it.
+/// The power has already been paid for and marked as used this turn.
+#[async_trait]
+pub trait UponHeroPowerAction: Send + Sync {
+    async fn action(
+        &self,
+        player_id: PlayerId,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    );
+}
+
+impl std::fmt::Debug for dyn UponHeroPowerAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{UponHeroPowerAction}}")
+    }
+}
+
+/// Decides whether a player may summon a card right now, such as only while they control a certain creature.
+/// It is checked when the summon is validated, so a card whose condition is not met stays in the hand.
+/// Hero powers use the same check before they are paid for.
+pub trait SummonCondition: Send + Sync {
+    fn is_met(&self, player_id: PlayerId, game_state: &GameState) -> bool;
+}
+
+impl std::fmt::Debug for dyn SummonCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{SummonCondition}}")
+    }
+}
+
+/// Decides whether a creature on the board reacts to an event, and how.
+/// It only inspects the event and the game state; the returned action is what changes them.
+pub trait EventTrigger: Send + Sync {
+    fn trigger(
+        &self,
+        instance_id: CreatureInstanceId,
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>>;
+}
+
+impl std::fmt::Debug for dyn EventTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{EventTrigger}}")
+    }
+}
+
+/// An action a creature runs in response to an event, as chosen by its `EventTrigger`.
+/// It may dispatch more events.
+#[async_trait]
+pub trait UponEventAction: Send + Sync {
+    async fn action(
+        &self,
+        event: &GameEvent,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    );
+}
+
+/// A continuous effect a creature has while it is on the board, such as a buff to its companion.
+/// Passives are recomputed around every event, so their buffs end as soon as they stop applying.
+pub trait PassiveEffect: Send + Sync {
+    /// The buffs the creature currently grants, each paired with the creature receiving it.
+    fn buffs(
+        &self,
+        instance_id: CreatureInstanceId,
+        game_state: &GameState,
+    ) -> Vec<(CreatureInstanceId, Buff)>;
+}
+
+impl std::fmt::Debug for dyn PassiveEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{PassiveEffect}}")
+    }
+}
+
+/// The default action for creatures with no special behavior.
+pub struct DoNothingAction;
+
+#[async_trait]
+impl UponSummonAction for DoNothingAction {
+    async fn action(
+        &self,
+        _instance_id: CreatureInstanceId,
+        _pos: BoardPos,
+        _game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+    }
+}
+
+#[async_trait]
+impl UponDeathAction for DoNothingAction {
+    async fn action(
+        &self,
+        _instance_id: CreatureInstanceId,
+        _pos: BoardPos,
+        _game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+    }
+}
+
+#[async_trait]
+impl UponAllyDestroyedAction for DoNothingAction {
+    async fn action(
+        &self,
+        _instance_id: CreatureInstanceId,
+        _ally_id: CreatureInstanceId,
+        _was_companion: bool,
+        _game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+    }
+}
+
+#[async_trait]
+impl UponCompanionDamagedAction for DoNothingAction {
+    async fn action(
+        &self,
+        _instance_id: CreatureInstanceId,
+        _companion_id: CreatureInstanceId,
+        _damage_amount: usize,
+        _game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+    }
+}
+
+#[async_trait]
+impl UponCastAction for DoNothingAction {
+    async fn action(
+        &self,
+        _card_id: CreatureInstanceId,
+        _player_id: PlayerId,
+        _game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+    }
+}
+
+#[async_trait]
+impl UponHeroPowerAction for DoNothingAction {
+    async fn action(
+        &self,
+        _player_id: PlayerId,
+        _game_state: &mut GameState,
+        _dispatcher: &mut EventDispatcher,
+    ) {
+    }
+}
+
+/// With no condition, a card can always be summoned.
+impl SummonCondition for DoNothingAction {
+    fn is_met(&self, _player_id: PlayerId, _game_state: &GameState) -> bool {
+        true
+    }
+}
+
+impl EventTrigger for DoNothingAction {
+    fn trigger(
+        &self,
+        _instance_id: CreatureInstanceId,
+        _event: &GameEvent,
+        _game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>> {
+        None
+    }
+}
+
+impl PassiveEffect for DoNothingAction {
+    fn buffs(
+        &self,
+        _instance_id: CreatureInstanceId,
+        _game_state: &GameState,
+    ) -> Vec<(CreatureInstanceId, Buff)> {
+        Vec::new()
+    }
+}
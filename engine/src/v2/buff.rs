@@ -0,0 +1,118 @@
+use protocol::entities::{BuffInstanceId, BuffSourceId, Keyword};
+use serde::{Deserialize, Serialize};
+
+/// A change to a creature instance's attack, health and keywords, granted by a card effect or a passive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Buff {
+    instance_id: BuffInstanceId,
+    source_id: BuffSourceId,
+    attack_amount: i32,
+    health_amount: i32,
+    gained_keywords: Vec<Keyword>,
+    lost_keywords: Vec<Keyword>,
+    is_from_passive: bool,
+}
+
+impl Buff {
+    #[must_use]
+    pub fn instance_id(&self) -> BuffInstanceId {
+        self.instance_id
+    }
+
+    #[must_use]
+    pub fn source_id(&self) -> BuffSourceId {
+        self.source_id
+    }
+
+    #[must_use]
+    pub fn attack_amount(&self) -> i32 {
+        self.attack_amount
+    }
+
+    #[must_use]
+    pub fn health_amount(&self) -> i32 {
+        self.health_amount
+    }
+
+    #[must_use]
+    pub fn gained_keywords(&self) -> &[Keyword] {
+        &self.gained_keywords
+    }
+
+    #[must_use]
+    pub fn lost_keywords(&self) -> &[Keyword] {
+        &self.lost_keywords
+    }
+
+    /// Passive buffs only last while their source's passive effect applies,
+    /// and are recomputed by `GameState::evaluate_passives`.
+    #[must_use]
+    pub fn is_from_passive(&self) -> bool {
+        self.is_from_passive
+    }
+}
+
+pub struct BuffBuilder {
+    source_id: BuffSourceId,
+    attack_amount: i32,
+    health_amount: i32,
+    gained_keywords: Vec<Keyword>,
+    lost_keywords: Vec<Keyword>,
+    is_from_passive: bool,
+}
+
+impl BuffBuilder {
+    pub fn new(source: impl Into<BuffSourceId>) -> Self {
+        Self {
+            source_id: source.into(),
+            attack_amount: 0,
+            health_amount: 0,
+            gained_keywords: Vec::new(),
+            lost_keywords: Vec::new(),
+            is_from_passive: false,
+        }
+    }
+
+    #[must_use]
+    pub fn attack(mut self, attack_amount: i32) -> Self {
+        self.attack_amount = attack_amount;
+        self
+    }
+
+    #[must_use]
+    pub fn health(mut self, health_amount: i32) -> Self {
+        self.health_amount = health_amount;
+        self
+    }
+
+    #[must_use]
+    pub fn gain_keyword(mut self, keyword: Keyword) -> Self {
+        self.gained_keywords.push(keyword);
+        self
+    }
+
+    #[must_use]
+    pub fn lose_keyword(mut self, keyword: Keyword) -> Self {
+        self.lost_keywords.push(keyword);
+        self
+    }
+
+    #[must_use]
+    pub fn from_passive(mut self) -> Self {
+        self.is_from_passive = true;
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Buff {
+        Buff {
+            instance_id: BuffInstanceId::new(),
+            source_id: self.source_id,
+            attack_amount: self.attack_amount,
+            health_amount: self.health_amount,
+            gained_keywords: self.gained_keywords,
+            lost_keywords: self.lost_keywords,
+            is_from_passive: self.is_from_passive,
+        }
+    }
+}
@@ -0,0 +1,429 @@
+//! Creature definitions written as data, in RON or JSON files, so cards can be added and tweaked
+//! without recompiling.
+//!
+//! A file holds a list of creatures. Their behaviour is limited to the primitives in `templates`:
+//!
+//! ```ron
+//! [
+//!     (
+//!         id: "0b6c9d1e-3f4a-4b5c-8d7e-9f0a1b2c3d4e",
+//!         title: "Lookout",
+//!         cost: 2,
+//!         attack: 1,
+//!         health: 3,
+//!         placeable_at: Back,
+//!         keywords: [Defender],
+//!         tribes: [Teacher],
+//!         triggers: [(when: StartOfTurn, effects: [DrawCards(count: 1)])],
+//!     ),
+//! ]
+//! ```
+
+use super::{
+    builder::CreatureDefinitionBuilder,
+    templates::{Effect, SummonRequirement, TemplateBehaviour, TribeBuff, TriggerTemplate},
+    CreatureDefinition, CreatureDefinitionId,
+};
+use crate::game_state::ROW_LENGTH;
+use protocol::entities::{Keyword, Position, Tribe};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// One creature, as written in a definition file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreatureDefinitionData {
+    #[serde(with = "id_as_str")]
+    pub id: CreatureDefinitionId,
+    pub title: String,
+    pub cost: i32,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub flavor_text: String,
+    pub attack: i32,
+    pub health: i32,
+    #[serde(default = "default_width")]
+    pub width: usize,
+    #[serde(default = "default_placeable_at")]
+    pub placeable_at: Position,
+    #[serde(default)]
+    pub keywords: Vec<Keyword>,
+    #[serde(default)]
+    pub tribes: Vec<Tribe>,
+    #[serde(default)]
+    pub upon_summon: Vec<Effect>,
+    #[serde(default)]
+    pub triggers: Vec<TriggerTemplate>,
+    #[serde(default)]
+    pub summon_condition: Option<SummonRequirement>,
+    #[serde(default)]
+    pub tribe_buffs: Vec<TribeBuff>,
+}
+
+/// Ids are written as plain uuid strings, rather than as the nested structs they serialize to.
+mod id_as_str {
+    use super::CreatureDefinitionId;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        id: &CreatureDefinitionId,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        id.id.serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CreatureDefinitionId, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        uuid::Uuid::parse_str(&id).map_err(D::Error::custom)?;
+
+        Ok(CreatureDefinitionId::parse_str(&id))
+    }
+}
+
+fn default_width() -> usize {
+    1
+}
+
+fn default_placeable_at() -> Position {
+    Position::Either
+}
+
+impl CreatureDefinitionData {
+    /// A builder with every field of the data set.
+    #[must_use]
+    pub fn builder(&self) -> CreatureDefinitionBuilder {
+        let mut builder = CreatureDefinitionBuilder::new();
+
+        builder
+            .definition_id(self.id)
+            .title(self.title.clone())
+            .cost(self.cost)
+            .text(self.text.clone())
+            .flavor_text(self.flavor_text.clone())
+            .attack(self.attack)
+            .health(self.health)
+            .width(self.width)
+            .placeable_at(self.placeable_at);
+
+        for keyword in &self.keywords {
+            builder.keyword(*keyword);
+        }
+
+        for tribe in &self.tribes {
+            builder.tribe(*tribe);
+        }
+
+        builder.templates(TemplateBehaviour {
+            upon_summon: self.upon_summon.clone(),
+            triggers: self.triggers.clone(),
+            summon_condition: self.summon_condition,
+            tribe_buffs: self.tribe_buffs.clone(),
+        });
+
+        builder
+    }
+
+    #[must_use]
+    pub fn build(&self) -> CreatureDefinition {
+        self.builder().build()
+    }
+
+    /// Checks the numbers a file may get wrong, which the board and rules assume are in range.
+    pub fn validate(&self) -> Result<(), DefinitionFileError> {
+        let out_of_range = |field, value: i64| DefinitionFileError::OutOfRange {
+            title: self.title.clone(),
+            field,
+            value,
+        };
+
+        if !(1..=ROW_LENGTH).contains(&self.width) {
+            return Err(out_of_range(
+                "width",
+                i64::try_from(self.width).unwrap_or(i64::MAX),
+            ));
+        }
+
+        if self.health <= 0 {
+            return Err(out_of_range("health", self.health.into()));
+        }
+
+        if self.cost < 0 {
+            return Err(out_of_range("cost", self.cost.into()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Why a definition file could not be loaded.
+#[derive(Debug, Error)]
+pub enum DefinitionFileError {
+    #[error("Could not read {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("{path:?} is neither a .ron nor a .json file.")]
+    UnknownFormat { path: PathBuf },
+    #[error("Invalid RON: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{title:?} has {field} {value}, which is out of range.")]
+    OutOfRange {
+        title: String,
+        field: &'static str,
+        value: i64,
+    },
+    #[error("{title:?} has the same id as {existing:?}.")]
+    DuplicateId { title: String, existing: String },
+}
+
+/// Parses a RON list of creatures.
+pub fn parse_ron(contents: &str) -> Result<Vec<CreatureDefinitionData>, DefinitionFileError> {
+    validated(ron::from_str(contents)?)
+}
+
+/// Parses a JSON array of creatures.
+pub fn parse_json(contents: &str) -> Result<Vec<CreatureDefinitionData>, DefinitionFileError> {
+    validated(serde_json::from_str(contents)?)
+}
+
+fn validated(
+    data: Vec<CreatureDefinitionData>,
+) -> Result<Vec<CreatureDefinitionData>, DefinitionFileError> {
+    data.iter().try_for_each(CreatureDefinitionData::validate)?;
+
+    Ok(data)
+}
+
+/// Reads a `.ron` or `.json` file of creatures, choosing the format by its extension.
+pub fn load_file(
+    path: impl AsRef<Path>,
+) -> Result<Vec<CreatureDefinitionData>, DefinitionFileError> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path).map_err(|source| DefinitionFileError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("ron") => parse_ron(&contents),
+        Some("json") => parse_json(&contents),
+        _ => Err(DefinitionFileError::UnknownFormat {
+            path: path.to_path_buf(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_file, parse_json, parse_ron, DefinitionFileError, Keyword};
+    use crate::{
+        game_logic::{
+            event_dispatch::tests::make_test_dispatcher,
+            events::{CreatureTakesDamageEvent, TurnStartEvent},
+        },
+        game_state::{game_state::GameState, CardInDeck},
+        v2::{
+            builder::CreatureDefinitionBuilder,
+            templates::{Effect, TriggerTemplate, When},
+            CreatureDefinitionId, CreatureInstance,
+        },
+    };
+    use protocol::entities::{BoardPos, PlayerId, Position, RowId, Tribe};
+
+    const LOOKOUT: &str = r#"[
+        (
+            id: "0b6c9d1e-3f4a-4b5c-8d7e-9f0a1b2c3d4e",
+            title: "Lookout",
+            cost: 2,
+            text: "Defender\nAt the start of\nyour turn, draw a card.",
+            attack: 1,
+            health: 3,
+            placeable_at: Back,
+            keywords: [Defender],
+            triggers: [(when: StartOfTurn, effects: [DrawCards(count: 1)])],
+        ),
+    ]"#;
+
+    #[test]
+    fn parse_ron_expects_fields_and_defaults() {
+        let data = parse_ron(LOOKOUT).unwrap();
+        let definition = data[0].build();
+
+        assert_eq!(
+            CreatureDefinitionId::parse_str("0b6c9d1e-3f4a-4b5c-8d7e-9f0a1b2c3d4e"),
+            definition.definition_id()
+        );
+        assert_eq!("Lookout", definition.title());
+        assert_eq!(
+            (2, 1, 3),
+            (definition.cost(), definition.attack(), definition.health())
+        );
+        assert_eq!(1, definition.width());
+        assert_eq!(Position::Back, definition.placeable_at());
+        assert_eq!("", definition.flavor_text());
+        assert!(definition.has_keyword(Keyword::Defender));
+        assert!(!definition.has_keyword(Keyword::Hidden));
+    }
+
+    #[test]
+    fn parse_json_expects_same_as_ron() {
+        let json = r#"[{
+            "id": "0b6c9d1e-3f4a-4b5c-8d7e-9f0a1b2c3d4e",
+            "title": "Lookout",
+            "cost": 2,
+            "text": "Defender\nAt the start of\nyour turn, draw a card.",
+            "attack": 1,
+            "health": 3,
+            "placeable_at": "Back",
+            "keywords": ["Defender"],
+            "triggers": [{"when": "StartOfTurn", "effects": [{"DrawCards": {"count": 1}}]}]
+        }]"#;
+
+        assert_eq!(parse_ron(LOOKOUT).unwrap(), parse_json(json).unwrap());
+    }
+
+    #[test]
+    fn parse_ron_when_unknown_field_expects_error() {
+        let ron = r#"[(id: "0b6c9d1e-3f4a-4b5c-8d7e-9f0a1b2c3d4e", title: "Typo", cost: 1,
+            attack: 1, health: 1, atack: 2)]"#;
+
+        assert!(matches!(parse_ron(ron), Err(DefinitionFileError::Ron(_))));
+    }
+
+    #[test]
+    fn parse_when_stat_out_of_range_expects_error() {
+        let with = |fields: &str| {
+            format!(
+                r#"[(id: "0b6c9d1e-3f4a-4b5c-8d7e-9f0a1b2c3d4e", title: "Bad", attack: 1, {fields})]"#
+            )
+        };
+
+        for (fields, field, value) in [
+            ("cost: 1, health: 1, width: 0", "width", 0),
+            ("cost: 1, health: 1, width: 7", "width", 7),
+            ("cost: 1, health: 0", "health", 0),
+            ("cost: -1, health: 1", "cost", -1),
+        ] {
+            match parse_ron(&with(fields)) {
+                Err(DefinitionFileError::OutOfRange {
+                    field: f, value: v, ..
+                }) => assert_eq!((field, value), (f, v)),
+                other => panic!("Expected {field} to be out of range, got {other:?}"),
+            }
+        }
+
+        assert!(parse_ron(&with("cost: 0, health: 1, width: 6")).is_ok());
+    }
+
+    #[test]
+    fn load_file_expects_format_chosen_by_extension() {
+        let dir = std::env::temp_dir();
+        let ron_path = dir.join(format!("{}.ron", uuid::Uuid::new_v4()));
+        let txt_path = dir.join(format!("{}.txt", uuid::Uuid::new_v4()));
+        std::fs::write(&ron_path, LOOKOUT).unwrap();
+        std::fs::write(&txt_path, LOOKOUT).unwrap();
+
+        let loaded = load_file(&ron_path);
+        let unknown = load_file(&txt_path);
+        std::fs::remove_file(&ron_path).unwrap();
+        std::fs::remove_file(&txt_path).unwrap();
+
+        assert_eq!(vec![Keyword::Defender], loaded.unwrap()[0].keywords);
+        assert!(matches!(
+            unknown,
+            Err(DefinitionFileError::UnknownFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn triggers_expect_effects_run_on_their_events() {
+        let mut data = parse_ron(LOOKOUT).unwrap().remove(0);
+        data.triggers[0].effects.push(Effect::GainStats {
+            attack: 1,
+            health: 0,
+        });
+        data.triggers.push(TriggerTemplate {
+            when: When::AfterDamaged,
+            effects: vec![Effect::HealSelf { amount: 1 }],
+        });
+
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        for _ in 0..2 {
+            game_state
+                .deck_mut(player_a)
+                .get_mut(|d| d.add_card(CardInDeck::new(CreatureDefinitionId::new())));
+        }
+        let lookout = crate::game_state::game_state::tests::place_creature(
+            &mut game_state,
+            &data.builder(),
+            BoardPos::new(player_a, RowId::BackRow, 0),
+        );
+        let stats = |game_state: &GameState| {
+            game_state
+                .board()
+                .creature_by_id(lookout)
+                .unwrap()
+                .get(|c: &CreatureInstance| (c.attack(), c.health()))
+        };
+        let mut dispatcher = make_test_dispatcher(&game_state);
+
+        let hand_size = game_state.hand(player_a).len();
+        smol::block_on(dispatcher.dispatch(TurnStartEvent(player_a), &mut game_state)).unwrap();
+
+        // One card from the turn's own draw, and one from the trigger.
+        assert_eq!(hand_size + 2, game_state.hand(player_a).len());
+        assert_eq!((2, 3), stats(&game_state));
+
+        smol::block_on(
+            dispatcher.dispatch(CreatureTakesDamageEvent::new(lookout, 2), &mut game_state),
+        )
+        .unwrap();
+
+        assert_eq!((2, 2), stats(&game_state));
+    }
+
+    #[test]
+    fn tribe_templates_expect_summon_condition_and_buff_for_tribe() {
+        let ron = r#"[(id: "5d0e7c4a-1b2f-4e3d-9a8c-7b6f5e4d3c2b", title: "Cat Herder", cost: 2,
+            attack: 1, health: 1, tribes: [Teacher], summon_condition: Some(ControlsTribe(Cat)),
+            tribe_buffs: [(tribe: Cat, attack: 1, health: 0)])]"#;
+        let data = parse_ron(ron).unwrap().remove(0);
+        let herder = data.build();
+        assert!(herder.has_tribe(Tribe::Teacher));
+
+        let mut game_state = GameState::new(PlayerId::new(), PlayerId::new());
+        let player_a = game_state.player_a_id();
+        assert!(!herder.summon_condition_met(player_a, &game_state));
+
+        let mut cat = CreatureDefinitionBuilder::new();
+        cat.attack(2).health(2).tribe(Tribe::Cat);
+        let cat = crate::game_state::game_state::tests::place_creature(
+            &mut game_state,
+            &cat,
+            BoardPos::new(player_a, RowId::FrontRow, 0),
+        );
+        assert!(herder.summon_condition_met(player_a, &game_state));
+
+        crate::game_state::game_state::tests::place_creature(
+            &mut game_state,
+            &data.builder(),
+            BoardPos::new(player_a, RowId::BackRow, 3),
+        );
+        game_state.evaluate_passives();
+
+        let cat_attack = game_state
+            .board()
+            .creature_by_id(cat)
+            .unwrap()
+            .get(|c: &CreatureInstance| c.attack());
+        assert_eq!(3, cat_attack);
+    }
+}
@@ -0,0 +1,216 @@
+//! A stable content hash ("thumbprint") of each creature definition, so players can agree
+//! on exactly which cards are legal in a match, custom cards included.
+
+use super::{templates::TemplateBehaviour, CreatureDefinition};
+use protocol::entities::{CardKind, Keyword, Position, Tribe};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Write},
+    str::FromStr,
+};
+
+/// The SHA-256 of a definition's canonical serialization, written as lowercase hex.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DefinitionHash([u8; 32]);
+
+impl DefinitionHash {
+    #[must_use]
+    pub fn of(bytes: &[u8]) -> Self {
+        Self(Sha256::digest(bytes).into())
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for DefinitionHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&to_hex(&self.0))
+    }
+}
+
+impl fmt::Debug for DefinitionHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DefinitionHash({self})")
+    }
+}
+
+impl FromStr for DefinitionHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_hex(s).map(Self)
+    }
+}
+
+impl Serialize for DefinitionHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DefinitionHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+pub(super) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, b| {
+        let _ = write!(hex, "{b:02x}");
+        hex
+    })
+}
+
+/// Parses exactly `N` bytes written as `2 * N` hex digits.
+pub(super) fn from_hex<const N: usize>(s: &str) -> Result<[u8; N], String> {
+    if s.len() != N * 2 || !s.is_ascii() {
+        return Err(format!("Expected {} hex digits, found {s:?}.", N * 2));
+    }
+
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16)
+            .map_err(|e| format!("Invalid hex in {s:?}: {e}"))?;
+    }
+
+    Ok(bytes)
+}
+
+/// Every field that defines a creature, in a fixed order.
+/// Behaviour written in Rust cannot be serialized, so only template behaviour is covered.
+/// Changing a Rust card's code without changing any of these fields keeps its hash.
+/// Tribes and the kind are only written when a card has them,
+/// so cards without them serialize as they did before those fields existed.
+#[derive(Serialize)]
+struct CanonicalDefinition<'a> {
+    id: &'a id::Id,
+    title: &'a str,
+    cost: i32,
+    text: &'a str,
+    flavor_text: &'a str,
+    attack: i32,
+    health: i32,
+    width: usize,
+    placeable_at: Position,
+    keywords: &'a BTreeSet<Keyword>,
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    tribes: &'a BTreeSet<Tribe>,
+    #[serde(skip_serializing_if = "CardKind::is_creature")]
+    kind: CardKind,
+    templates: Option<&'a TemplateBehaviour>,
+}
+
+impl CreatureDefinition {
+    /// The canonical serialization of this definition: compact JSON with fields in a fixed order.
+    /// Two definitions have the same canonical bytes exactly when they describe the same card.
+    #[must_use]
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let canonical = CanonicalDefinition {
+            id: &self.definition_id.id,
+            title: &self.title,
+            cost: self.base_cost,
+            text: &self.text,
+            flavor_text: &self.flavor_text,
+            attack: self.base_attack,
+            health: self.base_health,
+            width: self.width,
+            placeable_at: self.placeable_at,
+            keywords: &self.keywords,
+            tribes: &self.tribes,
+            kind: self.kind,
+            templates: self.templates.as_ref(),
+        };
+
+        serde_json::to_vec(&canonical).expect("Definitions always serialize.")
+    }
+
+    /// The content hash of this definition, which changes whenever any part of the card does.
+    #[must_use]
+    pub fn content_hash(&self) -> DefinitionHash {
+        DefinitionHash::of(&self.canonical_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DefinitionHash, Keyword, Tribe};
+    use crate::v2::{
+        actions::DoNothingAction,
+        builder::CreatureDefinitionBuilder,
+        templates::{Effect, TemplateBehaviour},
+        CreatureDefinitionId,
+    };
+
+    fn builder() -> CreatureDefinitionBuilder {
+        let mut builder = CreatureDefinitionBuilder::new();
+        builder
+            .definition_id(CreatureDefinitionId::parse_str(
+                "3e1c5a7b-9d2f-4c8e-a6b4-0f1e2d3c4b5a",
+            ))
+            .title("Pebble".to_string())
+            .cost(1)
+            .attack(1)
+            .health(2);
+
+        builder
+    }
+
+    #[test]
+    fn content_hash_expects_stable_for_same_definition() {
+        assert_eq!(
+            builder().build().content_hash(),
+            builder().build().content_hash()
+        );
+        assert_eq!(
+            r#"{"id":"3e1c5a7b-9d2f-4c8e-a6b4-0f1e2d3c4b5a","title":"Pebble","cost":1,"text":"","flavor_text":"","attack":1,"health":2,"width":1,"placeable_at":"Either","keywords":[],"templates":null}"#,
+            String::from_utf8(builder().build().canonical_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn content_hash_expects_changed_by_any_field() {
+        let original = builder().build().content_hash();
+
+        assert_ne!(original, builder().health(3).build().content_hash());
+        assert_ne!(
+            original,
+            builder().keyword(Keyword::Defender).build().content_hash()
+        );
+        assert_ne!(original, builder().tribe(Tribe::Cat).build().content_hash());
+        assert_ne!(
+            original,
+            builder().upon_cast(DoNothingAction).build().content_hash()
+        );
+        assert_ne!(
+            original,
+            builder()
+                .templates(TemplateBehaviour {
+                    upon_summon: vec![Effect::DrawCards { count: 1 }],
+                    ..TemplateBehaviour::default()
+                })
+                .build()
+                .content_hash()
+        );
+    }
+
+    #[test]
+    fn definition_hash_expects_round_trips_as_hex() {
+        let hash = builder().build().content_hash();
+        let hex = hash.to_string();
+
+        assert_eq!(64, hex.len());
+        assert_eq!(Ok(hash), hex.parse());
+        assert_eq!(
+            hash,
+            serde_json::from_str(&serde_json::to_string(&hash).unwrap()).unwrap()
+        );
+        assert!("not a hash".parse::<DefinitionHash>().is_err());
+    }
+}
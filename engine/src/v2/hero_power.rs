@@ -0,0 +1,93 @@
+use super::actions::{DoNothingAction, SummonCondition, UponHeroPowerAction};
+use crate::game_state::game_state::GameState;
+use id_macro::id;
+use protocol::entities::PlayerId;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// An `id` identifying a hero power definition.
+#[id]
+pub struct HeroPowerId;
+
+/// An ability a hero can activate once per turn by paying its cost, such as summoning a token.
+/// Heroes only hold the id; the definition lives in the `CardRegistry` alongside the cards.
+#[derive(Debug)]
+pub struct HeroPowerDefinition {
+    hero_power_id: HeroPowerId,
+    title: String,
+    text: String,
+    cost: u32,
+    effect: Arc<dyn UponHeroPowerAction>,
+    condition: Arc<dyn SummonCondition>,
+}
+
+impl HeroPowerDefinition {
+    #[must_use]
+    pub fn new(
+        hero_power_id: HeroPowerId,
+        title: impl Into<String>,
+        text: impl Into<String>,
+        cost: u32,
+        effect: impl UponHeroPowerAction + 'static,
+    ) -> Self {
+        Self {
+            hero_power_id,
+            title: title.into(),
+            text: text.into(),
+            cost,
+            effect: Arc::new(effect),
+            condition: Arc::new(DoNothingAction),
+        }
+    }
+
+    /// Only lets the hero power be used while `condition` is met,
+    /// such as while there is a free slot for the creature it summons.
+    #[must_use]
+    pub fn with_condition(mut self, condition: impl SummonCondition + 'static) -> Self {
+        self.condition = Arc::new(condition);
+        self
+    }
+
+    #[must_use]
+    pub fn hero_power_id(&self) -> HeroPowerId {
+        self.hero_power_id
+    }
+
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn cost(&self) -> u32 {
+        self.cost
+    }
+
+    /// The effect to run when the hero power is used.
+    /// Returned as an `Arc` so it can be run while the `GameState` holding this definition is mutated.
+    #[must_use]
+    pub fn effect(&self) -> Arc<dyn UponHeroPowerAction> {
+        Arc::clone(&self.effect)
+    }
+
+    /// Whether the player may use this hero power right now, as decided by its condition.
+    #[must_use]
+    pub fn condition_met(&self, player_id: PlayerId, game_state: &GameState) -> bool {
+        self.condition.is_met(player_id, game_state)
+    }
+
+    /// The flat, serializable view of this hero power that is sent to clients.
+    #[must_use]
+    pub fn view(&self) -> protocol::entities::HeroPower {
+        protocol::entities::HeroPower {
+            title: self.title.clone(),
+            text: self.text.clone(),
+            cost: self.cost,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::{fingerprint::DefinitionHash, CreatureDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Which cards may be played in a match, by their `DefinitionHash`.
+/// A card is legal if it is allowed and not blocked. By default every card is allowed,
+/// so a match that only needs to ban a few cards can just block them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegalitySet {
+    /// The only cards allowed, or `None` to allow any card.
+    allowed: Option<HashSet<DefinitionHash>>,
+    blocked: HashSet<DefinitionHash>,
+}
+
+impl LegalitySet {
+    /// A set where every card is legal.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A set where only the given cards are legal.
+    #[must_use]
+    pub fn allowing(hashes: impl IntoIterator<Item = DefinitionHash>) -> Self {
+        Self {
+            allowed: Some(hashes.into_iter().collect()),
+            blocked: HashSet::new(),
+        }
+    }
+
+    /// Adds a card to the allowed cards.
+    /// Has no effect when every card is already allowed.
+    pub fn allow(&mut self, hash: DefinitionHash) {
+        if let Some(allowed) = &mut self.allowed {
+            allowed.insert(hash);
+        }
+    }
+
+    /// Makes a card illegal, even if it is allowed.
+    pub fn block(&mut self, hash: DefinitionHash) {
+        self.blocked.insert(hash);
+    }
+
+    #[must_use]
+    pub fn is_legal(&self, hash: DefinitionHash) -> bool {
+        !self.blocked.contains(&hash)
+            && self
+                .allowed
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(&hash))
+    }
+
+    #[must_use]
+    pub fn is_definition_legal(&self, definition: &CreatureDefinition) -> bool {
+        self.is_legal(definition.content_hash())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LegalitySet;
+    use crate::v2::fingerprint::DefinitionHash;
+
+    #[test]
+    fn is_legal_expects_blocklist_overrides_allowed() {
+        let allowed = DefinitionHash::of(b"allowed");
+        let blocked = DefinitionHash::of(b"blocked");
+        let unlisted = DefinitionHash::of(b"unlisted");
+
+        let mut everything = LegalitySet::new();
+        everything.block(blocked);
+        assert!(everything.is_legal(unlisted));
+        assert!(!everything.is_legal(blocked));
+
+        let mut listed = LegalitySet::allowing([allowed, blocked]);
+        listed.block(blocked);
+        assert!(listed.is_legal(allowed));
+        assert!(!listed.is_legal(blocked));
+        assert!(!listed.is_legal(unlisted));
+
+        listed.allow(unlisted);
+        assert!(listed.is_legal(unlisted));
+    }
+}
@@ -0,0 +1,278 @@
+//! Card-set manifests: a list of definition hashes signed with an Ed25519 key.
+//! Cards are only loaded from a set whose manifest was signed by a trusted key,
+//! so the original set and community sets can be shared without anyone vouching for them by hand.
+
+use super::{
+    definition_file::DefinitionFileError,
+    fingerprint::{from_hex, to_hex, DefinitionHash},
+    CreatureDefinition,
+};
+use ed25519_dalek::{Signature, Signer};
+pub use ed25519_dalek::{SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Why a card set could not be trusted or loaded.
+#[derive(Debug, Error)]
+pub enum ManifestError {
+    #[error("Could not read {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid manifest JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid key or signature: {0}")]
+    Malformed(String),
+    #[error("The manifest was signed by {public_key}, which is not a trusted key.")]
+    UntrustedKey { public_key: String },
+    #[error("The manifest's signature does not match its contents.")]
+    BadSignature,
+    #[error("{title:?} is not listed in the manifest.")]
+    NotInManifest { title: String },
+    #[error("{title:?} has the same id as {existing:?}.")]
+    DuplicateId { title: String, existing: String },
+    #[error(transparent)]
+    Definitions(#[from] DefinitionFileError),
+}
+
+/// The public keys whose signed card sets are accepted.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedKeys {
+    keys: Vec<VerifyingKey>,
+}
+
+impl TrustedKeys {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn trust(&mut self, key: VerifyingKey) {
+        if !self.is_trusted(&key) {
+            self.keys.push(key);
+        }
+    }
+
+    /// Trusts a public key written as 64 hex digits, as in a config file.
+    pub fn trust_hex(&mut self, public_key: &str) -> Result<(), ManifestError> {
+        let key = parse_public_key(public_key)?;
+        self.trust(key);
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn is_trusted(&self, key: &VerifyingKey) -> bool {
+        self.keys.contains(key)
+    }
+}
+
+/// The hashes of every card in a set. This is the part that gets signed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardSetManifest {
+    pub name: String,
+    pub hashes: Vec<DefinitionHash>,
+}
+
+impl CardSetManifest {
+    /// A manifest of the given definitions, with their hashes sorted.
+    pub fn new<'a>(
+        name: impl Into<String>,
+        definitions: impl IntoIterator<Item = &'a CreatureDefinition>,
+    ) -> Self {
+        let mut hashes: Vec<_> = definitions
+            .into_iter()
+            .map(CreatureDefinition::content_hash)
+            .collect();
+        hashes.sort();
+
+        Self {
+            name: name.into(),
+            hashes,
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, hash: DefinitionHash) -> bool {
+        self.hashes.contains(&hash)
+    }
+
+    /// The bytes the signature covers: the manifest as compact JSON.
+    fn signed_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("Manifests always serialize.")
+    }
+
+    #[must_use]
+    pub fn sign(self, key: &SigningKey) -> SignedManifest {
+        let signature = key.sign(&self.signed_bytes());
+
+        SignedManifest {
+            public_key: to_hex(key.verifying_key().as_bytes()),
+            signature: to_hex(&signature.to_bytes()),
+            manifest: self,
+        }
+    }
+}
+
+/// A manifest together with the key that signed it and the signature, both as hex.
+/// This is what gets distributed alongside a set's definition file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedManifest {
+    pub manifest: CardSetManifest,
+    pub public_key: String,
+    pub signature: String,
+}
+
+impl SignedManifest {
+    /// Reads a signed manifest from a JSON file.
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, ManifestError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|source| ManifestError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// The manifest, if it was signed by one of the trusted keys and has not been altered since.
+    pub fn verify(&self, trusted: &TrustedKeys) -> Result<&CardSetManifest, ManifestError> {
+        let public_key = parse_public_key(&self.public_key)?;
+
+        if !trusted.is_trusted(&public_key) {
+            return Err(ManifestError::UntrustedKey {
+                public_key: self.public_key.clone(),
+            });
+        }
+
+        let signature =
+            Signature::from_bytes(&from_hex(&self.signature).map_err(ManifestError::Malformed)?);
+
+        public_key
+            .verify_strict(&self.manifest.signed_bytes(), &signature)
+            .map_err(|_| ManifestError::BadSignature)?;
+
+        Ok(&self.manifest)
+    }
+
+    /// Verifies the manifest, then checks that every definition is listed in it.
+    pub fn verify_definitions<'a>(
+        &self,
+        trusted: &TrustedKeys,
+        definitions: impl IntoIterator<Item = &'a CreatureDefinition>,
+    ) -> Result<(), ManifestError> {
+        let manifest = self.verify(trusted)?;
+
+        for definition in definitions {
+            if !manifest.contains(definition.content_hash()) {
+                return Err(ManifestError::NotInManifest {
+                    title: definition.title().to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_public_key(public_key: &str) -> Result<VerifyingKey, ManifestError> {
+    let bytes = from_hex(public_key).map_err(ManifestError::Malformed)?;
+
+    VerifyingKey::from_bytes(&bytes).map_err(|e| ManifestError::Malformed(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CardSetManifest, ManifestError, SignedManifest, SigningKey, TrustedKeys};
+    use crate::v2::{builder::CreatureDefinitionBuilder, CreatureDefinition};
+
+    fn key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn definitions() -> Vec<CreatureDefinition> {
+        ["Pebble", "Stone"]
+            .into_iter()
+            .map(|title| {
+                CreatureDefinitionBuilder::new()
+                    .title(title.to_string())
+                    .build()
+            })
+            .collect()
+    }
+
+    fn trusting(key: &SigningKey) -> TrustedKeys {
+        let mut trusted = TrustedKeys::new();
+        trusted.trust(key.verifying_key());
+
+        trusted
+    }
+
+    #[test]
+    fn verify_when_signed_by_trusted_key_expects_manifest() {
+        let definitions = definitions();
+        let signed = CardSetManifest::new("Base", &definitions).sign(&key(1));
+
+        let manifest = signed.verify(&trusting(&key(1))).unwrap();
+
+        assert_eq!("Base", manifest.name);
+        assert!(signed
+            .verify_definitions(&trusting(&key(1)), &definitions)
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_when_signed_by_untrusted_key_expects_error() {
+        let signed = CardSetManifest::new("Community", &definitions()).sign(&key(2));
+
+        assert!(matches!(
+            signed.verify(&trusting(&key(1))),
+            Err(ManifestError::UntrustedKey { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_when_manifest_altered_expects_bad_signature() {
+        let mut signed = CardSetManifest::new("Base", &definitions()).sign(&key(1));
+        signed.manifest.hashes.pop();
+
+        assert!(matches!(
+            signed.verify(&trusting(&key(1))),
+            Err(ManifestError::BadSignature)
+        ));
+    }
+
+    #[test]
+    fn verify_definitions_when_card_not_listed_expects_error() {
+        let mut definitions = definitions();
+        let signed = CardSetManifest::new("Base", &definitions).sign(&key(1));
+        definitions.push(
+            CreatureDefinitionBuilder::new()
+                .title("Smuggled".to_string())
+                .build(),
+        );
+
+        assert!(matches!(
+            signed.verify_definitions(&trusting(&key(1)), &definitions),
+            Err(ManifestError::NotInManifest { title }) if title == "Smuggled"
+        ));
+    }
+
+    #[test]
+    fn signed_manifest_expects_round_trips_through_json() {
+        let signed = CardSetManifest::new("Base", &definitions()).sign(&key(1));
+        let json = serde_json::to_string(&signed).unwrap();
+
+        let mut trusted = TrustedKeys::new();
+        trusted.trust_hex(&signed.public_key).unwrap();
+        let parsed: SignedManifest = serde_json::from_str(&json).unwrap();
+
+        assert!(parsed.verify(&trusted).is_ok());
+        assert!(matches!(
+            TrustedKeys::new().trust_hex("nope"),
+            Err(ManifestError::Malformed(_))
+        ));
+    }
+}
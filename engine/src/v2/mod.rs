@@ -1,13 +1,65 @@
+pub mod actions;
+mod buff;
+pub mod definition_file;
+mod fingerprint;
+mod hero_power;
+mod legality;
+pub mod manifest;
+mod registry;
+pub mod templates;
+
+use crate::{game_logic::events::GameEvent, game_state::game_state::GameState};
+use actions::{
+    EventTrigger, PassiveEffect, SummonCondition, UponAllyDestroyedAction, UponCastAction,
+    UponCompanionDamagedAction, UponDeathAction, UponEventAction, UponSummonAction,
+};
 use entity_arena::{id::EntityTypeId, IsEntity};
 use id_macro::id;
 use isentity_macro_derive::entity;
+pub use protocol::entities::CreatureInstanceId;
+use protocol::entities::{CardKind, InstanceState, Keyword, PlayerId, Position, Tribe};
 use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet, sync::Arc};
+use templates::TemplateBehaviour;
+
+pub use buff::{Buff, BuffBuilder};
+pub use fingerprint::DefinitionHash;
+pub use hero_power::{HeroPowerDefinition, HeroPowerId};
+pub use legality::LegalitySet;
+pub use registry::CardRegistry;
 
 /// An `id` identifying a creature definition.
 #[id]
 pub struct CreatureDefinitionId;
 
+/// What a spell needs on the board before it can be cast.
+/// A spell with no legal target is rejected before it is paid for, instead of fizzling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpellTarget {
+    /// The spell can always be cast.
+    #[default]
+    None,
+    /// The caster's opponent must have a creature on the board.
+    EnemyCreature,
+}
+
+impl SpellTarget {
+    #[must_use]
+    pub fn has_legal_target(self, player_id: PlayerId, game_state: &GameState) -> bool {
+        match self {
+            Self::None => true,
+            Self::EnemyCreature => !game_state
+                .board()
+                .player_creature_ids(game_state.other_player(player_id))
+                .is_empty(),
+        }
+    }
+}
+
 /// A struct representing a creature definition.
+/// Spells are defined the same way, with `CardKind::Spell` and an `upon_cast` action,
+/// so they share the registry, decks and hands with creatures.
+#[derive(Debug)]
 pub struct CreatureDefinition {
     definition_id: CreatureDefinitionId,
     title: String,
@@ -17,12 +69,205 @@ pub struct CreatureDefinition {
     base_attack: i32,
     base_health: i32,
     width: usize,
-    // placeable_at: Position,
+    placeable_at: Position,
+    keywords: BTreeSet<Keyword>,
+    tribes: BTreeSet<Tribe>,
+    kind: CardKind,
+    upon_summon: Arc<dyn UponSummonAction>,
+    upon_death: Arc<dyn UponDeathAction>,
+    upon_ally_destroyed: Arc<dyn UponAllyDestroyedAction>,
+    upon_companion_damaged: Arc<dyn UponCompanionDamagedAction>,
+    upon_cast: Arc<dyn UponCastAction>,
+    spell_target: SpellTarget,
+    summon_condition: Arc<dyn SummonCondition>,
+    pre_event_trigger: Arc<dyn EventTrigger>,
+    post_event_trigger: Arc<dyn EventTrigger>,
+    passive_effect: Arc<dyn PassiveEffect>,
+    templates: Option<TemplateBehaviour>,
 }
 
-/// An `id` identifying a creature instance.
-#[id]
-pub struct CreatureInstanceId;
+impl CreatureDefinition {
+    #[must_use]
+    pub fn definition_id(&self) -> CreatureDefinitionId {
+        self.definition_id
+    }
+
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[must_use]
+    pub fn cost(&self) -> i32 {
+        self.base_cost
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn flavor_text(&self) -> &str {
+        &self.flavor_text
+    }
+
+    #[must_use]
+    pub fn attack(&self) -> i32 {
+        self.base_attack
+    }
+
+    #[must_use]
+    pub fn health(&self) -> i32 {
+        self.base_health
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn placeable_at(&self) -> Position {
+        self.placeable_at
+    }
+
+    /// The keywords every instance of this creature starts with.
+    #[must_use]
+    pub fn keywords(&self) -> &BTreeSet<Keyword> {
+        &self.keywords
+    }
+
+    #[must_use]
+    pub fn has_keyword(&self, keyword: Keyword) -> bool {
+        self.keywords.contains(&keyword)
+    }
+
+    /// The cost the opponent sees while this creature is hidden: 3, 6 or 9,
+    /// rounding the real cost up to the next bucket.
+    #[must_use]
+    pub fn hidden_cost_bucket(&self) -> i32 {
+        ((self.base_cost + 2) / 3 * 3).clamp(3, 9)
+    }
+
+    /// The clubs this creature belongs to.
+    #[must_use]
+    pub fn tribes(&self) -> &BTreeSet<Tribe> {
+        &self.tribes
+    }
+
+    #[must_use]
+    pub fn has_tribe(&self, tribe: Tribe) -> bool {
+        self.tribes.contains(&tribe)
+    }
+
+    /// Whether the card is summoned as a creature or cast as a spell.
+    #[must_use]
+    pub fn kind(&self) -> CardKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn is_spell(&self) -> bool {
+        self.kind == CardKind::Spell
+    }
+
+    /// The action to run when this creature is summoned from the hand.
+    /// Returned as an `Arc` so it can be run while the `GameState` holding this definition is mutated.
+    #[must_use]
+    pub fn upon_summon(&self) -> Arc<dyn UponSummonAction> {
+        Arc::clone(&self.upon_summon)
+    }
+
+    /// The action to run when this creature is destroyed.
+    #[must_use]
+    pub fn upon_death(&self) -> Arc<dyn UponDeathAction> {
+        Arc::clone(&self.upon_death)
+    }
+
+    /// The action to run when another creature on this creature's side is destroyed,
+    /// while it has `Keyword::Vengeance`.
+    #[must_use]
+    pub fn upon_ally_destroyed(&self) -> Arc<dyn UponAllyDestroyedAction> {
+        Arc::clone(&self.upon_ally_destroyed)
+    }
+
+    /// The action to run after one of this creature's companions takes damage.
+    #[must_use]
+    pub fn upon_companion_damaged(&self) -> Arc<dyn UponCompanionDamagedAction> {
+        Arc::clone(&self.upon_companion_damaged)
+    }
+
+    /// The effect to run when this spell is cast from the hand.
+    #[must_use]
+    pub fn upon_cast(&self) -> Arc<dyn UponCastAction> {
+        Arc::clone(&self.upon_cast)
+    }
+
+    /// What this spell needs on the board before it can be cast.
+    #[must_use]
+    pub fn spell_target(&self) -> SpellTarget {
+        self.spell_target
+    }
+
+    /// Whether the player may summon this creature right now, as decided by its summon condition.
+    #[must_use]
+    pub fn summon_condition_met(&self, player_id: PlayerId, game_state: &GameState) -> bool {
+        self.summon_condition.is_met(player_id, game_state)
+    }
+
+    /// Invoked for each instance on the board before every event is handled.
+    #[must_use]
+    pub fn pre_event_action(
+        &self,
+        instance_id: CreatureInstanceId,
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>> {
+        self.pre_event_trigger
+            .trigger(instance_id, event, game_state)
+    }
+
+    /// Invoked for each instance on the board after every event is handled.
+    #[must_use]
+    pub fn post_event_action(
+        &self,
+        instance_id: CreatureInstanceId,
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>> {
+        self.post_event_trigger
+            .trigger(instance_id, event, game_state)
+    }
+
+    /// The buffs this creature's passive effect currently grants, each paired with its receiver.
+    #[must_use]
+    pub fn passive_buffs(
+        &self,
+        instance_id: CreatureInstanceId,
+        game_state: &GameState,
+    ) -> Vec<(CreatureInstanceId, Buff)> {
+        self.passive_effect.buffs(instance_id, game_state)
+    }
+
+    /// The flat, serializable view of this definition that is sent to clients.
+    #[must_use]
+    pub fn view(&self) -> protocol::entities::CreatureDefinition {
+        protocol::entities::CreatureDefinition {
+            title: self.title.clone(),
+            cost: self.base_cost,
+            text: self.text.clone(),
+            flavor_text: self.flavor_text.clone(),
+            attack: self.base_attack,
+            health: self.base_health,
+            row_width: self.width,
+            placeable_at: self.placeable_at,
+            keywords: self.keywords.iter().copied().collect(),
+            tribes: self.tribes.iter().copied().collect(),
+            kind: self.kind,
+        }
+    }
+}
 
 /// A struct representing a creature instance.
 /// Has an id that indicates its `CreatureDefinition`.
@@ -34,26 +279,231 @@ pub struct CreatureInstance {
     cost: i32,
     attack: i32,
     health: i32,
+    max_health: i32,
+    width: usize,
+    summoning_sick: bool,
+    has_attacked: bool,
+    keywords: BTreeSet<Keyword>,
+    buffs: Vec<Buff>,
+    state: Option<InstanceState>,
 }
 
 impl CreatureInstance {
-    pub fn new_from_definition_id(definition_id: CreatureDefinitionId) -> Self {
+    #[must_use]
+    pub fn new_from_definition_id(
+        instance_id: CreatureInstanceId,
+        definition_id: CreatureDefinitionId,
+    ) -> Self {
         Self {
-            instance_id: CreatureInstanceId::new(),
+            instance_id,
             definition_id,
             cost: 0,
             attack: 0,
             health: 0,
+            max_health: 0,
+            width: 1,
+            summoning_sick: true,
+            has_attacked: false,
+            keywords: BTreeSet::new(),
+            buffs: Vec::new(),
+            state: None,
+        }
+    }
+
+    /// Creates an instance with the definition's base stats.
+    /// The instance has the definition's keywords, and summoning sickness unless it is eager.
+    #[must_use]
+    pub fn new_from_definition(
+        instance_id: CreatureInstanceId,
+        definition: &CreatureDefinition,
+    ) -> Self {
+        Self {
+            instance_id,
+            definition_id: definition.definition_id(),
+            cost: definition.cost(),
+            attack: definition.attack(),
+            health: definition.health(),
+            max_health: definition.health(),
+            width: definition.width(),
+            summoning_sick: !definition.has_keyword(Keyword::Eager),
+            has_attacked: false,
+            keywords: definition.keywords().clone(),
+            buffs: Vec::new(),
+            state: None,
         }
     }
 
+    #[must_use]
+    pub fn instance_id(&self) -> CreatureInstanceId {
+        self.instance_id
+    }
+
+    #[must_use]
     pub fn definition_id(&self) -> CreatureDefinitionId {
         self.definition_id
     }
+
+    #[must_use]
+    pub fn cost(&self) -> i32 {
+        self.cost
+    }
+
+    /// The creature's attack, including its buffs.
+    #[must_use]
+    pub fn attack(&self) -> i32 {
+        self.attack + self.buffs.iter().map(Buff::attack_amount).sum::<i32>()
+    }
+
+    /// The creature's remaining health, including its buffs.
+    #[must_use]
+    pub fn health(&self) -> i32 {
+        self.health + self.buff_health()
+    }
+
+    /// The health the creature can be healed up to, including its buffs.
+    #[must_use]
+    pub fn max_health(&self) -> i32 {
+        self.max_health + self.buff_health()
+    }
+
+    fn buff_health(&self) -> i32 {
+        self.buffs.iter().map(Buff::health_amount).sum()
+    }
+
+    pub fn set_health(&mut self, health: i32) {
+        self.health = health;
+    }
+
+    pub fn take_damage(&mut self, amount: usize) {
+        self.health -= i32::try_from(amount).unwrap_or(i32::MAX);
+    }
+
+    /// Restores health, up to the creature's max health.
+    pub fn heal(&mut self, amount: usize) {
+        let healed = self
+            .health
+            .saturating_add(i32::try_from(amount).unwrap_or(i32::MAX));
+        self.health = healed.min(self.max_health).max(self.health);
+    }
+
+    #[must_use]
+    pub fn buffs(&self) -> &[Buff] {
+        &self.buffs
+    }
+
+    pub fn add_buff(&mut self, buff: Buff) {
+        self.buffs.push(buff);
+    }
+
+    /// Removes every buff granted by a passive effect, so the passives can be evaluated again.
+    pub fn remove_passive_buffs(&mut self) {
+        self.buffs.retain(|b| !b.is_from_passive());
+    }
+
+    /// The creature's keywords: its definition's, plus those its buffs grant,
+    /// minus those its buffs take away. Later buffs win over earlier ones.
+    #[must_use]
+    pub fn keywords(&self) -> BTreeSet<Keyword> {
+        let mut keywords = self.keywords.clone();
+
+        for buff in &self.buffs {
+            keywords.extend(buff.gained_keywords());
+            for keyword in buff.lost_keywords() {
+                keywords.remove(keyword);
+            }
+        }
+
+        keywords
+    }
+
+    #[must_use]
+    pub fn has_keyword(&self, keyword: Keyword) -> bool {
+        self.keywords().contains(&keyword)
+    }
+
+    /// A hidden creature's definition is kept from the opponent until it is revealed.
+    #[must_use]
+    pub fn is_hidden(&self) -> bool {
+        self.has_keyword(Keyword::Hidden)
+    }
+
+    /// Removes the creature's own `Hidden` keyword. A buff granting `Hidden` keeps it hidden.
+    pub fn reveal(&mut self) {
+        self.keywords.remove(&Keyword::Hidden);
+    }
+
+    /// The flat, serializable view of this creature, with its current stats and keywords.
+    /// This shows a hidden creature's definition; see `GameState::creature_view` for what each player may see.
+    #[must_use]
+    pub fn view(&self, definition: &CreatureDefinition) -> protocol::entities::CreatureInstance {
+        let mut definition_view = definition.view();
+        definition_view.attack = self.attack();
+        definition_view.health = self.health();
+
+        protocol::entities::CreatureInstance::new(
+            self.instance_id,
+            definition_view,
+            Vec::new(),
+            None,
+        )
+        .with_keywords(self.keywords().into_iter().collect())
+    }
+
+    /// Extra state a card keeps on its instance, such as a slot it picked when summoned.
+    #[must_use]
+    pub fn state(&self) -> Option<InstanceState> {
+        self.state
+    }
+
+    pub fn set_state(&mut self, state: Option<InstanceState>) {
+        self.state = state;
+    }
+
+    /// The number of adjacent board slots this creature occupies.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// True until the start of its owner's next turn after it was summoned.
+    #[must_use]
+    pub fn is_summoning_sick(&self) -> bool {
+        self.summoning_sick
+    }
+
+    #[must_use]
+    pub fn has_attacked(&self) -> bool {
+        self.has_attacked
+    }
+
+    /// A creature can attack once per turn, and not on the turn it was summoned.
+    #[must_use]
+    pub fn can_attack(&self) -> bool {
+        !self.summoning_sick && !self.has_attacked
+    }
+
+    pub fn set_has_attacked(&mut self) {
+        self.has_attacked = true;
+    }
+
+    /// Readies the creature for its owner's turn, clearing summoning sickness
+    /// and letting it attack again.
+    pub fn ready_for_turn(&mut self) {
+        self.summoning_sick = false;
+        self.has_attacked = false;
+    }
 }
 
 pub mod builder {
-    use super::{CreatureDefinition, CreatureDefinitionId};
+    use super::{
+        actions::{
+            DoNothingAction, EventTrigger, PassiveEffect, SummonCondition, UponAllyDestroyedAction,
+            UponCastAction, UponCompanionDamagedAction, UponDeathAction, UponSummonAction,
+        },
+        templates::{SummonTemplate, TemplateBehaviour, TemplatePassives, TemplateTriggers},
+        CardKind, CreatureDefinition, CreatureDefinitionId, Keyword, Position, SpellTarget, Tribe,
+    };
+    use std::{collections::BTreeSet, sync::Arc};
 
     pub struct CreatureDefinitionBuilder {
         definition_id: CreatureDefinitionId,
@@ -64,9 +514,31 @@ pub mod builder {
         base_attack: i32,
         base_health: i32,
         width: usize,
+        placeable_at: Position,
+        keywords: BTreeSet<Keyword>,
+        tribes: BTreeSet<Tribe>,
+        kind: CardKind,
+        upon_summon: Arc<dyn UponSummonAction>,
+        upon_death: Arc<dyn UponDeathAction>,
+        upon_ally_destroyed: Arc<dyn UponAllyDestroyedAction>,
+        upon_companion_damaged: Arc<dyn UponCompanionDamagedAction>,
+        upon_cast: Arc<dyn UponCastAction>,
+        spell_target: SpellTarget,
+        summon_condition: Arc<dyn SummonCondition>,
+        pre_event_trigger: Arc<dyn EventTrigger>,
+        post_event_trigger: Arc<dyn EventTrigger>,
+        passive_effect: Arc<dyn PassiveEffect>,
+        templates: Option<TemplateBehaviour>,
+    }
+
+    impl Default for CreatureDefinitionBuilder {
+        fn default() -> Self {
+            Self::new()
+        }
     }
 
     impl CreatureDefinitionBuilder {
+        #[must_use]
         pub fn new() -> Self {
             Self {
                 definition_id: CreatureDefinitionId::new(),
@@ -76,7 +548,22 @@ pub mod builder {
                 flavor_text: String::new(),
                 base_attack: 0,
                 base_health: 0,
-                width: 0,
+                width: 1,
+                placeable_at: Position::Either,
+                keywords: BTreeSet::new(),
+                tribes: BTreeSet::new(),
+                kind: CardKind::Creature,
+                upon_summon: Arc::new(DoNothingAction),
+                upon_death: Arc::new(DoNothingAction),
+                upon_ally_destroyed: Arc::new(DoNothingAction),
+                upon_companion_damaged: Arc::new(DoNothingAction),
+                upon_cast: Arc::new(DoNothingAction),
+                spell_target: SpellTarget::None,
+                summon_condition: Arc::new(DoNothingAction),
+                pre_event_trigger: Arc::new(DoNothingAction),
+                post_event_trigger: Arc::new(DoNothingAction),
+                passive_effect: Arc::new(DoNothingAction),
+                templates: None,
             }
         }
 
@@ -120,6 +607,112 @@ pub mod builder {
             self
         }
 
+        pub fn placeable_at(&mut self, placeable_at: Position) -> &mut Self {
+            self.placeable_at = placeable_at;
+            self
+        }
+
+        pub fn keyword(&mut self, keyword: Keyword) -> &mut Self {
+            self.keywords.insert(keyword);
+            self
+        }
+
+        pub fn tribe(&mut self, tribe: Tribe) -> &mut Self {
+            self.tribes.insert(tribe);
+            self
+        }
+
+        /// Also gives the creature `Keyword::Summon`, which the action runs under.
+        pub fn upon_summon(&mut self, action: impl UponSummonAction + 'static) -> &mut Self {
+            self.upon_summon = Arc::new(action);
+            self.keyword(Keyword::Summon)
+        }
+
+        pub fn upon_death(&mut self, action: impl UponDeathAction + 'static) -> &mut Self {
+            self.upon_death = Arc::new(action);
+            self
+        }
+
+        /// Also gives the creature `Keyword::Vengeance`, which the action runs under.
+        pub fn upon_ally_destroyed(
+            &mut self,
+            action: impl UponAllyDestroyedAction + 'static,
+        ) -> &mut Self {
+            self.upon_ally_destroyed = Arc::new(action);
+            self.keyword(Keyword::Vengeance)
+        }
+
+        pub fn upon_companion_damaged(
+            &mut self,
+            action: impl UponCompanionDamagedAction + 'static,
+        ) -> &mut Self {
+            self.upon_companion_damaged = Arc::new(action);
+            self
+        }
+
+        /// Makes the card a spell, which runs the action when cast instead of being summoned.
+        pub fn upon_cast(&mut self, action: impl UponCastAction + 'static) -> &mut Self {
+            self.upon_cast = Arc::new(action);
+            self.kind = CardKind::Spell;
+            self
+        }
+
+        /// Only lets the spell be cast while it has a legal target.
+        pub fn spell_target(&mut self, target: SpellTarget) -> &mut Self {
+            self.spell_target = target;
+            self
+        }
+
+        pub fn summon_condition(&mut self, condition: impl SummonCondition + 'static) -> &mut Self {
+            self.summon_condition = Arc::new(condition);
+            self
+        }
+
+        pub fn pre_event_action(&mut self, trigger: impl EventTrigger + 'static) -> &mut Self {
+            self.pre_event_trigger = Arc::new(trigger);
+            self
+        }
+
+        pub fn post_event_action(&mut self, trigger: impl EventTrigger + 'static) -> &mut Self {
+            self.post_event_trigger = Arc::new(trigger);
+            self
+        }
+
+        pub fn passive_effect(&mut self, effect: impl PassiveEffect + 'static) -> &mut Self {
+            self.passive_effect = Arc::new(effect);
+            self
+        }
+
+        /// Sets the summon action, summon condition, passive effect and event hooks from templates,
+        /// replacing any set before.
+        /// Unlike behaviour written in Rust, templates are part of the definition's fingerprint.
+        pub fn templates(&mut self, templates: TemplateBehaviour) -> &mut Self {
+            if templates.upon_summon.is_empty() {
+                self.keywords.remove(&Keyword::Summon);
+            } else {
+                self.keywords.insert(Keyword::Summon);
+            }
+            self.upon_summon = Arc::new(SummonTemplate(templates.upon_summon.clone()));
+
+            let (pre, post) = templates
+                .triggers
+                .iter()
+                .cloned()
+                .partition(|t| t.when.is_pre_event());
+            self.pre_event_trigger = Arc::new(TemplateTriggers(pre));
+            self.post_event_trigger = Arc::new(TemplateTriggers(post));
+
+            self.summon_condition = match templates.summon_condition {
+                Some(requirement) => Arc::new(requirement),
+                None => Arc::new(DoNothingAction),
+            };
+            self.passive_effect = Arc::new(TemplatePassives(templates.tribe_buffs.clone()));
+
+            self.templates = Some(templates);
+            self
+        }
+
+        #[must_use]
         pub fn build(&self) -> CreatureDefinition {
             CreatureDefinition {
                 definition_id: self.definition_id,
@@ -130,7 +723,103 @@ pub mod builder {
                 base_attack: self.base_attack,
                 base_health: self.base_health,
                 width: self.width,
+                placeable_at: self.placeable_at,
+                keywords: self.keywords.clone(),
+                tribes: self.tribes.clone(),
+                kind: self.kind,
+                upon_summon: Arc::clone(&self.upon_summon),
+                upon_death: Arc::clone(&self.upon_death),
+                upon_ally_destroyed: Arc::clone(&self.upon_ally_destroyed),
+                upon_companion_damaged: Arc::clone(&self.upon_companion_damaged),
+                upon_cast: Arc::clone(&self.upon_cast),
+                spell_target: self.spell_target,
+                summon_condition: Arc::clone(&self.summon_condition),
+                pre_event_trigger: Arc::clone(&self.pre_event_trigger),
+                post_event_trigger: Arc::clone(&self.post_event_trigger),
+                passive_effect: Arc::clone(&self.passive_effect),
+                templates: self.templates.clone(),
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{builder::CreatureDefinitionBuilder, BuffBuilder, CreatureInstance};
+    use protocol::entities::{CreatureInstanceId, Keyword};
+
+    #[test]
+    fn heal_expects_capped_at_max_health_with_buffs() {
+        let definition = CreatureDefinitionBuilder::new().attack(1).health(4).build();
+        let mut creature =
+            CreatureInstance::new_from_definition(CreatureInstanceId::new(), &definition);
+
+        creature.add_buff(
+            BuffBuilder::new(CreatureInstanceId::new())
+                .attack(2)
+                .health(1)
+                .build(),
+        );
+        assert_eq!((3, 5), (creature.attack(), creature.health()));
+
+        creature.take_damage(3);
+        creature.heal(1);
+        assert_eq!(3, creature.health());
+
+        creature.heal(10);
+        assert_eq!(5, creature.health());
+        assert_eq!(5, creature.max_health());
+    }
+
+    #[test]
+    fn keywords_expect_gained_and_lost_through_buffs() {
+        let definition = CreatureDefinitionBuilder::new()
+            .keyword(Keyword::Defender)
+            .keyword(Keyword::Hidden)
+            .build();
+        let mut creature =
+            CreatureInstance::new_from_definition(CreatureInstanceId::new(), &definition);
+        assert!(creature.is_hidden());
+
+        creature.add_buff(
+            BuffBuilder::new(CreatureInstanceId::new())
+                .gain_keyword(Keyword::Versatile)
+                .lose_keyword(Keyword::Defender)
+                .build(),
+        );
+        creature.reveal();
+
+        assert_eq!(
+            vec![Keyword::Versatile],
+            creature.keywords().into_iter().collect::<Vec<_>>()
+        );
+        assert!(!creature.is_hidden());
+
+        // A later buff wins over an earlier one.
+        creature.add_buff(
+            BuffBuilder::new(CreatureInstanceId::new())
+                .gain_keyword(Keyword::Defender)
+                .build(),
+        );
+        assert!(creature.has_keyword(Keyword::Defender));
+        assert_eq!(
+            vec![Keyword::Defender, Keyword::Versatile],
+            creature.view(&definition).keywords().to_vec()
+        );
+    }
+
+    #[test]
+    fn hidden_cost_bucket_expects_rounded_up_to_3_6_or_9() {
+        let bucket = |cost| {
+            CreatureDefinitionBuilder::new()
+                .cost(cost)
+                .build()
+                .hidden_cost_bucket()
+        };
+
+        assert_eq!(
+            vec![3, 3, 3, 6, 6, 9, 9],
+            [0, 1, 3, 4, 6, 7, 12].map(bucket).to_vec()
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use super::{
+    definition_file::{load_file, DefinitionFileError},
+    manifest::{ManifestError, SignedManifest, TrustedKeys},
+    CreatureDefinition, CreatureDefinitionId, HeroPowerDefinition, HeroPowerId,
+};
+use std::{collections::HashMap, path::Path};
+
+/// The creature definitions a game can refer to, keyed by their `CreatureDefinitionId`.
+/// Cards, instances on the board and cards in decks and hands only hold the id,
+/// and look up their cost, stats and actions here.
+/// Heroes look up their hero powers here the same way.
+#[derive(Debug, Default)]
+pub struct CardRegistry {
+    definitions: HashMap<CreatureDefinitionId, CreatureDefinition>,
+    hero_powers: HashMap<HeroPowerId, HeroPowerDefinition>,
+}
+
+impl CardRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a definition to the registry.
+    /// Panics if a definition with the same id is already registered,
+    /// since two cards sharing an id could never be told apart.
+    pub fn register(&mut self, definition: CreatureDefinition) {
+        let definition_id = definition.definition_id();
+
+        if let Some(existing) = self.definitions.get(&definition_id) {
+            panic!(
+                "Cannot register {:?} with id {definition_id:?}, since {:?} already has that id.",
+                definition.title(),
+                existing.title()
+            );
+        }
+
+        self.definitions.insert(definition_id, definition);
+    }
+
+    /// Adds a hero power to the registry.
+    /// Panics if a hero power with the same id is already registered.
+    pub fn register_hero_power(&mut self, hero_power: HeroPowerDefinition) {
+        let hero_power_id = hero_power.hero_power_id();
+
+        if let Some(existing) = self.hero_powers.get(&hero_power_id) {
+            panic!(
+                "Cannot register {:?} with id {hero_power_id:?}, since {:?} already has that id.",
+                hero_power.title(),
+                existing.title()
+            );
+        }
+
+        self.hero_powers.insert(hero_power_id, hero_power);
+    }
+
+    /// Registers every creature in a `.ron` or `.json` definition file,
+    /// returning how many were added.
+    /// Nothing is registered if two of them share an id, or one's id is already registered.
+    pub fn register_file(&mut self, path: impl AsRef<Path>) -> Result<usize, DefinitionFileError> {
+        let definitions: Vec<_> = load_file(path)?.iter().map(|d| d.build()).collect();
+
+        if let Some((title, existing)) = self.find_duplicate_id(&definitions) {
+            return Err(DefinitionFileError::DuplicateId { title, existing });
+        }
+
+        let count = definitions.len();
+        for definition in definitions {
+            self.register(definition);
+        }
+
+        Ok(count)
+    }
+
+    /// Registers every creature in a definition file, but only once its signed manifest
+    /// is verified against the trusted keys and lists every one of them.
+    /// Nothing is registered if any check fails,
+    /// including two of them sharing an id or one's id already being registered.
+    pub fn register_signed_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        manifest: &SignedManifest,
+        trusted: &TrustedKeys,
+    ) -> Result<usize, ManifestError> {
+        let definitions: Vec<_> = load_file(path)?.iter().map(|d| d.build()).collect();
+
+        manifest.verify_definitions(trusted, &definitions)?;
+
+        if let Some((title, existing)) = self.find_duplicate_id(&definitions) {
+            return Err(ManifestError::DuplicateId { title, existing });
+        }
+
+        let count = definitions.len();
+        for definition in definitions {
+            self.register(definition);
+        }
+
+        Ok(count)
+    }
+
+    /// Finds a definition whose id is used by an earlier one in `definitions`
+    /// or is already registered, returning its title and the title of the one it clashes with.
+    fn find_duplicate_id(&self, definitions: &[CreatureDefinition]) -> Option<(String, String)> {
+        let mut seen = HashMap::new();
+
+        for definition in definitions {
+            let definition_id = definition.definition_id();
+            let existing = self
+                .definitions
+                .get(&definition_id)
+                .or_else(|| seen.get(&definition_id).copied());
+
+            if let Some(existing) = existing {
+                return Some((definition.title().to_string(), existing.title().to_string()));
+            }
+
+            seen.insert(definition_id, definition);
+        }
+
+        None
+    }
+
+    #[must_use]
+    pub fn get(&self, definition_id: CreatureDefinitionId) -> Option<&CreatureDefinition> {
+        self.definitions.get(&definition_id)
+    }
+
+    #[must_use]
+    pub fn hero_power(&self, hero_power_id: HeroPowerId) -> Option<&HeroPowerDefinition> {
+        self.hero_powers.get(&hero_power_id)
+    }
+
+    pub fn definitions(&self) -> impl Iterator<Item = &CreatureDefinition> {
+        self.definitions.values()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CardRegistry;
+    use crate::v2::{
+        builder::CreatureDefinitionBuilder,
+        definition_file::{load_file, DefinitionFileError},
+        manifest::{CardSetManifest, ManifestError, SigningKey, TrustedKeys},
+        CreatureDefinitionId,
+    };
+
+    #[test]
+    fn get_when_registered_expects_definition() {
+        let mut registry = CardRegistry::new();
+        let definition_id = CreatureDefinitionId::new();
+
+        registry.register(
+            CreatureDefinitionBuilder::new()
+                .definition_id(definition_id)
+                .cost(3)
+                .build(),
+        );
+
+        assert_eq!(Some(3), registry.get(definition_id).map(|d| d.cost()));
+        assert!(registry.get(CreatureDefinitionId::new()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "already has that id")]
+    fn register_when_id_taken_expects_panic() {
+        let mut registry = CardRegistry::new();
+        let definition_id = CreatureDefinitionId::new();
+
+        registry.register(
+            CreatureDefinitionBuilder::new()
+                .definition_id(definition_id)
+                .build(),
+        );
+        registry.register(
+            CreatureDefinitionBuilder::new()
+                .definition_id(definition_id)
+                .build(),
+        );
+    }
+
+    #[test]
+    fn register_file_expects_every_creature_registered() {
+        let path = std::env::temp_dir().join(format!("{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            r#"[
+                {"id": "6f1f0c2e-5d1a-4e9b-9c43-0f9d8e7a6b5c", "title": "Pebble", "cost": 1, "attack": 1, "health": 1},
+                {"id": "7a2b3c4d-6e1f-4a0b-8c9d-1e2f3a4b5c6d", "title": "Stone", "cost": 2, "attack": 2, "health": 2}
+            ]"#,
+        )
+        .unwrap();
+
+        let mut registry = CardRegistry::new();
+        let registered = registry.register_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(2, registered.unwrap());
+        assert_eq!(
+            Some("Stone"),
+            registry
+                .get(CreatureDefinitionId::parse_str(
+                    "7a2b3c4d-6e1f-4a0b-8c9d-1e2f3a4b5c6d"
+                ))
+                .map(|d| d.title())
+        );
+    }
+
+    #[test]
+    fn register_file_when_id_taken_expects_duplicate_id_and_nothing_registered() {
+        let path = std::env::temp_dir().join(format!("{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            r#"[
+                {"id": "6f1f0c2e-5d1a-4e9b-9c43-0f9d8e7a6b5c", "title": "Pebble", "cost": 1, "attack": 1, "health": 1},
+                {"id": "6f1f0c2e-5d1a-4e9b-9c43-0f9d8e7a6b5c", "title": "Stone", "cost": 2, "attack": 2, "health": 2}
+            ]"#,
+        )
+        .unwrap();
+
+        let mut registry = CardRegistry::new();
+        let within_file = registry.register_file(&path);
+        assert!(matches!(
+            within_file,
+            Err(DefinitionFileError::DuplicateId { ref title, ref existing })
+                if title == "Stone" && existing == "Pebble"
+        ));
+        assert!(registry.is_empty());
+
+        registry.register(
+            CreatureDefinitionBuilder::new()
+                .definition_id(CreatureDefinitionId::parse_str(
+                    "6f1f0c2e-5d1a-4e9b-9c43-0f9d8e7a6b5c",
+                ))
+                .build(),
+        );
+        let against_registry = registry.register_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            against_registry,
+            Err(DefinitionFileError::DuplicateId { .. })
+        ));
+        assert_eq!(1, registry.len());
+    }
+
+    #[test]
+    fn register_signed_file_when_card_not_in_manifest_expects_nothing_registered() {
+        let path = std::env::temp_dir().join(format!("{}.ron", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            r#"[
+                (id: "6f1f0c2e-5d1a-4e9b-9c43-0f9d8e7a6b5c", title: "Pebble", cost: 1, attack: 1, health: 1),
+                (id: "7a2b3c4d-6e1f-4a0b-8c9d-1e2f3a4b5c6d", title: "Stone", cost: 2, attack: 2, health: 2),
+            ]"#,
+        )
+        .unwrap();
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut trusted = TrustedKeys::new();
+        trusted.trust(key.verifying_key());
+
+        let definitions: Vec<_> = load_file(&path)
+            .unwrap()
+            .iter()
+            .map(|d| d.build())
+            .collect();
+        let full = CardSetManifest::new("Rocks", &definitions).sign(&key);
+        let partial = CardSetManifest::new("Rocks", &definitions[..1]).sign(&key);
+
+        let mut registry = CardRegistry::new();
+        let rejected = registry.register_signed_file(&path, &partial, &trusted);
+        assert!(matches!(rejected, Err(ManifestError::NotInManifest { .. })));
+        assert!(registry.is_empty());
+
+        let registered = registry.register_signed_file(&path, &full, &trusted);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(2, registered.unwrap());
+    }
+
+    #[test]
+    fn register_signed_file_when_id_taken_expects_duplicate_id_and_nothing_registered() {
+        let path = std::env::temp_dir().join(format!("{}.ron", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            r#"[
+                (id: "6f1f0c2e-5d1a-4e9b-9c43-0f9d8e7a6b5c", title: "Pebble", cost: 1, attack: 1, health: 1),
+                (id: "7a2b3c4d-6e1f-4a0b-8c9d-1e2f3a4b5c6d", title: "Stone", cost: 2, attack: 2, health: 2),
+            ]"#,
+        )
+        .unwrap();
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut trusted = TrustedKeys::new();
+        trusted.trust(key.verifying_key());
+
+        let definitions: Vec<_> = load_file(&path)
+            .unwrap()
+            .iter()
+            .map(|d| d.build())
+            .collect();
+        let manifest = CardSetManifest::new("Rocks", &definitions).sign(&key);
+
+        let mut registry = CardRegistry::new();
+        registry.register(
+            CreatureDefinitionBuilder::new()
+                .definition_id(CreatureDefinitionId::parse_str(
+                    "7a2b3c4d-6e1f-4a0b-8c9d-1e2f3a4b5c6d",
+                ))
+                .build(),
+        );
+        let rejected = registry.register_signed_file(&path, &manifest, &trusted);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(rejected, Err(ManifestError::DuplicateId { .. })));
+        assert_eq!(1, registry.len());
+    }
+}
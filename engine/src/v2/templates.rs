@@ -0,0 +1,294 @@
+//! Primitive, parameterised behaviours that creatures can be assembled from without writing Rust,
+//! such as "at the start of your turn, draw 1 card".
+//! Data-driven definitions are built entirely from these.
+
+use super::{
+    actions::{EventTrigger, PassiveEffect, SummonCondition, UponEventAction, UponSummonAction},
+    Buff, BuffBuilder, CreatureInstance,
+};
+use crate::{
+    game_logic::{
+        event_dispatch::EventDispatcher,
+        events::{CreatureHealedEvent, DrawCardEvent, GameEvent, HeroTakesDamageEvent},
+    },
+    game_state::game_state::GameState,
+};
+use async_trait::async_trait;
+use protocol::entities::{BoardPos, CreatureInstanceId, PlayerId, Tribe};
+use serde::{Deserialize, Serialize};
+
+/// Something a creature does, relative to itself and its owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Effect {
+    /// The creature permanently gains the given stats.
+    GainStats { attack: i32, health: i32 },
+    /// The owner draws cards.
+    DrawCards { count: usize },
+    /// The opponent's hero takes damage.
+    DamageEnemyHero { amount: usize },
+    /// The creature heals itself.
+    HealSelf { amount: usize },
+}
+
+impl Effect {
+    async fn apply(
+        self,
+        instance_id: CreatureInstanceId,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        // Effects of a creature that has already left the board do nothing.
+        let Some(owner) = game_state
+            .board()
+            .creature_pos(instance_id)
+            .map(|pos| pos.player_id)
+        else {
+            return;
+        };
+
+        match self {
+            Effect::GainStats { attack, health } => {
+                let buff = BuffBuilder::new(instance_id)
+                    .attack(attack)
+                    .health(health)
+                    .build();
+
+                let mut board = game_state.board_mut();
+                if let Some(mut creature) = board.creature_by_id_mut(instance_id) {
+                    creature.get_mut(|c| c.add_buff(buff));
+                }
+            }
+            Effect::DrawCards { count } => {
+                for _ in 0..count {
+                    dispatcher
+                        .dispatch_triggered(DrawCardEvent::new(owner), game_state)
+                        .await;
+                }
+            }
+            Effect::DamageEnemyHero { amount } => {
+                let enemy = game_state.other_player(owner);
+                dispatcher
+                    .dispatch_triggered(HeroTakesDamageEvent::new(enemy, amount), game_state)
+                    .await;
+            }
+            Effect::HealSelf { amount } => {
+                dispatcher
+                    .dispatch_triggered(CreatureHealedEvent::new(instance_id, amount), game_state)
+                    .await;
+            }
+        }
+    }
+}
+
+/// The moment a `TriggerTemplate` fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum When {
+    /// After the owner's turn has started.
+    StartOfTurn,
+    /// Before the owner's turn ends.
+    EndOfTurn,
+    /// After the creature takes damage and survives.
+    AfterDamaged,
+    /// After the creature is moved to other slots on the board.
+    AfterMoved,
+    /// After the hidden creature is revealed during its owner's turn.
+    RevealedDuringOwnersTurn,
+    /// After the hidden creature is revealed during the opponent's turn.
+    RevealedDuringEnemysTurn,
+}
+
+impl When {
+    /// Whether the trigger runs before the event it reacts to is handled, rather than after.
+    #[must_use]
+    pub fn is_pre_event(self) -> bool {
+        matches!(self, When::EndOfTurn)
+    }
+
+    fn matches(
+        self,
+        instance_id: CreatureInstanceId,
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> bool {
+        let Some(owner) = game_state
+            .board()
+            .creature_pos(instance_id)
+            .map(|pos| pos.player_id)
+        else {
+            return false;
+        };
+
+        match (self, event) {
+            (When::StartOfTurn, GameEvent::TurnStartEvent(e)) => e.0 == owner,
+            (When::EndOfTurn, GameEvent::EndTurnEvent(e)) => e.0 == owner,
+            (When::AfterDamaged, GameEvent::CreatureTakesDamageEvent(e)) => {
+                e.creature_id() == instance_id && is_alive(instance_id, game_state)
+            }
+            (When::AfterMoved, GameEvent::CreatureMovedEvent(e)) => e.creature_id() == instance_id,
+            (When::RevealedDuringOwnersTurn, GameEvent::CreatureRevealedEvent(e)) => {
+                e.creature_id() == instance_id && game_state.cur_player_turn() == owner
+            }
+            (When::RevealedDuringEnemysTurn, GameEvent::CreatureRevealedEvent(e)) => {
+                e.creature_id() == instance_id && game_state.cur_player_turn() != owner
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether the creature still has health left; one that died in simultaneous damage
+/// stays on the board until the damage is done, but does not react to it.
+fn is_alive(instance_id: CreatureInstanceId, game_state: &GameState) -> bool {
+    game_state
+        .board()
+        .creature_by_id(instance_id)
+        .is_some_and(|c| c.get(CreatureInstance::health) > 0)
+}
+
+/// Runs the effects, in order, when the creature's `When` condition is met.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TriggerTemplate {
+    pub when: When,
+    pub effects: Vec<Effect>,
+}
+
+/// What must hold for a template-built creature to be summoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SummonRequirement {
+    /// The player controls a creature of the tribe.
+    ControlsTribe(Tribe),
+}
+
+impl SummonCondition for SummonRequirement {
+    fn is_met(&self, player_id: PlayerId, game_state: &GameState) -> bool {
+        match self {
+            SummonRequirement::ControlsTribe(tribe) => !game_state
+                .board()
+                .player_creature_ids_of_tribe(player_id, *tribe)
+                .is_empty(),
+        }
+    }
+}
+
+/// While the creature is on the board, the owner's other creatures of the tribe have the given stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TribeBuff {
+    pub tribe: Tribe,
+    pub attack: i32,
+    pub health: i32,
+}
+
+/// Everything a template-built creature does, as written in its definition.
+/// The summon condition and tribe buffs are left out of the serialization while unused.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateBehaviour {
+    pub upon_summon: Vec<Effect>,
+    pub triggers: Vec<TriggerTemplate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summon_condition: Option<SummonRequirement>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tribe_buffs: Vec<TribeBuff>,
+}
+
+/// A set of trigger templates sharing one hook, so a creature can have any number of them.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateTriggers(pub Vec<TriggerTemplate>);
+
+impl EventTrigger for TemplateTriggers {
+    fn trigger(
+        &self,
+        instance_id: CreatureInstanceId,
+        event: &GameEvent,
+        game_state: &GameState,
+    ) -> Option<Box<dyn UponEventAction>> {
+        let effects: Vec<Effect> = self
+            .0
+            .iter()
+            .filter(|t| t.when.matches(instance_id, event, game_state))
+            .flat_map(|t| t.effects.iter().copied())
+            .collect();
+
+        if effects.is_empty() {
+            return None;
+        }
+
+        Some(Box::new(EffectsAction {
+            instance_id,
+            effects,
+        }))
+    }
+}
+
+struct EffectsAction {
+    instance_id: CreatureInstanceId,
+    effects: Vec<Effect>,
+}
+
+#[async_trait]
+impl UponEventAction for EffectsAction {
+    async fn action(
+        &self,
+        _event: &GameEvent,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        for effect in &self.effects {
+            effect.apply(self.instance_id, game_state, dispatcher).await;
+        }
+    }
+}
+
+/// Runs the effects, in order, when the creature is summoned.
+#[derive(Debug, Clone, Default)]
+pub struct SummonTemplate(pub Vec<Effect>);
+
+#[async_trait]
+impl UponSummonAction for SummonTemplate {
+    async fn action(
+        &self,
+        instance_id: CreatureInstanceId,
+        _pos: BoardPos,
+        game_state: &mut GameState,
+        dispatcher: &mut EventDispatcher,
+    ) {
+        for effect in &self.0 {
+            effect.apply(instance_id, game_state, dispatcher).await;
+        }
+    }
+}
+
+/// Grants every tribe buff, as one passive effect.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatePassives(pub Vec<TribeBuff>);
+
+impl PassiveEffect for TemplatePassives {
+    fn buffs(
+        &self,
+        instance_id: CreatureInstanceId,
+        game_state: &GameState,
+    ) -> Vec<(CreatureInstanceId, Buff)> {
+        let board = game_state.board();
+        let Some(owner) = board.creature_pos(instance_id).map(|pos| pos.player_id) else {
+            return Vec::new();
+        };
+
+        self.0
+            .iter()
+            .flat_map(|tribe_buff| {
+                board
+                    .player_creature_ids_of_tribe(owner, tribe_buff.tribe)
+                    .into_iter()
+                    .filter(|receiver_id| *receiver_id != instance_id)
+                    .map(|receiver_id| {
+                        let buff = BuffBuilder::new(instance_id)
+                            .attack(tribe_buff.attack)
+                            .health(tribe_buff.health)
+                            .from_passive()
+                            .build();
+
+                        (receiver_id, buff)
+                    })
+            })
+            .collect()
+    }
+}
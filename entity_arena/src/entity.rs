@@ -33,7 +33,7 @@ impl Entity {
         TypedEntity {
             id: self.id,
             data: self.data,
-            _phantom: std::marker::PhantomData::default(),
+            _phantom: std::marker::PhantomData,
         }
     }
 
@@ -42,7 +42,7 @@ impl Entity {
         TypedEntity {
             id: self.id,
             data: &mut self.data,
-            _phantom: std::marker::PhantomData::default(),
+            _phantom: std::marker::PhantomData,
         }
     }
 
@@ -51,7 +51,7 @@ impl Entity {
         TypedEntity {
             id: self.id,
             data: &self.data,
-            _phantom: std::marker::PhantomData::default(),
+            _phantom: std::marker::PhantomData,
         }
     }
 
@@ -82,7 +82,6 @@ mod tests {
     use crate::{
         entity::tests::{AnotherTestEntity, TestEntity},
         id::EntityId,
-        Entity,
     };
 
     #[test]
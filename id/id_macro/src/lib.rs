@@ -49,5 +49,5 @@ pub fn id(args: TokenStream, input: TokenStream) -> TokenStream {
     // Useful during debug:
     // println!("{:?}", stream);
 
-    return stream;
+    stream
 }
@@ -9,12 +9,29 @@ impl Id {
         Self(Uuid::new_v4())
     }
 
+    /// A random (v4) id made from the given bytes, so a seeded RNG can produce reproducible ids.
+    #[must_use]
+    pub fn from_random_bytes(bytes: [u8; 16]) -> Self {
+        let uuid = uuid::Builder::from_bytes(bytes)
+            .set_variant(uuid::Variant::RFC4122)
+            .set_version(uuid::Version::Random)
+            .build();
+
+        Self(uuid)
+    }
+
     #[must_use]
     pub fn parse_str(s: &str) -> Self {
         Id(Uuid::parse_str(s).unwrap())
     }
 }
 
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl std::fmt::Debug for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.to_string()[0..8].fmt(f)
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{CreatureInstanceId, PlayerId};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CastSpellFromHand {
+    pub player_id: PlayerId,
+    pub card_id: CreatureInstanceId,
+}
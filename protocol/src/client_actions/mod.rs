@@ -1,7 +1,13 @@
 mod attack;
+mod cast_spell_from_hand;
 mod end_turn;
+mod move_creature;
 mod summon_creature_from_hand;
+mod use_hero_power;
 
 pub use attack::Attack;
+pub use cast_spell_from_hand::CastSpellFromHand;
 pub use end_turn::EndTurn;
+pub use move_creature::MoveCreature;
 pub use summon_creature_from_hand::SummonCreatureFromHand;
+pub use use_hero_power::UseHeroPower;
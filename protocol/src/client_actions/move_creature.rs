@@ -0,0 +1,8 @@
+use crate::entities::{BoardPos, CreatureInstanceId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MoveCreature {
+    pub creature_id: CreatureInstanceId,
+    pub to: BoardPos,
+}
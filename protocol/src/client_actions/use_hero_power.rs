@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::PlayerId;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UseHeroPower {
+    pub player_id: PlayerId,
+}
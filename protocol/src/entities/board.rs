@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use super::{player::PlayerId, CreatureInstance};
 use serde::{Deserialize, Serialize};
 
@@ -29,7 +27,7 @@ pub enum RowId {
 
 /// Describes which board positions
 /// this creature card may occupy.
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Position {
     /// The front side of the board.
     Front,
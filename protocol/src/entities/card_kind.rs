@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// What a card does when it is played from the hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum CardKind {
+    /// Summoned onto the board as a creature.
+    #[default]
+    Creature,
+    /// Cast for its effect, then gone.
+    Spell,
+}
+
+impl CardKind {
+    #[must_use]
+    pub fn is_creature(&self) -> bool {
+        *self == CardKind::Creature
+    }
+}
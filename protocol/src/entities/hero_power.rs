@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// The view of a hero's once-per-turn power.
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct HeroPower {
+    pub title: String,
+    pub text: String,
+    pub cost: u32,
+}
@@ -1,7 +1,22 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A value that one of the players may not be allowed to see yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Hideable<T> {
     Hidden,
     Revealed(T),
 }
+
+impl<T> Hideable<T> {
+    pub fn is_hidden(&self) -> bool {
+        matches!(self, Hideable::Hidden)
+    }
+
+    /// The value, if it has been revealed.
+    pub fn revealed(&self) -> Option<&T> {
+        match self {
+            Hideable::Hidden => None,
+            Hideable::Revealed(value) => Some(value),
+        }
+    }
+}
@@ -26,7 +26,7 @@ mod test {
     fn can_parse() {
         let guid_to_parse = "9f19a122-b52f-43b7-b5f4-632d2defb828";
 
-        let parsed = Id::parse_str(guid_to_parse);
+        let _parsed = Id::parse_str(guid_to_parse);
 
         // implicit assert: no panic
     }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// A rule a creature has, shown on its card by name.
+/// Creatures get keywords from their definition, and can gain or lose them through buffs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Keyword {
+    /// Must be destroyed before the back row slot behind it can be attacked.
+    Defender,
+    /// Can attack on the turn it is summoned.
+    Eager,
+    /// Summoned face down, and revealed when it first fights.
+    Hidden,
+    /// Does something when it is summoned from the hand.
+    Summon,
+    /// Does something when another creature on its side is destroyed.
+    Vengeance,
+    /// Can be placed in either row, whatever its usual row.
+    Versatile,
+}
@@ -1,27 +1,33 @@
 mod board;
 mod buff;
+mod card_kind;
 mod hand;
 mod hero;
+mod hero_power;
 mod hideable;
 mod id;
+mod keyword;
 mod passive_effect;
 mod player;
+mod tribe;
 mod unit_card_definition;
 mod unit_card_instance_view;
 
-use std::any::Any;
-
 pub use crate::entities::id::*;
 use ::id::Id;
 pub use board::*;
 pub use buff::*;
+pub use card_kind::*;
 pub use hand::*;
 pub use hero::*;
+pub use hero_power::*;
 pub use hideable::*;
+pub use keyword::*;
 pub use passive_effect::*;
 pub use player::*;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::json;
+pub use tribe::*;
 pub use unit_card_definition::*;
 pub use unit_card_instance_view::*;
 
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// A club a creature belongs to, such as cats or dogs.
+/// Some cards only care about creatures of a certain tribe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Tribe {
+    Bug,
+    Cat,
+    ChessPiece,
+    Dog,
+    Teacher,
+}
@@ -1,7 +1,8 @@
-use super::Position;
+use super::{CardKind, Keyword, Position, Tribe};
 use serde::{Deserialize, Serialize};
 
-/// The view of a creature card definition.
+/// The view of a card definition.
+/// Spells use the same view; their attack, health and width are unused.
 #[derive(Debug, Serialize, Clone, Deserialize)]
 pub struct CreatureDefinition {
     pub title: String,
@@ -12,6 +13,12 @@ pub struct CreatureDefinition {
     pub health: i32,
     pub row_width: usize,
     pub placeable_at: Position,
+    #[serde(default)]
+    pub keywords: Vec<Keyword>,
+    #[serde(default)]
+    pub tribes: Vec<Tribe>,
+    #[serde(default)]
+    pub kind: CardKind,
 }
 
 impl CreatureDefinition {
@@ -25,6 +32,9 @@ impl CreatureDefinition {
             health: 1,
             row_width: 1,
             placeable_at: Position::Either,
+            keywords: Vec::new(),
+            tribes: Vec::new(),
+            kind: CardKind::Creature,
         }
     }
 }
@@ -1,21 +1,25 @@
 pub use self::id::*;
 
 use super::{
-    board::BoardPos, buff::BuffPlayerView, unit_card_definition::CreatureDefinition,
-    EntityPosition, EntityTypeId, HasId, IsEntity, PassiveEffectInstancePlayerView,
+    board::BoardPos, buff::BuffPlayerView, unit_card_definition::CreatureDefinition, EntityTypeId,
+    HasId, Hideable, IsEntity, Keyword, PassiveEffectInstancePlayerView,
 };
 use serde::{Deserialize, Serialize};
 
 /// A view of a creature card instance.
+/// The opponent's view of a hidden creature keeps its definition hidden,
+/// and shows only the cost bucket it was summoned from.
 #[derive(Debug, Serialize, Clone, Deserialize)]
 pub struct CreatureInstance {
-    definition: CreatureDefinition,
+    definition: Hideable<CreatureDefinition>,
     buffs: Vec<BuffPlayerView>,
     passive_effect: Option<PassiveEffectInstancePlayerView>,
     id: CreatureInstanceId,
+    cost: i32,
     attack: i32,
     health: i32,
     width: usize,
+    keywords: Vec<Keyword>,
     state: Option<InstanceState>,
 }
 
@@ -27,25 +31,61 @@ impl CreatureInstance {
         passive_effect: Option<PassiveEffectInstancePlayerView>,
     ) -> Self {
         let width = definition.row_width;
+        let cost = definition.cost;
         let attack = definition.attack;
         let health = definition.health;
+        let keywords = definition.keywords.clone();
 
         Self {
-            definition,
+            definition: Hideable::Revealed(definition),
             buffs,
             passive_effect,
             id,
+            cost,
             attack,
             health,
             width,
+            keywords,
+            state: None,
+        }
+    }
+
+    /// The face-down placeholder for a hidden creature: its width, and its cost bucket in place of its cost.
+    pub fn hidden(id: CreatureInstanceId, cost_bucket: i32, width: usize) -> Self {
+        Self {
+            definition: Hideable::Hidden,
+            buffs: Vec::new(),
+            passive_effect: None,
+            id,
+            cost: cost_bucket,
+            attack: 0,
+            health: 0,
+            width,
+            keywords: vec![Keyword::Hidden],
             state: None,
         }
     }
 
     /// Get a reference to the unit card instance's definition.
-    pub fn definition(&self) -> &CreatureDefinition {
+    pub fn definition(&self) -> &Hideable<CreatureDefinition> {
         &self.definition
     }
+
+    /// The creature's cost, or its cost bucket while it is hidden.
+    pub fn cost(&self) -> i32 {
+        self.cost
+    }
+
+    /// The keywords the creature currently has, including those gained or lost through buffs.
+    pub fn keywords(&self) -> &[Keyword] {
+        &self.keywords
+    }
+
+    #[must_use]
+    pub fn with_keywords(mut self, keywords: Vec<Keyword>) -> Self {
+        self.keywords = keywords;
+        self
+    }
 }
 
 impl HasId for CreatureInstance {
@@ -1,5 +1,7 @@
 use crate::{
-    client_actions::{Attack, EndTurn, SummonCreatureFromHand},
+    client_actions::{
+        Attack, CastSpellFromHand, EndTurn, MoveCreature, SummonCreatureFromHand, UseHeroPower,
+    },
     entities::BoardPos,
     GameMessage,
 };
@@ -23,6 +25,9 @@ pub enum ClientAction {
     EndTurn(EndTurn),
     SummonCreatureFromHand(SummonCreatureFromHand),
     Attack(Attack),
+    CastSpellFromHand(CastSpellFromHand),
+    UseHeroPower(UseHeroPower),
+    MoveCreature(MoveCreature),
     // DrawCard(DrawCardEvent), "draw card" is not an action a client can decide to do, it just happens
 }
 
@@ -1,5 +1,6 @@
 use crate::{
-    entities::{AsId, Entity, EntityId, EntityPosition, EntityTypeId, PlayerId},
+    entities::{AsId, Entity, EntityPosition, EntityTypeId, PlayerId},
+    rule_violation::RuleViolation,
     visual_events::*,
     GameMessage,
 };
@@ -8,6 +9,7 @@ use serde::{Deserialize, Serialize};
 
 /// Views of events that the server can send to clients.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum VisualEvent {
     CardAddedToHand(CardAddedToHand),
     CreatureSetOnBoard(CreatureSetOnBoard),
@@ -17,6 +19,14 @@ pub enum VisualEvent {
     PlayerGainMana(PlayerGainMana),
     PlayerSpendMana(PlayerSpendMana),
     CreatureAttacksTarget(CreatureAttacksTarget),
+    HeroTakesDamage(HeroTakesDamage),
+    CreatureTakesDamage(CreatureTakesDamage),
+    CreatureDestroyed(CreatureDestroyed),
+    CreatureRevealed(CreatureRevealed),
+    SpellCastFromHand(SpellCastFromHand),
+    HeroPowerUsed(HeroPowerUsed),
+    CreatureMoved(CreatureMoved),
+    PosTakesDamage(PosTakesDamage),
 }
 
 /// A message from server to client that informs of an entity's new value.
@@ -48,6 +58,7 @@ impl EntityAdded {
 
 /// Messages that can be sent from the game server to the game client.
 #[derive(Serialize, Deserialize, Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum FromServer {
     /// Initial message from the server, providing the player with their ID for the match.
     Hello {
@@ -70,10 +81,15 @@ pub enum FromServer {
 
     /// A message from the server notifying the game client about some event.
     Notification(Notification),
+
+    /// Sent to a client whose action broke a rule of the game.
+    /// The action had no effect, and the server is still waiting for a valid action.
+    RuleViolation(RuleViolation),
 }
 impl GameMessage for FromServer {}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum Notification {
     VisualEvent(VisualEvent),
     EntityUpdate(EntityUpdate),
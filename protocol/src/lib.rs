@@ -2,6 +2,7 @@ pub mod client_actions;
 pub mod entities;
 pub mod from_client;
 pub mod from_server;
+pub mod rule_violation;
 pub mod visual_events;
 
 use serde::{de::DeserializeOwned, Serialize};
@@ -0,0 +1,87 @@
+use crate::entities::{BoardPos, CreatureInstanceId, PlayerId, Position};
+use id::Id;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The reasons the game can reject an action for breaking its rules.
+/// The offending client is told which rule was broken, and the game continues.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize)]
+pub enum RuleViolation {
+    #[error("It is not player {player_id:?}'s turn.")]
+    NotYourTurn { player_id: PlayerId },
+
+    #[error("Costs {cost} mana, but the player only has {available}.")]
+    NotEnoughMana { cost: u32, available: u32 },
+
+    #[error("Player {player_id:?} has no card {card_id:?} in hand.")]
+    CardNotInHand {
+        player_id: PlayerId,
+        card_id: CreatureInstanceId,
+    },
+
+    #[error("Card {card_id:?} has no known definition.")]
+    UnknownCard { card_id: CreatureInstanceId },
+
+    #[error("Card {card_id:?} is a spell, so it cannot be summoned.")]
+    NotACreature { card_id: CreatureInstanceId },
+
+    #[error("Card {card_id:?} is a creature, so it cannot be cast.")]
+    NotASpell { card_id: CreatureInstanceId },
+
+    #[error("Card {card_id:?} can only be summoned while its condition is met.")]
+    SummonConditionNotMet { card_id: CreatureInstanceId },
+
+    #[error("Spell {card_id:?} has nothing it can target.")]
+    NoLegalTarget { card_id: CreatureInstanceId },
+
+    #[error("Player {player_id:?}'s hero has no hero power.")]
+    NoHeroPower { player_id: PlayerId },
+
+    #[error("Player {player_id:?} has already used their hero power this turn.")]
+    HeroPowerAlreadyUsed { player_id: PlayerId },
+
+    #[error("Player {player_id:?} can only use their hero power while its condition is met.")]
+    HeroPowerConditionNotMet { player_id: PlayerId },
+
+    #[error("Player {player_id:?} cannot act on the other player's side of the board at {pos:?}.")]
+    NotYourSide { player_id: PlayerId, pos: BoardPos },
+
+    #[error("A creature with width {width} does not fit in the row at {pos:?}.")]
+    DoesNotFit { pos: BoardPos, width: usize },
+
+    #[error("There is no slot at {pos:?}.")]
+    NoSuchSlot { pos: BoardPos },
+
+    #[error("The slot {pos:?} is already occupied.")]
+    SlotOccupied { pos: BoardPos },
+
+    #[error("Cannot place at {pos:?}, since the card is only placeable at {placeable_at:?}.")]
+    NotPlaceableAt {
+        pos: BoardPos,
+        placeable_at: Position,
+    },
+
+    #[error("Creature {creature_id:?} is not on the board.")]
+    NotOnBoard { creature_id: CreatureInstanceId },
+
+    #[error("Creature {creature_id:?} is not Versatile, so it cannot be moved by its owner.")]
+    NotVersatile { creature_id: CreatureInstanceId },
+
+    #[error("Creature {creature_id:?} was summoned this turn and cannot attack yet.")]
+    SummoningSick { creature_id: CreatureInstanceId },
+
+    #[error("Creature {creature_id:?} has already attacked this turn.")]
+    AlreadyAttacked { creature_id: CreatureInstanceId },
+
+    #[error("A creature cannot attack its own side.")]
+    CannotAttackOwnSide,
+
+    #[error("Cannot attack defended pos {pos:?}.")]
+    TargetDefended { pos: BoardPos },
+
+    #[error("Card definition {definition_id:?} is not known.")]
+    UnknownDefinition { definition_id: Id },
+
+    #[error("{title:?} is not legal in this match.")]
+    IllegalCard { definition_id: Id, title: String },
+}
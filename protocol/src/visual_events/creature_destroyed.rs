@@ -0,0 +1,7 @@
+use crate::entities::CreatureInstanceId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreatureDestroyed {
+    pub creature_id: CreatureInstanceId,
+}
@@ -0,0 +1,11 @@
+use crate::entities::{BoardPos, CreatureInstanceId};
+use serde::{Deserialize, Serialize};
+
+/// A creature on the board moved to other slots, keeping everything else about it.
+/// Both positions are the creature's leftmost slot.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreatureMoved {
+    pub creature_id: CreatureInstanceId,
+    pub from: BoardPos,
+    pub to: BoardPos,
+}
@@ -0,0 +1,9 @@
+use crate::entities::{BoardPos, CreatureInstance};
+use serde::{Deserialize, Serialize};
+
+/// A hidden creature was turned face up, showing its definition to the opponent.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreatureRevealed {
+    pub board_pos: BoardPos,
+    pub creature: CreatureInstance,
+}
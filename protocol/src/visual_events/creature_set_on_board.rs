@@ -1,4 +1,9 @@
+use crate::entities::{BoardPos, CreatureInstance, PlayerId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct CreatureSetOnBoard;
+pub struct CreatureSetOnBoard {
+    pub player_id: PlayerId,
+    pub board_pos: BoardPos,
+    pub creature: CreatureInstance,
+}
@@ -1,4 +1,9 @@
+use crate::entities::{BoardPos, CreatureInstance, PlayerId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct CreatureSummonedFromHand;
+pub struct CreatureSummonedFromHand {
+    pub player_id: PlayerId,
+    pub board_pos: BoardPos,
+    pub creature: CreatureInstance,
+}
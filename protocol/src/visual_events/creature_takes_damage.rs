@@ -0,0 +1,8 @@
+use crate::entities::CreatureInstanceId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreatureTakesDamage {
+    pub creature_id: CreatureInstanceId,
+    pub damage_amount: usize,
+}
@@ -0,0 +1,9 @@
+use crate::entities::{HeroPower, PlayerId};
+use serde::{Deserialize, Serialize};
+
+/// A player used their hero's power.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HeroPowerUsed {
+    pub player_id: PlayerId,
+    pub hero_power: HeroPower,
+}
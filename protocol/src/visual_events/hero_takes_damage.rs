@@ -0,0 +1,8 @@
+use crate::entities::PlayerId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HeroTakesDamage {
+    pub player_id: PlayerId,
+    pub damage_amount: usize,
+}
@@ -1,17 +1,33 @@
 mod card_added_to_hand;
 mod creature_attacks_target;
+mod creature_destroyed;
+mod creature_moved;
+mod creature_revealed;
 mod creature_set_on_board;
 mod creature_summoned_from_hand;
+mod creature_takes_damage;
+mod hero_power_used;
+mod hero_takes_damage;
 mod player_gain_mana;
 mod player_spend_mana;
+mod pos_takes_damage;
+mod spell_cast_from_hand;
 mod turn_ended;
 mod turn_started;
 
 pub use card_added_to_hand::CardAddedToHand;
 pub use creature_attacks_target::CreatureAttacksTarget;
+pub use creature_destroyed::CreatureDestroyed;
+pub use creature_moved::CreatureMoved;
+pub use creature_revealed::CreatureRevealed;
 pub use creature_set_on_board::CreatureSetOnBoard;
 pub use creature_summoned_from_hand::CreatureSummonedFromHand;
+pub use creature_takes_damage::CreatureTakesDamage;
+pub use hero_power_used::HeroPowerUsed;
+pub use hero_takes_damage::HeroTakesDamage;
 pub use player_gain_mana::PlayerGainMana;
 pub use player_spend_mana::PlayerSpendMana;
+pub use pos_takes_damage::PosTakesDamage;
+pub use spell_cast_from_hand::SpellCastFromHand;
 pub use turn_ended::TurnEnded;
 pub use turn_started::TurnStarted;
@@ -1,4 +1,10 @@
+use crate::entities::PlayerId;
 use serde::{Deserialize, Serialize};
 
+/// A player's mana limit grew, and their available mana was refilled.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct PlayerGainMana;
+pub struct PlayerGainMana {
+    pub player_id: PlayerId,
+    pub mana_limit: u32,
+    pub available_mana: u32,
+}
@@ -1,4 +1,10 @@
+use crate::entities::PlayerId;
 use serde::{Deserialize, Serialize};
 
+/// A player spent some of their available mana.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct PlayerSpendMana;
+pub struct PlayerSpendMana {
+    pub player_id: PlayerId,
+    pub spent_mana_count: u32,
+    pub available_mana: u32,
+}
@@ -0,0 +1,10 @@
+use crate::entities::BoardPos;
+use serde::{Deserialize, Serialize};
+
+/// Slots on the board were hit by damage, whether or not anything stood in them.
+/// Any creatures or heroes that were hit are also sent their own damage events.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PosTakesDamage {
+    pub slots: Vec<BoardPos>,
+    pub damage_amount: usize,
+}
@@ -0,0 +1,10 @@
+use crate::entities::{CreatureDefinition, CreatureInstanceId, PlayerId};
+use serde::{Deserialize, Serialize};
+
+/// A player cast a spell from their hand, showing it to both players.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SpellCastFromHand {
+    pub player_id: PlayerId,
+    pub card_id: CreatureInstanceId,
+    pub spell: CreatureDefinition,
+}
@@ -1,4 +1,7 @@
+use crate::entities::PlayerId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct TurnEnded;
+pub struct TurnEnded {
+    pub player_id: PlayerId,
+}
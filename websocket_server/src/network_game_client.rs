@@ -8,6 +8,7 @@ use log::info;
 use protocol::entities::PlayerId;
 use protocol::from_client::{ClientAction, FromClient};
 use protocol::from_server::FromServer;
+use protocol::rule_violation::RuleViolation;
 use salt_engine::game_agent::{ClientNotifier, GameClient};
 use salt_engine::{game_agent::Prompter, game_state::GameState};
 
@@ -59,6 +60,15 @@ impl GameClient for NetworkGameClient {
         action
     }
 
+    async fn on_rule_violation(&mut self, violation: RuleViolation) {
+        info!("Rejected action from player {:?}: {}", self.player_id, violation);
+
+        self.connection
+            .send(FromServer::RuleViolation(violation))
+            .await
+            .expect("failed to send rule violation");
+    }
+
     async fn make_prompter(&self) -> Arc<dyn Prompter> {
         Arc::new(NewtorkPrompter::new(self.connection.clone()))
     }